@@ -55,6 +55,10 @@ pub static TRANSACTION_CACHE_SIZE: LazyLock<usize> =
 pub static DATASTORE_CACHE_SIZE: LazyLock<usize> =
 	lazy_env_parse!("SURREAL_DATASTORE_CACHE_SIZE", usize, 1_000);
 
+/// The maximum number of definitions output per category by `INFO FOR DATABASE` (default: 10,000)
+pub static INFO_MAX_ENTRIES: LazyLock<usize> =
+	lazy_env_parse!("SURREAL_INFO_MAX_ENTRIES", usize, 10_000);
+
 /// The maximum number of keys that should be scanned at once in general queries (default: 500)
 pub static NORMAL_FETCH_SIZE: LazyLock<u32> =
 	lazy_env_parse!("SURREAL_NORMAL_FETCH_SIZE", u32, 500);
@@ -67,10 +71,19 @@ pub static EXPORT_BATCH_SIZE: LazyLock<u32> =
 pub static COUNT_BATCH_SIZE: LazyLock<u32> =
 	lazy_env_parse!("SURREAL_COUNT_BATCH_SIZE", u32, 10_000);
 
+/// The maximum number of keys to delete per transaction when reclaiming the data of removed
+/// namespaces (default: 1000)
+pub static NAMESPACE_REMOVAL_BATCH_SIZE: LazyLock<u32> =
+	lazy_env_parse!("SURREAL_NAMESPACE_REMOVAL_BATCH_SIZE", u32, 1000);
+
 /// The maximum number of keys to scan at once per concurrent indexing batch (default: 250)
 pub static INDEXING_BATCH_SIZE: LazyLock<u32> =
 	lazy_env_parse!("SURREAL_INDEXING_BATCH_SIZE", u32, 250);
 
+/// Whether to count the records of a table before indexing it, to estimate the progress of the initial indexing (default: false)
+pub static INDEXING_PROGRESS_COUNT: LazyLock<bool> =
+	lazy_env_parse!("SURREAL_INDEXING_PROGRESS_COUNT", bool, false);
+
 /// The maximum size of the priority queue triggering usage of the priority queue for the result collector.
 pub static MAX_ORDER_LIMIT_PRIORITY_QUEUE_SIZE: LazyLock<u32> =
 	lazy_env_parse!("SURREAL_MAX_ORDER_LIMIT_PRIORITY_QUEUE_SIZE", u32, 1000);
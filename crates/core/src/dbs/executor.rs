@@ -14,11 +14,12 @@ use crate::sql::paths::DB;
 use crate::sql::paths::NS;
 use crate::sql::query::Query;
 use crate::sql::statement::Statement;
-use crate::sql::statements::{OptionStatement, UseStatement};
+use crate::sql::statements::{CancelStatement, OptionStatement, UseStatement};
 use crate::sql::value::Value;
 use crate::sql::Base;
 use crate::sql::ControlFlow;
 use crate::sql::FlowResult;
+use crate::sql::Ident;
 use futures::{Stream, StreamExt};
 use reblessive::TreeStack;
 use std::pin::{pin, Pin};
@@ -155,6 +156,11 @@ impl Executor {
 			}
 		}
 
+		// Invalidate anything built from the changed definitions, on every node.
+		if let Ok(_) | Err(ControlFlow::Return(_)) = res {
+			self.ctx.tx().new_schema_generation().await?;
+		}
+
 		res
 	}
 
@@ -241,7 +247,46 @@ impl Executor {
 		}
 	}
 
-	/// Execute the begin statement and all statements after which are within a transaction block.
+	/// Cancel the transaction, recording the reason for the cancellation.
+	#[instrument(
+		level = "debug",
+		name = "cancel",
+		target = "surrealdb::core::dbs",
+		skip_all,
+		fields(reason = stm.reason.as_ref().map(|v| v.as_str()))
+	)]
+	async fn cancel_transaction(txn: &Transaction, stm: &CancelStatement) {
+		trace!(target: TARGET, "Cancelling transaction");
+		let _ = txn.cancel().await;
+	}
+
+	/// Rollback the transaction to a savepoint, recording the reason for the rollback.
+	///
+	/// The savepoint is set again afterwards, so it can be rolled back to more than once.
+	#[instrument(
+		level = "debug",
+		name = "cancel",
+		target = "surrealdb::core::dbs",
+		skip_all,
+		fields(
+			reason = stm.reason.as_ref().map(|v| v.as_str()),
+			savepoint = stm.savepoint.as_ref().map(|v| v.as_str())
+		)
+	)]
+	async fn rollback_to_savepoint(
+		txn: &Transaction,
+		stm: &CancelStatement,
+		count: usize,
+	) -> Result<(), Error> {
+		trace!(target: TARGET, "Rolling back transaction to savepoint");
+		let mut lock = txn.lock().await;
+		for _ in 0..count {
+			lock.rollback_to_save_point().await?;
+		}
+		lock.new_save_point().await;
+		Ok(())
+	}
+
 	async fn execute_begin_statement<S>(
 		&mut self,
 		kvs: &Datastore,
@@ -256,7 +301,12 @@ impl Executor {
 			while let Some(stmt) = stream.next().await {
 				yield_now!();
 				let stmt = stmt?;
-				if let Statement::Cancel(_) | Statement::Commit(_) = stmt {
+				if let Statement::Cancel(CancelStatement {
+					savepoint: None,
+					..
+				})
+				| Statement::Commit(_) = stmt
+				{
 					return Ok(());
 				}
 
@@ -283,6 +333,8 @@ impl Executor {
 		let txn = Arc::new(txn);
 		let start_results = self.results.len();
 		let mut skip_remaining = false;
+		// The savepoints set in this transaction, with the results which followed them.
+		let mut savepoints: Vec<(Ident, usize)> = Vec::new();
 
 		// loop over the statements until we hit a cancel or a commit statement.
 		while let Some(stmt) = stream.next().await {
@@ -310,7 +362,12 @@ impl Executor {
 				while let Some(stmt) = stream.next().await {
 					yield_now!();
 					let stmt = stmt?;
-					if let Statement::Cancel(_) | Statement::Commit(_) = stmt {
+					if let Statement::Cancel(CancelStatement {
+						savepoint: None,
+						..
+					})
+					| Statement::Commit(_) = stmt
+					{
 						return Ok(());
 					}
 
@@ -328,7 +385,14 @@ impl Executor {
 				return Ok(());
 			}
 
-			if skip_remaining && !matches!(stmt, Statement::Cancel(_) | Statement::Commit(_)) {
+			if skip_remaining
+				&& !matches!(
+					stmt,
+					Statement::Cancel(CancelStatement {
+						savepoint: None,
+						..
+					}) | Statement::Commit(_)
+				) {
 				continue;
 			}
 
@@ -366,7 +430,12 @@ impl Executor {
 					while let Some(stmt) = stream.next().await {
 						yield_now!();
 						let stmt = stmt?;
-						if let Statement::Cancel(_) | Statement::Commit(_) = stmt {
+						if let Statement::Cancel(CancelStatement {
+							savepoint: None,
+							..
+						})
+						| Statement::Commit(_) = stmt
+						{
 							return Ok(());
 						}
 
@@ -380,8 +449,41 @@ impl Executor {
 					// Missing CANCEL/COMMIT statement, statement already canceled so nothing todo.
 					return Ok(());
 				}
-				Statement::Cancel(_) => {
-					let _ = txn.cancel().await;
+				Statement::Savepoint(stm) => {
+					txn.lock().await.new_save_point().await;
+					savepoints.push((stm.name, self.results.len()));
+					continue;
+				}
+				Statement::Cancel(
+					ref stm @ CancelStatement {
+						savepoint: Some(ref name),
+						..
+					},
+				) => match savepoints.iter().rposition(|(v, _)| v == name) {
+					Some(pos) => {
+						let count = savepoints.len() - pos;
+						if let Err(e) = Self::rollback_to_savepoint(&txn, stm, count).await {
+							let _ = txn.cancel().await;
+							return Err(e);
+						}
+						savepoints.truncate(pos + 1);
+						// The rollback might have changed definitions.
+						txn.clear();
+
+						// update the results indicating cancelation.
+						for res in &mut self.results[savepoints[pos].1..] {
+							res.query_type = QueryType::Other;
+							res.result = Err(Error::QueryCancelled);
+						}
+
+						continue;
+					}
+					None => Err(Error::SavepointNotFound {
+						name: name.to_raw(),
+					}),
+				},
+				Statement::Cancel(stm) => {
+					Self::cancel_transaction(&txn, &stm).await;
 
 					// update the results indicating cancelation.
 					for res in &mut self.results[start_results..] {
@@ -476,7 +578,12 @@ impl Executor {
 							while let Some(stmt) = stream.next().await {
 								yield_now!();
 								let stmt = stmt?;
-								if let Statement::Cancel(_) | Statement::Commit(_) = stmt {
+								if let Statement::Cancel(CancelStatement {
+									savepoint: None,
+									..
+								})
+								| Statement::Commit(_) = stmt
+								{
 									return Ok(());
 								}
 
@@ -109,6 +109,8 @@ pub(crate) struct Iterator {
 	limit: Option<u32>,
 	/// Iterator start value
 	start: Option<u32>,
+	/// Iterator start value as a percentage of the results
+	start_percentage: Option<f64>,
 	/// Counter of remaining documents that can be skipped processing
 	start_skip: Option<usize>,
 	/// Iterator runtime error
@@ -129,6 +131,7 @@ impl Clone for Iterator {
 			run: self.run.clone(),
 			limit: self.limit,
 			start: self.start,
+			start_percentage: self.start_percentage,
 			start_skip: self.start_skip.map(|_| self.start.unwrap_or(0) as usize),
 			error: None,
 			results: Results::default(),
@@ -366,7 +369,8 @@ impl Iterator {
 			ctx,
 			stm,
 			self.start,
-			self.limit,
+			// A percentage START needs the full result set
+			self.limit.filter(|_| self.start_percentage.is_none()),
 		)?;
 		// Extract the expected behaviour depending on the presence of EXPLAIN with or without FULL
 		let mut plan = Plan::new(ctx, stm, &self.entries, &self.results);
@@ -418,6 +422,10 @@ impl Iterator {
 				#[cfg(target_family = "wasm")]
 				self.results.sort(orders);
 			}
+			// Resolve any START percentage against the number of results
+			if let Some(p) = self.start_percentage {
+				self.start = Some((self.results.len() as f64 * p / 100.0) as u32);
+			}
 			// Process any START & LIMIT clause
 			self.results.start_limit(self.start_skip, self.start, self.limit).await?;
 			// Process any FETCH clause
@@ -473,13 +481,21 @@ impl Iterator {
 		stm: &Statement<'_>,
 	) -> Result<(), Error> {
 		if let Some(v) = stm.start() {
-			self.start = Some(v.process(stk, ctx, opt, None).await?);
+			if v.is_percentage() {
+				self.start_percentage = Some(v.percentage(stk, ctx, opt, None).await?);
+			} else {
+				self.start = Some(v.process(stk, ctx, opt, None).await?);
+			}
 		}
 		Ok(())
 	}
 
 	/// Check if the iteration can be limited per iterator
 	fn check_set_start_limit(&self, ctx: &Context, stm: &Statement<'_>) -> bool {
+		// If the start is a percentage of the results we can't
+		if self.start_percentage.is_some() {
+			return false;
+		}
 		// If there are groups we can't
 		if stm.group().is_some() {
 			return false;
@@ -3,7 +3,7 @@ use revision::revisioned;
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Debug, Display};
 
-#[revisioned(revision = 1)]
+#[revisioned(revision = 2)]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 #[non_exhaustive]
@@ -11,6 +11,9 @@ pub enum Action {
 	Create,
 	Update,
 	Delete,
+	/// The resource which the LIVE query was defined within has been removed, ending the query
+	#[revision(start = 2)]
+	Remove,
 }
 
 impl Display for Action {
@@ -19,6 +22,7 @@ impl Display for Action {
 			Action::Create => write!(f, "CREATE"),
 			Action::Update => write!(f, "UPDATE"),
 			Action::Delete => write!(f, "DELETE"),
+			Action::Remove => write!(f, "REMOVE"),
 		}
 	}
 }
@@ -187,6 +187,12 @@ pub enum Error {
 		value: String,
 	},
 
+	/// The START clause percentage must be between 0 and 100
+	#[error("Found {value} but the START percentage must be between 0 and 100")]
+	InvalidStartPercentage {
+		value: String,
+	},
+
 	/// There was an error with the provided JavaScript code
 	#[error("Problem with embedded script function. {message}")]
 	InvalidScript {
@@ -279,6 +285,12 @@ pub enum Error {
 	#[error("The query was not executed due to a cancelled transaction")]
 	QueryCancelled,
 
+	/// The savepoint to rollback to does not exist in the transaction
+	#[error("The savepoint '{name}' does not exist in this transaction")]
+	SavepointNotFound {
+		name: String,
+	},
+
 	/// The query did not execute, because the memory threshold has been reached
 	#[error("The query was not executed due to the memory threshold being reached")]
 	QueryBeyondMemoryThreshold,
@@ -977,6 +989,12 @@ pub enum Error {
 		name: String,
 	},
 
+	/// The requested namespace is still being removed in the background
+	#[error("The namespace '{name}' is still being removed")]
+	NsRemovalPending {
+		name: String,
+	},
+
 	/// The requested param already exists
 	#[error("The param '${name}' already exists")]
 	PaAlreadyExists {
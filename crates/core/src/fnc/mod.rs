@@ -422,32 +422,46 @@ pub fn synchronous(
 		"time::from::uuid" => time::from::uuid,
 		"time::is::leap_year" => time::is::leap_year,
 		//
-		"type::array" => r#type::array,
+		"type::array" => r#type::array(ctx),
 		"type::bool" => r#type::bool,
 		"type::bytes" => r#type::bytes,
+		"type::bytes_from_string" => r#type::bytes_from_string,
+		"type::cast" => r#type::cast(ctx),
+		"type::coerce" => r#type::coerce(ctx),
 		"type::datetime" => r#type::datetime,
+		"type::datetime_from_unix" => r#type::datetime_from_unix,
 		"type::decimal" => r#type::decimal,
 		"type::duration" => r#type::duration,
+		"type::duration_from" => r#type::duration_from,
 		exp(Files) "type::file" => r#type::file,
+		exp(Files) "type::file_from_url" => r#type::file_from_url,
 		"type::float" => r#type::float,
 		"type::geometry" => r#type::geometry,
 		"type::int" => r#type::int,
+		"type::kind" => r#type::kind,
 		"type::number" => r#type::number,
 		"type::point" => r#type::point,
 		"type::range" => r#type::range,
 		"type::record" => r#type::record,
+		"type::set" => r#type::set,
 		"type::string" => r#type::string,
 		"type::string_lossy" => r#type::string_lossy,
 		"type::table" => r#type::table,
 		"type::thing" => r#type::thing,
+		"type::thing_range" => r#type::thing_range,
 		"type::uuid" => r#type::uuid,
+		"type::geometry::collection" => r#type::geometry::collection,
+		"type::geometry::line" => r#type::geometry::line,
+		"type::geometry::polygon" => r#type::geometry::polygon,
 		"type::is::array" => r#type::is::array,
 		"type::is::bool" => r#type::is::bool,
 		"type::is::bytes" => r#type::is::bytes,
+		"type::is::closure" => r#type::is::closure,
 		"type::is::collection" => r#type::is::collection,
 		"type::is::datetime" => r#type::is::datetime,
 		"type::is::decimal" => r#type::is::decimal,
 		"type::is::duration" => r#type::is::duration,
+		"type::is::file" => r#type::is::file,
 		"type::is::float" => r#type::is::float,
 		"type::is::geometry" => r#type::is::geometry,
 		"type::is::int" => r#type::is::int,
@@ -465,6 +479,8 @@ pub fn synchronous(
 		"type::is::record" => r#type::is::record,
 		"type::is::string" => r#type::is::string,
 		"type::is::uuid" => r#type::is::uuid,
+		"type::record::id" => r#type::record::id,
+		"type::record::table" => r#type::record::table,
 		//
 		"vector::add" => vector::add,
 		"vector::angle" => vector::angle,
@@ -923,6 +939,7 @@ pub async fn idiom(
 				"is_array" => r#type::is::array,
 				"is_bool" => r#type::is::bool,
 				"is_bytes" => r#type::is::bytes,
+				"is_closure" => r#type::is::closure,
 				"is_collection" => r#type::is::collection,
 				"is_datetime" => r#type::is::datetime,
 				"is_decimal" => r#type::is::decimal,
@@ -945,7 +962,7 @@ pub async fn idiom(
 				"is_string" => r#type::is::string,
 				"is_uuid" => r#type::is::uuid,
 				//
-				"to_array" => r#type::array,
+				"to_array" => r#type::array(ctx),
 				"to_bool" => r#type::bool,
 				"to_bytes" => r#type::bytes,
 				"to_datetime" => r#type::datetime,
@@ -14,22 +14,31 @@ impl_module_def!(
 	"array" => run,
 	"bool" => run,
 	"bytes" => run,
+	"bytes_from_string" => run,
+	"cast" => run,
+	"coerce" => run,
 	"datetime" => run,
+	"datetime_from_unix" => run,
 	"decimal" => run,
 	"duration" => run,
+	"duration_from" => run,
 	"field" => fut Async,
 	"fields" => fut Async,
 	"file" => run,
+	"file_from_url" => run,
 	"float" => run,
 	"int" => run,
 	"is" => (is::Package),
+	"kind" => run,
 	"number" => run,
 	"point" => run,
 	"regex" => run,
+	"set" => run,
 	"string" => run,
 	"string_lossy" => run,
 	"table" => run,
 	"thing" => run,
+	"thing_range" => run,
 	"range" => run,
 	"record" => run,
 	"uuid" => run,
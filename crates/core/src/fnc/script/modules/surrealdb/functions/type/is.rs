@@ -14,6 +14,7 @@ impl_module_def!(
 	"datetime" => run,
 	"decimal" => run,
 	"duration" => run,
+	"file" => run,
 	"float" => run,
 	"geometry" => run,
 	"int" => run,
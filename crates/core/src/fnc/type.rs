@@ -1,18 +1,45 @@
-use std::ops::Deref;
+use std::ops::{Bound, Deref};
 
 use crate::ctx::Context;
 use crate::dbs::Options;
 use crate::doc::CursorDoc;
 use crate::err::Error;
+use crate::sql::array::Uniq;
+use crate::sql::id::range::IdRange;
+use crate::sql::id::Id;
 use crate::sql::table::Table;
 use crate::sql::thing::Thing;
 use crate::sql::value::Value;
-use crate::sql::{File, FlowResultExt as _, Kind, Strand};
+use crate::sql::{Datetime, Duration, File, FlowResultExt as _, Geometry, Kind, Object, Strand};
 use crate::syn;
+use base64::engine::general_purpose::{STANDARD, STANDARD_NO_PAD};
+use base64::Engine;
+use chrono::DateTime;
 use reblessive::tree::Stk;
 
-pub fn array((val,): (Value,)) -> Result<Value, Error> {
-	val.convert_to_array().map(Value::from)
+/// Converts a value into an array, optionally converting every element into the kind given as
+/// a string, like `int` or `option<string>`.
+pub fn array(ctx: &Context, (val, kind): (Value, Option<String>)) -> Result<Value, Error> {
+	let val = val.convert_to_array()?;
+	let Some(kind) = kind else {
+		return Ok(val.into());
+	};
+	let kind = syn::kind_with_capabilities(&kind, &ctx.get_capabilities()).map_err(|_| {
+		Error::InvalidArguments {
+			name: String::from("type::array"),
+			message: format!("The second argument must be a valid kind, found '{kind}'."),
+		}
+	})?;
+	val.into_iter()
+		.enumerate()
+		.map(|(i, v)| {
+			v.convert_to(&kind).map_err(|e| Error::InvalidArguments {
+				name: String::from("type::array"),
+				message: format!("The element at index {i} could not be converted: {e}"),
+			})
+		})
+		.collect::<Result<Vec<_>, _>>()
+		.map(Value::from)
 }
 
 pub fn bool((val,): (Value,)) -> Result<Value, Error> {
@@ -23,14 +50,113 @@ pub fn file((bucket, key): (String, String)) -> Result<Value, Error> {
 	Ok(Value::File(File::new(bucket, key)))
 }
 
+/// Creates a file pointer from a URL like `scheme://bucket/key`, or from a string like
+/// `bucket:/key`.
+pub fn file_from_url((url,): (String,)) -> Result<Value, Error> {
+	let (bucket, key) = match url.split_once("://") {
+		Some((_, rest)) => rest.split_once('/').unwrap_or((rest, "")),
+		None => url.split_once(':').unwrap_or((&url, "")),
+	};
+	if bucket.is_empty() || key.trim_start_matches('/').is_empty() {
+		return Err(Error::InvalidArguments {
+			name: String::from("type::file_from_url"),
+			message: format!(
+				"The argument must be a URL containing a bucket and a key, found '{url}'."
+			),
+		});
+	}
+	Ok(Value::File(File::new(bucket.to_owned(), key.to_owned())))
+}
+
 pub fn bytes((val,): (Value,)) -> Result<Value, Error> {
 	val.convert_to_bytes().map(Value::from)
 }
 
+/// Creates bytes from a string in the encoding given as the second argument, one of `utf8`,
+/// `hex`, or `base64`. This is the inverse of `type::string_lossy` for `utf8` strings.
+pub fn bytes_from_string((val, encoding): (String, String)) -> Result<Value, Error> {
+	let invalid = |encoding: &str| Error::InvalidArguments {
+		name: String::from("type::bytes_from_string"),
+		message: format!("The first argument must be a valid {encoding} string."),
+	};
+	let res = match encoding.as_str() {
+		"utf8" => val.into_bytes(),
+		"hex" => hex::decode(val).map_err(|_| invalid("hex"))?,
+		// Padding is optional, as it is left out by `encoding::base64::encode`, but must be
+		// complete when given
+		"base64" => match val.ends_with('=') {
+			true => STANDARD.decode(val),
+			false => STANDARD_NO_PAD.decode(val),
+		}
+		.map_err(|_| invalid("base64"))?,
+		encoding => {
+			return Err(Error::InvalidArguments {
+				name: String::from("type::bytes_from_string"),
+				message: format!(
+					"The second argument must be one of 'utf8', 'hex', or 'base64', found '{encoding}'."
+				),
+			})
+		}
+	};
+	Ok(Value::Bytes(res.into()))
+}
+
+/// Converts a value into the kind given as a string, like `array<int>` or `option<string>`.
+pub fn cast(ctx: &Context, (val, kind): (Value, String)) -> Result<Value, Error> {
+	let kind = syn::kind_with_capabilities(&kind, &ctx.get_capabilities()).map_err(|_| {
+		Error::InvalidArguments {
+			name: String::from("type::cast"),
+			message: format!("The second argument must be a valid kind, found '{kind}'."),
+		}
+	})?;
+	val.convert_to(&kind)
+}
+
+/// Coerces a value into the kind given as a string, like `array<int>` or `option<string>`.
+///
+/// Unlike [`cast`], which will parse strings and convert between types where possible, this
+/// follows the rules used when enforcing field types, and only allows values which are already
+/// compatible with the kind, so `type::coerce("1", "int")` fails where `type::cast` succeeds.
+pub fn coerce(ctx: &Context, (val, kind): (Value, String)) -> Result<Value, Error> {
+	let kind = syn::kind_with_capabilities(&kind, &ctx.get_capabilities()).map_err(|_| {
+		Error::InvalidArguments {
+			name: String::from("type::coerce"),
+			message: format!("The second argument must be a valid kind, found '{kind}'."),
+		}
+	})?;
+	val.coerce_to(&kind)
+}
+
 pub fn datetime((val,): (Value,)) -> Result<Value, Error> {
 	val.convert_to_datetime().map(Value::from)
 }
 
+/// Creates a datetime from a Unix timestamp, in seconds, or in the unit given as the second
+/// argument, one of `s`, `ms`, `us`, or `ns`.
+pub fn datetime_from_unix((val, unit): (i64, Option<String>)) -> Result<Value, Error> {
+	let res = match unit.as_deref().unwrap_or("s") {
+		"s" => DateTime::from_timestamp(val, 0),
+		"ms" => DateTime::from_timestamp_millis(val),
+		"us" => DateTime::from_timestamp_micros(val),
+		"ns" => Some(DateTime::from_timestamp_nanos(val)),
+		unit => {
+			return Err(Error::InvalidArguments {
+				name: String::from("type::datetime_from_unix"),
+				message: format!(
+					"The second argument must be one of 's', 'ms', 'us', or 'ns', found '{unit}'."
+				),
+			})
+		}
+	};
+	match res {
+		Some(v) => Ok(Datetime::from(v).into()),
+		None => Err(Error::InvalidArguments {
+			name: String::from("type::datetime_from_unix"),
+			message: String::from("The first argument must be an in-bounds timestamp relative to January 1, 1970 0:00:00 UTC."),
+		}),
+	}
+}
+
 pub fn decimal((val,): (Value,)) -> Result<Value, Error> {
 	val.convert_to_decimal().map(Value::from)
 }
@@ -39,6 +165,42 @@ pub fn duration((val,): (Value,)) -> Result<Value, Error> {
 	val.convert_to_duration().map(Value::from)
 }
 
+/// Creates a duration from a number of the unit given as the second argument, one of `ns`, `us`,
+/// `ms`, `s`, `m`, `h`, `d`, or `w`.
+pub fn duration_from((val, unit): (i64, String)) -> Result<Value, Error> {
+	let Ok(val) = u64::try_from(val) else {
+		return Err(Error::InvalidArguments {
+			name: String::from("type::duration_from"),
+			message: String::from("The first argument must be a non-negative integer."),
+		});
+	};
+	let res = match unit.as_str() {
+		"ns" => Some(Duration::from_nanos(val)),
+		"us" => Some(Duration::from_micros(val)),
+		"ms" => Some(Duration::from_millis(val)),
+		"s" => Some(Duration::from_secs(val)),
+		"m" => Duration::from_mins(val),
+		"h" => Duration::from_hours(val),
+		"d" => Duration::from_days(val),
+		"w" => Duration::from_weeks(val),
+		unit => {
+			return Err(Error::InvalidArguments {
+				name: String::from("type::duration_from"),
+				message: format!(
+					"The second argument must be one of 'ns', 'us', 'ms', 's', 'm', 'h', 'd', or 'w', found '{unit}'."
+				),
+			})
+		}
+	};
+	match res {
+		Some(v) => Ok(v.into()),
+		None => Err(Error::InvalidArguments {
+			name: String::from("type::duration_from"),
+			message: String::from("The resulting duration is out of bounds."),
+		}),
+	}
+}
+
 pub async fn field(
 	(stk, ctx, opt, doc): (&mut Stk, &Context, Option<&Options>, Option<&CursorDoc>),
 	(val,): (String,),
@@ -85,12 +247,70 @@ pub fn int((val,): (Value,)) -> Result<Value, Error> {
 	val.convert_to_int().map(Value::from)
 }
 
-pub fn number((val,): (Value,)) -> Result<Value, Error> {
-	val.convert_to_number().map(Value::from)
+/// Returns the name of the kind of a value, including the table of a record and the type of a
+/// geometry, like `record<user>` or `geometry<point>`.
+pub fn kind((val,): (Value,)) -> Result<Value, Error> {
+	Ok(match val {
+		Value::Thing(v) => Kind::Record(vec![Table(v.tb)]).to_string().into(),
+		Value::File(_) => "file".into(),
+		Value::Table(_) => "table".into(),
+		Value::Regex(_) => "regex".into(),
+		v => v.kindof().into(),
+	})
+}
+
+/// Converts a value into a number. Numbers are returned as they are, while strings are parsed as
+/// an integer if they can be, and as a float otherwise, which loses the precision of decimals with
+/// more significant digits than a float can hold.
+///
+/// With the `preserve_decimal` option, as in `type::number($x, { preserve_decimal: true })`,
+/// strings which are not integers are parsed as a decimal instead, keeping up to 28 significant
+/// digits. Strings which are not valid decimals, like `1e400` or `NaN`, are still parsed as a float.
+pub fn number((val, opts): (Value, Option<Object>)) -> Result<Value, Error> {
+	let preserve_decimal = match opts.as_ref().and_then(|v| v.get("preserve_decimal")) {
+		None => false,
+		Some(Value::Bool(v)) => *v,
+		Some(v) => {
+			return Err(Error::InvalidArguments {
+				name: String::from("type::number"),
+				message: format!("The 'preserve_decimal' option must be a boolean, found {v}."),
+			})
+		}
+	};
+	match val {
+		Value::Strand(ref v) if preserve_decimal && v.parse::<i64>().is_err() => {
+			match val.clone().convert_to_decimal() {
+				Ok(v) => Ok(v.into()),
+				Err(_) => val.convert_to_number().map(Value::from),
+			}
+		}
+		val => val.convert_to_number().map(Value::from),
+	}
+}
+
+pub fn point((val, lon): (Value, Option<f64>)) -> Result<Value, Error> {
+	match lon {
+		Some(lon) => point_from_coords((val.coerce_to_f64()?, lon)),
+		None => val.convert_to_point().map(Value::from),
+	}
 }
 
-pub fn point((val,): (Value,)) -> Result<Value, Error> {
-	val.convert_to_point().map(Value::from)
+/// Creates a point from a latitude and a longitude in degrees, called as `type::point($lat, $lon)`.
+pub fn point_from_coords((lat, lon): (f64, f64)) -> Result<Value, Error> {
+	if !(-90.0..=90.0).contains(&lat) {
+		return Err(Error::InvalidArguments {
+			name: String::from("type::point"),
+			message: format!("The latitude must be between -90 and 90, found {lat}."),
+		});
+	}
+	if !(-180.0..=180.0).contains(&lon) {
+		return Err(Error::InvalidArguments {
+			name: String::from("type::point"),
+			message: format!("The longitude must be between -180 and 180, found {lon}."),
+		});
+	}
+	// Points are stored with the longitude as the first coordinate
+	Ok(Geometry::Point((lon, lat).into()).into())
 }
 
 pub fn range((val,): (Value,)) -> Result<Value, Error> {
@@ -115,6 +335,12 @@ pub fn record((rid, tb): (Value, Option<Value>)) -> Result<Value, Error> {
 	}
 }
 
+/// Converts a value into an array with duplicate values removed, keeping the first occurrence of
+/// each value.
+pub fn set((val,): (Value,)) -> Result<Value, Error> {
+	val.convert_to_array().map(Uniq::uniq).map(Value::from)
+}
+
 pub fn string((val,): (Value,)) -> Result<Value, Error> {
 	val.convert_to_strand().map(Value::from)
 }
@@ -172,15 +398,129 @@ pub fn thing((arg1, arg2): (Value, Option<Value>)) -> Result<Value, Error> {
 	}
 }
 
+/// Creates a record id range of a table, which can be used to scan the records between two ids.
+///
+/// A bound of `NONE` or `NULL` leaves that side of the range open. The third argument selects
+/// whether the bounds are included, one of `[)`, `[]`, `(]`, or `()`, defaulting to `[)` like
+/// `table:beg..end`.
+pub fn thing_range(
+	(tb, beg, end, bounds): (String, Value, Value, Option<String>),
+) -> Result<Value, Error> {
+	if tb.is_empty() {
+		return Err(Error::TbInvalid {
+			value: tb,
+		});
+	}
+	let (beg_incl, end_incl) = match bounds.as_deref().unwrap_or("[)") {
+		"[)" => (true, false),
+		"[]" => (true, true),
+		"(]" => (false, true),
+		"()" => (false, false),
+		bounds => {
+			return Err(Error::InvalidArguments {
+				name: String::from("type::thing_range"),
+				message: format!(
+					"The fourth argument must be one of '[)', '[]', '(]', or '()', found '{bounds}'."
+				),
+			})
+		}
+	};
+	let bound = |val: Value, incl: bool| -> Result<Bound<Id>, Error> {
+		let id = match val {
+			Value::None | Value::Null => return Ok(Bound::Unbounded),
+			Value::Thing(v) if v.tb == tb => v.id,
+			Value::Thing(v) => {
+				return Err(Error::InvalidArguments {
+					name: String::from("type::thing_range"),
+					message: format!("Expected a record of the table '{tb}', found {v}."),
+				})
+			}
+			v => Id::try_from(v)?,
+		};
+		Ok(match incl {
+			true => Bound::Included(id),
+			false => Bound::Excluded(id),
+		})
+	};
+	let range = IdRange::try_from((bound(beg, beg_incl)?, bound(end, end_incl)?))?;
+	Ok(Value::Thing(Thing {
+		tb,
+		id: Id::Range(Box::new(range)),
+	}))
+}
+
 pub fn uuid((val,): (Value,)) -> Result<Value, Error> {
 	val.convert_to_uuid().map(Value::from)
 }
 
+pub mod geometry {
+	use crate::err::Error;
+	use crate::sql::value::Value;
+	use crate::sql::{Array, Geometry};
+	use geo::{Coord, LineString, Polygon};
+
+	/// Creates a line from an array of at least two points.
+	pub fn line((points,): (Array,)) -> Result<Value, Error> {
+		let coords = coords("type::geometry::line", points)?;
+		if coords.len() < 2 {
+			return Err(Error::InvalidArguments {
+				name: String::from("type::geometry::line"),
+				message: String::from("The argument must be an array of at least 2 points."),
+			});
+		}
+		Ok(Geometry::Line(LineString::from(coords)).into())
+	}
+
+	/// Creates a polygon without holes from a closed ring of at least four points, where the first
+	/// and the last point are the same.
+	pub fn polygon((points,): (Array,)) -> Result<Value, Error> {
+		let coords = coords("type::geometry::polygon", points)?;
+		if coords.len() < 4 || coords.first() != coords.last() {
+			return Err(Error::InvalidArguments {
+				name: String::from("type::geometry::polygon"),
+				message: String::from(
+					"The argument must be an array of at least 4 points, starting and ending with the same point.",
+				),
+			});
+		}
+		Ok(Geometry::Polygon(Polygon::new(LineString::from(coords), vec![])).into())
+	}
+
+	/// Creates a geometry collection from an array of geometries.
+	pub fn collection((geometries,): (Array,)) -> Result<Value, Error> {
+		geometries
+			.into_iter()
+			.map(|v| match v {
+				Value::Geometry(v) => Ok(v),
+				v => Err(Error::InvalidArguments {
+					name: String::from("type::geometry::collection"),
+					message: format!("The argument must be an array of geometries, found {v}."),
+				}),
+			})
+			.collect::<Result<Vec<_>, _>>()
+			.map(|v| Geometry::Collection(v).into())
+	}
+
+	/// Converts every value of an array into the coordinates of a point.
+	fn coords(name: &str, points: Array) -> Result<Vec<Coord>, Error> {
+		points
+			.into_iter()
+			.map(|v| match v.convert_to_point() {
+				Ok(Geometry::Point(v)) => Ok(v.0),
+				_ => Err(Error::InvalidArguments {
+					name: name.to_owned(),
+					message: String::from("The argument must be an array of points."),
+				}),
+			})
+			.collect()
+	}
+}
+
 pub mod is {
 	use crate::err::Error;
 	use crate::sql::table::Table;
 	use crate::sql::value::Value;
-	use crate::sql::Geometry;
+	use crate::sql::{Geometry, Strand};
 
 	pub fn array((arg,): (Value,)) -> Result<Value, Error> {
 		Ok(arg.is_array().into())
@@ -194,6 +534,10 @@ pub mod is {
 		Ok(arg.is_bytes().into())
 	}
 
+	pub fn closure((arg,): (Value,)) -> Result<Value, Error> {
+		Ok(arg.is_closure().into())
+	}
+
 	pub fn collection((arg,): (Value,)) -> Result<Value, Error> {
 		Ok(matches!(arg, Value::Geometry(Geometry::Collection(_))).into())
 	}
@@ -210,12 +554,30 @@ pub mod is {
 		Ok(arg.is_duration().into())
 	}
 
+	pub fn file((arg,): (Value,)) -> Result<Value, Error> {
+		Ok(matches!(arg, Value::File(_)).into())
+	}
+
 	pub fn float((arg,): (Value,)) -> Result<Value, Error> {
 		Ok(arg.is_float().into())
 	}
 
-	pub fn geometry((arg,): (Value,)) -> Result<Value, Error> {
-		Ok(arg.is_geometry().into())
+	/// Checks whether the value is a geometry, optionally of the given subtype, one of `feature`,
+	/// `point`, `line`, `polygon`, `multipoint`, `multiline`, `multipolygon`, or `collection`.
+	pub fn geometry((arg, kind): (Value, Option<String>)) -> Result<Value, Error> {
+		match kind {
+			Some(kind) => match kind.as_str() {
+				"feature" | "point" | "line" | "polygon" | "multipoint" | "multiline"
+				| "multipolygon" | "collection" => Ok(arg.is_geometry_type(&[kind]).into()),
+				_ => Err(Error::InvalidArguments {
+					name: String::from("type::is::geometry"),
+					message: format!(
+						"The second argument must be a geometry type, one of 'feature', 'point', 'line', 'polygon', 'multipoint', 'multiline', 'multipolygon', or 'collection', found '{kind}'."
+					),
+				}),
+			},
+			None => Ok(arg.is_geometry().into()),
+		}
 	}
 
 	pub fn int((arg,): (Value,)) -> Result<Value, Error> {
@@ -266,11 +628,32 @@ pub mod is {
 		Ok(arg.is_range().into())
 	}
 
-	pub fn record((arg, table): (Value, Option<String>)) -> Result<Value, Error> {
-		Ok(match table {
-			Some(tb) => arg.is_record_type(&[Table(tb)]).into(),
-			None => arg.is_record().into(),
-		})
+	/// Checks whether the value is a record, optionally of the given table or any of the given
+	/// tables.
+	pub fn record((arg, table): (Value, Option<Value>)) -> Result<Value, Error> {
+		let tables = match table {
+			Some(Value::Strand(Strand(tb)) | Value::Table(Table(tb))) => vec![Table(tb)],
+			Some(Value::Array(tbs)) => tbs
+				.into_iter()
+				.map(|tb| match tb {
+					Value::Strand(Strand(tb)) | Value::Table(Table(tb)) => Ok(Table(tb)),
+					_ => Err(()),
+				})
+				.collect::<Result<Vec<_>, _>>()
+				.map_err(|_| Error::InvalidArguments {
+					name: "type::is::record".into(),
+					message: "The second argument must be an array of table names.".into(),
+				})?,
+			Some(_) => {
+				return Err(Error::InvalidArguments {
+					name: "type::is::record".into(),
+					message: "The second argument must be a table name or an array of table names."
+						.into(),
+				})
+			}
+			None => return Ok(arg.is_record().into()),
+		};
+		Ok(arg.is_record_type(&tables).into())
 	}
 
 	pub fn string((arg,): (Value,)) -> Result<Value, Error> {
@@ -282,10 +665,89 @@ pub mod is {
 	}
 }
 
+pub mod record {
+	use crate::err::Error;
+	use crate::sql::value::Value;
+
+	/// Returns the id part of a record id.
+	pub fn id((arg,): (Value,)) -> Result<Value, Error> {
+		match arg {
+			Value::Thing(v) => Ok(v.id.into()),
+			v => Err(Error::ConvertTo {
+				from: v,
+				into: "record".into(),
+			}),
+		}
+	}
+
+	/// Returns the table name of a record id.
+	pub fn table((arg,): (Value,)) -> Result<Value, Error> {
+		match arg {
+			Value::Thing(v) => Ok(v.tb.into()),
+			v => Err(Error::ConvertTo {
+				from: v,
+				into: "record".into(),
+			}),
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
+	use crate::ctx::MutableContext;
+	use crate::dbs::capabilities::ExperimentalTarget;
+	use crate::dbs::Capabilities;
 	use crate::err::Error;
 	use crate::sql::value::Value;
+	use std::sync::Arc;
+
+	#[test]
+	fn array_element_kind() {
+		let ctx = MutableContext::background().freeze();
+		let value = crate::syn::value("[1, '2', 3.0]").unwrap();
+		let value = super::array(&ctx, (value, Some("int".into()))).unwrap();
+		assert_eq!(value, crate::syn::value("[1, 2, 3]").unwrap());
+
+		let value = crate::syn::value("[1, 'two', 3]").unwrap();
+		match super::array(&ctx, (value, Some("int".into()))) {
+			Err(Error::InvalidArguments {
+				message,
+				..
+			}) => assert!(message.starts_with("The element at index 1 "), "{message}"),
+			res => panic!("expected an error, found {res:?}"),
+		}
+
+		super::array(&ctx, (Value::from(vec![1]), Some("notakind<".into()))).unwrap_err();
+	}
+
+	#[test]
+	fn cast_experimental_kind() {
+		let value = Value::from(vec![1]);
+		// Experimental kinds are rejected unless the capabilities allow them
+		let ctx = MutableContext::background().freeze();
+		match super::cast(&ctx, (value.clone(), "references".into())) {
+			Err(Error::InvalidArguments {
+				message,
+				..
+			}) => assert!(
+				message.starts_with("The second argument must be a valid kind"),
+				"{message}"
+			),
+			res => panic!("expected an error, found {res:?}"),
+		}
+
+		let mut ctx = MutableContext::background();
+		ctx.add_capabilities(Arc::new(
+			Capabilities::default().with_experimental(ExperimentalTarget::RecordReferences.into()),
+		));
+		let ctx = ctx.freeze();
+		match super::cast(&ctx, (value, "references".into())) {
+			Err(Error::CoerceTo {
+				..
+			}) => {}
+			res => panic!("expected a conversion error, found {res:?}"),
+		}
+	}
 
 	#[test]
 	fn is_array() {
@@ -296,6 +758,56 @@ mod tests {
 		assert_eq!(value, Value::Bool(false));
 	}
 
+	#[test]
+	fn is_closure() {
+		let closure = crate::syn::value("|$a: int| $a + 1").unwrap();
+		let value = super::is::closure((closure,)).unwrap();
+		assert_eq!(value, Value::Bool(true));
+
+		let value = super::is::closure(("|$a| $a".into(),)).unwrap();
+		assert_eq!(value, Value::Bool(false));
+	}
+
+	#[test]
+	fn is_geometry_subtype() {
+		let point = crate::syn::value("(-0.118092, 51.509865)").unwrap();
+		let value = super::is::geometry((point.clone(), None)).unwrap();
+		assert_eq!(value, Value::Bool(true));
+		let value = super::is::geometry((point.clone(), Some("point".into()))).unwrap();
+		assert_eq!(value, Value::Bool(true));
+		let value = super::is::geometry((point.clone(), Some("feature".into()))).unwrap();
+		assert_eq!(value, Value::Bool(true));
+		let value = super::is::geometry((point.clone(), Some("polygon".into()))).unwrap();
+		assert_eq!(value, Value::Bool(false));
+		let value = super::is::geometry(("test".into(), Some("point".into()))).unwrap();
+		assert_eq!(value, Value::Bool(false));
+		super::is::geometry((point, Some("square".into()))).unwrap_err();
+	}
+
+	#[test]
+	fn is_file() {
+		let value =
+			super::is::file((super::file(("bucket".into(), "key".into())).unwrap(),)).unwrap();
+		assert_eq!(value, Value::Bool(true));
+
+		let value = super::is::file(("bucket:/key".into(),)).unwrap();
+		assert_eq!(value, Value::Bool(false));
+	}
+
+	#[test]
+	fn record_parts() {
+		let thing = crate::syn::value("user:123").unwrap();
+		let value = super::record::id((thing.clone(),)).unwrap();
+		assert_eq!(value, Value::from(123));
+		let value = super::record::table((thing,)).unwrap();
+		assert_eq!(value, Value::from("user"));
+
+		let value = super::record::id(("user:123".into(),));
+		assert!(matches!(value, Err(Error::ConvertTo { .. })));
+		let value = super::record::table((Value::None,));
+		assert!(matches!(value, Err(Error::ConvertTo { .. })));
+	}
+
 	#[test]
 	fn no_empty_thing() {
 		let value = super::thing(("".into(), None));
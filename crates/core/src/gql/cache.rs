@@ -1,18 +1,24 @@
 use tokio::sync::RwLock;
 
-use std::{collections::BTreeMap, fmt::Debug, hash::Hash, marker::PhantomData, sync::Arc};
+use std::{collections::HashMap, fmt::Debug, hash::Hash, marker::PhantomData, sync::Arc};
 
 use async_graphql::dynamic::Schema;
 
 use crate::dbs::Session;
-use crate::kvs::Datastore;
+use crate::iam::{Action, Auth};
+use crate::kvs::{Datastore, LockType, TransactionType};
+use crate::sql::Uuid;
 
 use super::{error::GqlError, schema::generate_schema};
 
 pub trait Invalidator: Debug + Clone + Send + Sync + 'static {
 	type MetaData: Debug + Clone + Send + Sync + Hash;
 
-	fn is_valid(datastore: &Datastore, session: &Session, meta: &Self::MetaData) -> bool;
+	fn is_valid(
+		datastore: &Datastore,
+		session: &Session,
+		meta: &Self::MetaData,
+	) -> impl std::future::Future<Output = Result<bool, GqlError>> + std::marker::Send;
 
 	fn generate(
 		datastore: &Arc<Datastore>,
@@ -25,8 +31,12 @@ pub struct Pessimistic;
 impl Invalidator for Pessimistic {
 	type MetaData = ();
 
-	fn is_valid(_datastore: &Datastore, _session: &Session, _meta: &Self::MetaData) -> bool {
-		false
+	async fn is_valid(
+		_datastore: &Datastore,
+		_session: &Session,
+		_meta: &Self::MetaData,
+	) -> Result<bool, GqlError> {
+		Ok(false)
 	}
 
 	async fn generate(
@@ -43,8 +53,12 @@ pub struct Optimistic;
 impl Invalidator for Optimistic {
 	type MetaData = ();
 
-	fn is_valid(_datastore: &Datastore, _session: &Session, _meta: &Self::MetaData) -> bool {
-		true
+	async fn is_valid(
+		_datastore: &Datastore,
+		_session: &Session,
+		_meta: &Self::MetaData,
+	) -> Result<bool, GqlError> {
+		Ok(true)
 	}
 
 	async fn generate(
@@ -56,10 +70,52 @@ impl Invalidator for Optimistic {
 	}
 }
 
+/// Reuses a schema until any definitions in the datastore are changed.
+///
+/// The generation of the definitions is stored in the datastore, so
+/// definitions changed through any node of a cluster invalidate the schema.
+#[derive(Debug, Clone, Copy)]
+pub struct Generational;
+impl Invalidator for Generational {
+	type MetaData = Option<Uuid>;
+
+	async fn is_valid(
+		datastore: &Datastore,
+		_session: &Session,
+		meta: &Self::MetaData,
+	) -> Result<bool, GqlError> {
+		Ok(schema_generation(datastore).await? == *meta)
+	}
+
+	async fn generate(
+		datastore: &Arc<Datastore>,
+		session: &Session,
+	) -> Result<(Schema, Self::MetaData), GqlError> {
+		// Read the generation first, so that changes made while generating invalidate the schema
+		let generation = schema_generation(datastore).await?;
+		let schema = generate_schema(datastore, session).await?;
+		Ok((schema, generation))
+	}
+}
+
+/// Fetches the generation of the schema definitions of the datastore.
+async fn schema_generation(datastore: &Datastore) -> Result<Option<Uuid>, GqlError> {
+	let tx = datastore.transaction(TransactionType::Read, LockType::Optimistic).await?;
+	let res = tx.get_schema_generation().await;
+	tx.cancel().await?;
+	Ok(res?)
+}
+
+/// Caches the schema of each database for each authenticated actor.
+///
+/// Schemas depend on the session they were generated for, as fields are
+/// hidden from sessions whose permissions are checked and resolvers run with
+/// the session's auth. Sessions whose permissions are checked may depend on
+/// their `$auth` and `$token` parameters, so their schemas are never cached.
 #[derive(Clone)]
-pub struct SchemaCache<I: Invalidator = Pessimistic> {
+pub struct SchemaCache<I: Invalidator = Generational> {
 	#[allow(clippy::type_complexity)]
-	inner: Arc<RwLock<BTreeMap<(String, String), (Schema, I::MetaData)>>>,
+	inner: Arc<RwLock<HashMap<(String, String, Arc<Auth>), (Schema, I::MetaData)>>>,
 	pub datastore: Arc<Datastore>,
 	_invalidator: PhantomData<I>,
 }
@@ -84,22 +140,35 @@ impl<I: Invalidator> SchemaCache<I> {
 	pub async fn get_schema(&self, session: &Session) -> Result<Schema, GqlError> {
 		let ns = session.ns.as_ref().ok_or(GqlError::UnspecifiedNamespace)?;
 		let db = session.db.as_ref().ok_or(GqlError::UnspecifiedDatabase)?;
-		{
-			let guard = self.inner.read().await;
-			if let Some(cand) = guard.get(&(ns.to_owned(), db.to_owned())) {
-				if I::is_valid(&self.datastore, session, &cand.1) {
-					return Ok(cand.0.clone());
-				}
+		if self.datastore.setup_options(session).check_perms(Action::View)? {
+			let (schema, _) = I::generate(&self.datastore, session).await?;
+			return Ok(schema);
+		}
+		let key = (ns.to_owned(), db.to_owned(), session.au.clone());
+		let cand = self.inner.read().await.get(&key).cloned();
+		if let Some((schema, meta)) = cand {
+			if I::is_valid(&self.datastore, session, &meta).await? {
+				return Ok(schema);
 			}
-		};
+		}
 
 		let (schema, meta) = I::generate(&self.datastore, session).await?;
 
 		{
 			let mut guard = self.inner.write().await;
-			guard.insert((ns.to_owned(), db.to_owned()), (schema.clone(), meta));
+			guard.insert(key, (schema.clone(), meta));
 		}
 
 		Ok(schema)
 	}
+
+	/// Removes the cached schema of a database, forcing it to be rebuilt on the next request.
+	pub async fn invalidate(&self, ns: &str, db: &str) {
+		self.inner.write().await.retain(|(n, d, _), _| n != ns || d != db);
+	}
+
+	/// Removes all cached schemas, forcing them to be rebuilt on the next request.
+	pub async fn invalidate_all(&self) {
+		self.inner.write().await.clear();
+	}
 }
@@ -0,0 +1,68 @@
+//! Opaque cursors of connection edges, as described in the
+//! [Relay cursor connections specification](https://relay.dev/graphql/connections.htm).
+//!
+//! A cursor is the base64 encoded id of the record at the edge. Clients must not rely on this
+//! encoding, it only has to round-trip through [`encode_cursor`] and [`decode_cursor`].
+
+use super::error::{input_error, GqlError};
+use crate::sql::Thing;
+use crate::syn;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+/// Encodes the id of the record at an edge into a cursor.
+pub(crate) fn encode_cursor(thing: &Thing) -> String {
+    STANDARD.encode(thing.to_string())
+}
+
+/// Decodes a cursor created by [`encode_cursor`] back into the id of the record at the edge.
+pub(crate) fn decode_cursor(cursor: &str) -> Result<Thing, GqlError> {
+    STANDARD
+        .decode(cursor)
+        .ok()
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+        .and_then(|s| syn::thing(&s).ok())
+        .ok_or_else(|| input_error(format!("Invalid cursor: {cursor}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sql::{Array, Id, Object, Value};
+
+    #[test]
+    fn round_trip() {
+        let things = [
+            Thing::from(("foo", "1")),
+            Thing::from(("foo", Id::from(42))),
+            Thing::from(("foo bar", "with spaces and ⟨brackets⟩")),
+            Thing::from(("foo", Id::Array(Array::from(vec![Value::from(1), Value::from("a")])))),
+            Thing::from((
+                "foo",
+                Id::Object(Object::from(map! { "a".to_string() => Value::from(1) })),
+            )),
+        ];
+        for thing in things {
+            let cursor = encode_cursor(&thing);
+            assert!(!cursor.contains(&thing.tb), "cursor should be opaque: {cursor}");
+            assert_eq!(decode_cursor(&cursor).unwrap(), thing);
+        }
+    }
+
+    #[test]
+    fn tampered_cursor() {
+        let cursor = encode_cursor(&Thing::from(("foo", "1")));
+
+        // Not valid base64
+        let tampered = format!("{cursor}!");
+        assert!(matches!(decode_cursor(&tampered), Err(GqlError::InputError(_))));
+
+        // Valid base64, but not a record id
+        let tampered = STANDARD.encode("foo");
+        assert!(matches!(decode_cursor(&tampered), Err(GqlError::InputError(_))));
+
+        // A record id with trailing data
+        let tampered = STANDARD.encode("foo:1; DELETE foo");
+        assert!(matches!(decode_cursor(&tampered), Err(GqlError::InputError(_))));
+    }
+}
@@ -1,6 +1,8 @@
-use std::{backtrace, fmt::Debug};
+use std::sync::Arc;
+use std::{backtrace, fmt::Debug, fmt::Display};
 
-use async_graphql::{InputType, InputValueError};
+use async_graphql::extensions::{Extension, ExtensionContext, ExtensionFactory, NextExecute};
+use async_graphql::{ErrorExtensionValues, ErrorExtensions, InputType, InputValueError, Response};
 use thiserror::Error;
 
 use crate::sql::Kind;
@@ -28,6 +30,119 @@ pub enum GqlError {
         target: Kind,
         val: async_graphql::Value,
     },
+    #[error("Query {limit} of {found} exceeds the limit of {max}")]
+    LimitExceeded {
+        limit: &'static str,
+        max: usize,
+        found: usize,
+    },
+    #[error("{source} at `{path}`")]
+    AtPath {
+        /// The path of the value which failed, e.g. `user.size.width` or `user.tags[1]`.
+        path: String,
+        source: Box<GqlError>,
+    },
+}
+
+impl GqlError {
+    /// A machine readable code identifying the kind of error, which is exposed to clients in the
+    /// `code` extension of GraphQL errors.
+    pub fn code(&self) -> &'static str {
+        match self {
+            GqlError::DbError(_) => "DATABASE_ERROR",
+            GqlError::SchemaError(_) => "SCHEMA_ERROR",
+            GqlError::ResolverError(_) => "RESOLVER_ERROR",
+            GqlError::UnspecifiedNamespace => "UNSPECIFIED_NAMESPACE",
+            GqlError::UnspecifiedDatabase => "UNSPECIFIED_DATABASE",
+            GqlError::NotConfigured => "NOT_CONFIGURED",
+            GqlError::InternalError(_) => "INTERNAL_ERROR",
+            GqlError::InputError(_) => "INPUT_ERROR",
+            GqlError::TypeError { .. } => "TYPE_ERROR",
+            GqlError::LimitExceeded { .. } => "LIMIT_EXCEEDED",
+            GqlError::AtPath { source, .. } => source.code(),
+        }
+    }
+
+    /// Marks the error as having occurred within the field with the given name, prepending it to
+    /// the path of the error. Errors are given their path while they bubble up from the nested
+    /// value which failed, so that the path is only built when an error occurs.
+    pub(crate) fn in_field(self, name: impl Display) -> Self {
+        self.prepend_path(name.to_string())
+    }
+
+    /// Marks the error as having occurred within the list item at the given index.
+    pub(crate) fn at_index(self, idx: usize) -> Self {
+        self.prepend_path(format!("[{idx}]"))
+    }
+
+    fn prepend_path(self, segment: String) -> Self {
+        match self {
+            GqlError::AtPath { path, source } => {
+                let path = match path.starts_with('[') {
+                    true => segment + &path,
+                    false => format!("{segment}.{path}"),
+                };
+                GqlError::AtPath { path, source }
+            }
+            e => GqlError::AtPath { path: segment, source: Box::new(e) },
+        }
+    }
+
+    pub(crate) fn set_extensions(&self, ext: &mut ErrorExtensionValues) {
+        ext.set("code", self.code());
+        match self {
+            GqlError::TypeError { target, val } => {
+                ext.set("target", target.to_string());
+                ext.set("value", val.clone());
+            }
+            GqlError::LimitExceeded { limit, max, .. } => {
+                ext.set("limit", *limit);
+                ext.set("max", *max as u64);
+            }
+            GqlError::AtPath { path, source } => {
+                source.set_extensions(ext);
+                ext.set("valuePath", path.as_str());
+            }
+            _ => {}
+        }
+    }
+}
+
+impl ErrorExtensions for GqlError {
+    fn extend(&self) -> async_graphql::Error {
+        async_graphql::Error::new(self.to_string()).extend_with(|_, ext| self.set_extensions(ext))
+    }
+}
+
+/// Schema extension adding the extensions of [`GqlError`]s to the errors of a response.
+///
+/// Resolvers return `GqlError`s through `?`, which only keeps the error as the source of the
+/// GraphQL error, so the extensions are added once the request has been executed.
+pub(crate) struct ErrorCodes;
+
+impl ExtensionFactory for ErrorCodes {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(ErrorCodes)
+    }
+}
+
+#[async_graphql::async_trait::async_trait]
+impl Extension for ErrorCodes {
+    async fn execute(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        operation_name: Option<&str>,
+        next: NextExecute<'_>,
+    ) -> Response {
+        let mut res = next.run(ctx, operation_name).await;
+        for err in res.errors.iter_mut() {
+            let Some(source) = err.source.clone() else { continue };
+            if let Some(e) = source.downcast_ref::<GqlError>() {
+                e.set_extensions(err.extensions.get_or_insert_with(Default::default));
+            }
+        }
+        res
+    }
 }
 
 pub fn schema_error(msg: impl Into<String>) -> GqlError {
@@ -1,5 +1,7 @@
 use std::ops::Deref;
 
+use async_graphql::dynamic::Type;
+
 use crate::sql::statements::define::config::graphql::TableConfig;
 use crate::sql::statements::DefineTableStatement;
 use crate::sql::{statements::UseStatement, Cond, Ident, Idiom, Limit, Part, Start, Table, Value};
@@ -43,7 +45,7 @@ where
     V: Into<Value>,
 {
     fn from(value: V) -> Self {
-        Start(value.into())
+        Start(value.into(), false)
     }
 }
 
@@ -192,6 +194,21 @@ impl Named for TableConfig {
     }
 }
 
+impl Named for Type {
+    fn name(&self) -> &str {
+        match self {
+            Type::Scalar(s) => s.type_name(),
+            Type::Object(o) => o.type_name(),
+            Type::InputObject(i) => i.type_name(),
+            Type::Enum(e) => e.type_name(),
+            Type::Interface(i) => i.type_name(),
+            Type::Union(u) => u.type_name(),
+            Type::Subscription(s) => s.type_name(),
+            Type::Upload => "Upload",
+        }
+    }
+}
+
 pub trait NamedContainer {
     fn contains_name(&self, name: &str) -> bool;
 }
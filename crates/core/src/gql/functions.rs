@@ -7,12 +7,14 @@ use crate::dbs::Session;
 use crate::gql::schema::kind_to_type;
 use crate::gql::utils::GQLTx;
 use crate::kvs::Datastore;
+use crate::sql::statements::define::config::graphql::GqlNaming;
 use crate::sql::statements::DefineFunctionStatement;
 use crate::sql::{Kind, Value as SqlValue};
 use async_graphql::dynamic::FieldFuture;
 use async_graphql::dynamic::InputValue;
 use async_graphql::dynamic::Object;
 use async_graphql::dynamic::Type;
+use async_graphql::dynamic::TypeRef;
 use async_graphql::dynamic::{Field, FieldValue};
 
 //FIXME: usage of kind to type
@@ -22,19 +24,29 @@ pub async fn process_fns(
     types: &mut Vec<Type>,
     session: &Session,
     datastore: &Arc<Datastore>,
+    naming: GqlNaming,
 ) -> Result<Object, GqlError> {
     for fnd in fns.iter() {
         let Some(kind) = &fnd.returns else {
-            // TODO: handle case where there are no typed functions and give graceful error
+            warn!("Skipping function `fn::{}` in the GraphQL schema as it has no return type", fnd.name);
             continue;
         };
+        // Functions with signatures that can not be represented in GraphQL are left out, instead
+        // of failing the generation of the whole schema.
+        let (ret_ty, arg_tys) = match signature_types(fnd, kind, types, naming) {
+            Ok(tys) => tys,
+            Err(e) => {
+                warn!("Skipping function `fn::{}` in the GraphQL schema: {e}", fnd.name);
+                continue;
+            }
+        };
         let sess1 = session.clone();
         let kvs1 = datastore.clone();
         let fnd1 = fnd.clone();
         let kind1 = kind.clone();
         let mut field = Field::new(
             format!("fn_{}", fnd.name),
-            kind_to_type(kind.clone(), types, &[&fnd1.name])?,
+            ret_ty,
             move |ctx| {
                 let sess1 = sess1.clone();
                 let kvs1 = kvs1.clone();
@@ -47,7 +59,8 @@ pub async fn process_fns(
 
                     for (arg_name, arg_kind) in fnd1.args {
                         if let Some(arg_val) = gql_args.get(arg_name.as_str()) {
-                            let arg_val = gql_to_sql_kind(arg_val, arg_kind)?;
+                            let arg_val = gql_to_sql_kind(arg_val, arg_kind)
+                                .map_err(|e| e.in_field(&arg_name).in_field(format!("fn::{}", fnd1.name)))?;
                             args.push(arg_val);
                         } else {
                             args.push(SqlValue::None);
@@ -61,7 +74,7 @@ pub async fn process_fns(
                             let mut tmp = field_val_erase_owned((gtx.clone(), rid.clone()));
                             match kind1 {
                                 Kind::Record(ts) if ts.len() != 1 => {
-                                    tmp = tmp.with_type(rid.tb.clone())
+                                    tmp = tmp.with_type(naming.type_name(&rid.tb))
                                 }
                                 _ => {}
                             }
@@ -76,9 +89,7 @@ pub async fn process_fns(
             },
         );
 
-        let fnd2 = fnd.clone();
-        for (arg_name, arg_kind) in fnd2.args {
-            let arg_ty = kind_to_type(arg_kind.clone(), types, &[&fnd2.name])?;
+        for ((arg_name, _), arg_ty) in fnd.args.iter().zip(arg_tys) {
             field = field.argument(InputValue::new(&arg_name.0, arg_ty))
         }
 
@@ -87,3 +98,19 @@ pub async fn process_fns(
 
     Ok(query)
 }
+
+/// Returns the GraphQL types of the return value and the arguments of a function.
+fn signature_types(
+    fnd: &DefineFunctionStatement,
+    returns: &Kind,
+    types: &mut Vec<Type>,
+    naming: GqlNaming,
+) -> Result<(TypeRef, Vec<TypeRef>), GqlError> {
+    let ret_ty = kind_to_type(returns.clone(), types, &[&fnd.name], naming)?;
+    let arg_tys = fnd
+        .args
+        .iter()
+        .map(|(_, arg_kind)| kind_to_type(arg_kind.clone(), types, &[&fnd.name], naming))
+        .collect::<Result<_, _>>()?;
+    Ok((ret_ty, arg_tys))
+}
@@ -35,22 +35,60 @@ pub fn polygon_to_list(polygon: &Polygon) -> Result<GqlValue, GqlError> {
     ))
 }
 
-pub fn geometry_kind_name_to_type_name(name: &str) -> Result<&'static str, GqlError> {
+/// Returns the GeoJSON type of the geometries of the given `geometry<name>` kind.
+pub fn geometry_kind_name_to_geojson_type(name: &str) -> Result<&'static str, GqlError> {
     match name {
-        "point" => Ok("GeometryPoint"),
-        "line" => Ok("GeometryLineString"),
-        "polygon" => Ok("GeometryPolygon"),
-        "multipoint" => Ok("GeometryMultiPoint"),
-        "multiline" => Ok("GeometryMultiLineString"),
-        "multipolygon" => Ok("GeometryMultiPolygon"),
+        "point" => Ok("Point"),
+        "line" => Ok("LineString"),
+        "polygon" => Ok("Polygon"),
+        "multipoint" => Ok("MultiPoint"),
+        "multiline" => Ok("MultiLineString"),
+        "multipolygon" => Ok("MultiPolygon"),
         "collection" => Ok("GeometryCollection"),
         _ => Err(internal_error("expected valid geometry name")),
     }
 }
 
+/// Converts a geometry into its GeoJSON object representation.
+pub fn geometry_to_geojson(geometry: &Geometry) -> Result<GqlValue, GqlError> {
+    let (key, value) = match geometry {
+        Geometry::Point(point) => ("coordinates", coord_to_list(point.0)?),
+        Geometry::Line(line) => ("coordinates", coord_collection_to_list(line.0.iter().copied())?),
+        Geometry::Polygon(polygon) => ("coordinates", polygon_to_list(polygon)?),
+        Geometry::MultiPoint(multipoint) => {
+            ("coordinates", coord_collection_to_list(multipoint.iter().map(|p| p.0))?)
+        }
+        Geometry::MultiLine(multiline) => (
+            "coordinates",
+            GqlValue::List(
+                multiline
+                    .iter()
+                    .map(|l| coord_collection_to_list(l.0.iter().copied()))
+                    .collect::<Result<_, _>>()?,
+            ),
+        ),
+        Geometry::MultiPolygon(multipolygon) => (
+            "coordinates",
+            GqlValue::List(multipolygon.iter().map(polygon_to_list).collect::<Result<_, _>>()?),
+        ),
+        Geometry::Collection(collection) => (
+            "geometries",
+            GqlValue::List(collection.iter().map(geometry_to_geojson).collect::<Result<_, _>>()?),
+        ),
+    };
+    Ok(GqlValue::Object(
+        [
+            (Name::new("type"), GqlValue::String(geometry.as_type().to_string())),
+            (Name::new(key), value),
+        ]
+            .into_iter()
+            .collect(),
+    ))
+}
+
 fn extract_coord(arr: &[GqlValue]) -> Option<Coord> {
     match arr {
-        [GqlValue::Number(y), GqlValue::Number(x)] => Some(Coord {
+        [GqlValue::Number(x), GqlValue::Number(y)] => Some(Coord {
             x: x.as_f64()?,
             y: y.as_f64()?,
         }),
@@ -92,6 +130,7 @@ fn extract_polygon_list(arr: &[GqlValue]) -> Option<Vec<Polygon>> {
         .collect()
 }
 
+/// Parses a GeoJSON object into a geometry.
 pub fn extract_geometry(map: &IndexMap<Name, GqlValue>) -> Option<Geometry> {
     let ty = match map.get("type") {
         Some(GqlValue::String(ty)) => Some(ty),
@@ -109,17 +148,17 @@ pub fn extract_geometry(map: &IndexMap<Name, GqlValue>) -> Option<Geometry> {
     };
 
     match ty?.as_str() {
-        "GeometryPoint" => Some(Geometry::Point(Point(extract_coord(coordinates?).unwrap()))),
-        "GeometryLineString" => Some(Geometry::Line(LineString(extract_coord_list(coordinates?)?))),
-        "GeometryPolygon" => Some(Geometry::Polygon(extract_polygon(coordinates?)?)),
-        "GeometryMultiPoint" => Some(Geometry::MultiPoint(MultiPoint(
-            extract_coord_list(&coordinates?)?.into_iter().map(Point).collect(),
+        "Point" => Some(Geometry::Point(Point(extract_coord(coordinates?)?))),
+        "LineString" => Some(Geometry::Line(LineString(extract_coord_list(coordinates?)?))),
+        "Polygon" => Some(Geometry::Polygon(extract_polygon(coordinates?)?)),
+        "MultiPoint" => Some(Geometry::MultiPoint(MultiPoint(
+            extract_coord_list(coordinates?)?.into_iter().map(Point).collect(),
         ))),
-        "GeometryMultiLineString" => Some(Geometry::MultiLine(MultiLineString(
-            extract_coord_list_list(&coordinates?)?.into_iter().map(LineString).collect(),
+        "MultiLineString" => Some(Geometry::MultiLine(MultiLineString(
+            extract_coord_list_list(coordinates?)?.into_iter().map(LineString).collect(),
         ))),
-        "GeometryMultiPolygon" => {
-            Some(Geometry::MultiPolygon(MultiPolygon(extract_polygon_list(&coordinates?)?)))
+        "MultiPolygon" => {
+            Some(Geometry::MultiPolygon(MultiPolygon(extract_polygon_list(coordinates?)?)))
         }
         "GeometryCollection" => Some(Geometry::Collection(
             geometries?
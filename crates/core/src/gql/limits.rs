@@ -0,0 +1,50 @@
+//! Limits on the depth and complexity of queries, which guard against queries resolving to more
+//! records than the database should read for a single request.
+
+use std::sync::Arc;
+
+use async_graphql::extensions::{Extension, ExtensionContext, ExtensionFactory, NextValidation};
+use async_graphql::{ServerError, ValidationResult};
+
+use super::error::GqlError;
+
+/// Schema extension rejecting queries which exceed the limits of the GraphQL config once they are
+/// validated, before any of their fields are resolved.
+#[derive(Clone, Copy)]
+pub(crate) struct QueryLimits {
+    pub depth: Option<usize>,
+    pub complexity: Option<usize>,
+}
+
+impl ExtensionFactory for QueryLimits {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(*self)
+    }
+}
+
+#[async_graphql::async_trait::async_trait]
+impl Extension for QueryLimits {
+    async fn validation(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        next: NextValidation<'_>,
+    ) -> Result<ValidationResult, Vec<ServerError>> {
+        let res = next.run(ctx).await?;
+        let limits = [
+            ("depth", self.depth, res.depth),
+            ("complexity", self.complexity, res.complexity),
+        ];
+        for (limit, max, found) in limits {
+            match max {
+                Some(max) if found > max => {
+                    let err = GqlError::LimitExceeded { limit, max, found };
+                    let mut server_err = ServerError::new(err.to_string(), None);
+                    err.set_extensions(server_err.extensions.get_or_insert_with(Default::default));
+                    return Err(vec![server_err]);
+                }
+                _ => {}
+            }
+        }
+        Ok(res)
+    }
+}
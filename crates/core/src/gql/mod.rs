@@ -1,9 +1,13 @@
 #![cfg(not(target_family = "wasm"))]
 
 pub mod cache;
+mod cursor;
 pub mod error;
 mod ext;
 mod functions;
+mod limits;
+mod mutations;
+mod naming;
 pub mod schema;
 mod tables;
 mod utils;
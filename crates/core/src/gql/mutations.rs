@@ -0,0 +1,338 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use super::error::{input_error, internal_error, GqlError};
+use super::ext::IntoExt;
+use super::naming::NameScope;
+use super::schema::gql_to_sql_kind;
+use super::utils::{
+    check_field_names, field_gql_description, field_gql_name, field_val_erase_owned,
+    parse_record_id, with_comment, ErasedRecord, GQLTx, GqlValueUtils,
+};
+use crate::dbs::Session;
+use crate::kvs::{Datastore, Transaction};
+use crate::sql::paths::ID;
+use crate::sql::statements::define::config::graphql::GqlNaming;
+use crate::sql::statements::{
+    CreateStatement, DefineFieldStatement, DefineTableStatement, DeleteStatement, UpdateStatement,
+};
+use crate::sql::{Data, Kind, Output, Part, Statement, TableType, Thing};
+use crate::sql::{Value as SqlValue, Values};
+use async_graphql::dynamic::indexmap::IndexMap;
+use async_graphql::dynamic::{Field, FieldFuture, FieldValue, InputObject, InputValue, Object};
+use async_graphql::dynamic::{Type, TypeRef};
+use async_graphql::Name;
+use async_graphql::Value as GqlValue;
+
+/// Adds `create`, `update` and `delete` mutations for every normal table to the mutation object.
+///
+/// Returns `None` if there are no normal tables, as the schema must not contain an empty
+/// mutation object.
+#[allow(clippy::too_many_arguments)]
+pub async fn process_mutations(
+    tbs: &[DefineTableStatement],
+    mut mutation: Object,
+    types: &mut Vec<Type>,
+    tx: &Transaction,
+    ns: &str,
+    db: &str,
+    session: &Session,
+    datastore: &Arc<Datastore>,
+    naming: GqlNaming,
+) -> Result<Option<Object>, GqlError> {
+    let tables = tbs.iter().filter(|tb| matches!(tb.kind, TableType::Normal)).collect::<Vec<_>>();
+    if tables.is_empty() {
+        return Ok(None);
+    }
+
+    let mut names = NameScope::default();
+    for tb in tables {
+        let tb_name = tb.name.to_string();
+        let tb_name_gql = naming.type_name(&tb_name);
+
+        let fds = tx.all_tb_fields(ns, db, &tb.name.0, None).await?;
+        let fds: Arc<[DefineFieldStatement]> = fds.iter().filter(|fd| is_input_field(fd)).cloned().collect();
+        check_field_names(&tb_name, &fds, naming)?;
+
+        // =======================================================
+        // Add input types
+        // =======================================================
+
+        let create_input_name = naming.type_name(&format!("{tb_name}_create_input"));
+        let mut create_input = InputObject::new(&create_input_name).description(with_comment(
+            format!("The values of a record created in the table `{tb_name}`."),
+            tb.comment.as_deref(),
+        ));
+        let update_input_name = naming.type_name(&format!("{tb_name}_update_input"));
+        let mut update_input = InputObject::new(&update_input_name).description(with_comment(
+            format!("The values of a record updated in the table `{tb_name}`."),
+            tb.comment.as_deref(),
+        ));
+
+        for fd in fds.iter() {
+            let Some(ref kind) = fd.kind else { continue; };
+            let ty = kind_to_input_type(kind);
+            let fd_name = field_gql_name(fd, naming)?;
+
+            // Fields without a value to fall back on must be provided when creating a record
+            let required = !matches!(kind, Kind::Option(_) | Kind::Any)
+                && fd.default.is_none()
+                && fd.value.is_none();
+            let create_ty = match required {
+                true => TypeRef::NonNull(Box::new(ty.clone())),
+                false => ty.clone(),
+            };
+            let description = field_gql_description(fd);
+            create_input = create_input
+                .field(InputValue::new(&fd_name, create_ty).description(&description));
+
+            if !fd.readonly {
+                update_input =
+                    update_input.field(InputValue::new(&fd_name, ty).description(description));
+            }
+        }
+
+        types.push(Type::InputObject(create_input));
+        types.push(Type::InputObject(update_input));
+
+        // =======================================================
+        // Add create mutation
+        // =======================================================
+
+        let create_name = naming.field_name(&format!("create_{tb_name}"));
+        names.claim(&create_name, format!("the create mutation of the table `{tb_name}`"))?;
+
+        let sess1 = session.to_owned();
+        let kvs1 = datastore.clone();
+        let fds1 = fds.clone();
+        let tb_name1 = tb_name.clone();
+
+        mutation = mutation.field(
+            Field::new(
+                create_name,
+                TypeRef::named_nn(&tb_name_gql),
+                move |ctx| {
+                    let sess1 = sess1.clone();
+                    let kvs1 = kvs1.clone();
+                    let fds1 = fds1.clone();
+                    let tb_name = tb_name1.clone();
+                    FieldFuture::new(async move {
+                        let args = ctx.args.as_index_map();
+                        let content = input_to_object(args.get("input"), &tb_name, &fds1, naming)?;
+
+                        // CREATE $tb CONTENT $content
+                        let ast = Statement::Create(CreateStatement {
+                            what: Values(vec![SqlValue::Table(tb_name.intox())]),
+                            data: Some(Data::ContentExpression(content)),
+                            ..Default::default()
+                        });
+                        trace!("generated mutation ast: {ast:?}");
+
+                        match process_mutation(&kvs1, &sess1, ast).await? {
+                            Some(t) => {
+                                let gtx = GQLTx::new(&kvs1, &sess1).await?;
+                                let erased: ErasedRecord = (gtx, t);
+                                Ok(Some(field_val_erase_owned(erased)))
+                            }
+                            None => Err(internal_error("Created record was not returned").into()),
+                        }
+                    })
+                },
+            )
+                .description(format!("Creates a record in the table `{tb_name}`."))
+                .argument(InputValue::new("input", TypeRef::named_nn(&create_input_name))),
+        );
+
+        // =======================================================
+        // Add update mutation
+        // =======================================================
+
+        let update_name = naming.field_name(&format!("update_{tb_name}"));
+        names.claim(&update_name, format!("the update mutation of the table `{tb_name}`"))?;
+
+        let sess2 = session.to_owned();
+        let kvs2 = datastore.clone();
+        let fds2 = fds.clone();
+        let tb_name2 = tb_name.clone();
+
+        mutation = mutation.field(
+            Field::new(
+                update_name,
+                TypeRef::named(&tb_name_gql),
+                move |ctx| {
+                    let sess2 = sess2.clone();
+                    let kvs2 = kvs2.clone();
+                    let fds2 = fds2.clone();
+                    let tb_name = tb_name2.clone();
+                    FieldFuture::new(async move {
+                        let args = ctx.args.as_index_map();
+                        let thing = thing_from_args(args, &tb_name)?;
+                        let content = input_to_object(args.get("input"), &tb_name, &fds2, naming)?;
+
+                        // UPDATE $thing MERGE $content
+                        let ast = Statement::Update(UpdateStatement {
+                            what: Values(vec![SqlValue::Thing(thing)]),
+                            data: Some(Data::MergeExpression(content)),
+                            ..Default::default()
+                        });
+                        trace!("generated mutation ast: {ast:?}");
+
+                        match process_mutation(&kvs2, &sess2, ast).await? {
+                            Some(t) => {
+                                let gtx = GQLTx::new(&kvs2, &sess2).await?;
+                                let erased: ErasedRecord = (gtx, t);
+                                Ok(Some(field_val_erase_owned(erased)))
+                            }
+                            None => Ok(None),
+                        }
+                    })
+                },
+            )
+                .description(format!(
+                    "Updates a record in the table `{tb_name}`, returns null if the record does not exist."
+                ))
+                .argument(InputValue::new("id", TypeRef::named_nn(TypeRef::ID)))
+                .argument(InputValue::new("input", TypeRef::named_nn(&update_input_name))),
+        );
+
+        // =======================================================
+        // Add delete mutation
+        // =======================================================
+
+        let delete_name = naming.field_name(&format!("delete_{tb_name}"));
+        names.claim(&delete_name, format!("the delete mutation of the table `{tb_name}`"))?;
+
+        let sess3 = session.to_owned();
+        let kvs3 = datastore.clone();
+        let tb_name3 = tb_name.clone();
+
+        mutation = mutation.field(
+            Field::new(
+                delete_name,
+                TypeRef::named(TypeRef::ID),
+                move |ctx| {
+                    let sess3 = sess3.clone();
+                    let kvs3 = kvs3.clone();
+                    let tb_name = tb_name3.clone();
+                    FieldFuture::new(async move {
+                        let args = ctx.args.as_index_map();
+                        let thing = thing_from_args(args, &tb_name)?;
+
+                        // DELETE $thing RETURN BEFORE
+                        let ast = Statement::Delete(DeleteStatement {
+                            what: Values(vec![SqlValue::Thing(thing)]),
+                            output: Some(Output::Before),
+                            ..Default::default()
+                        });
+                        trace!("generated mutation ast: {ast:?}");
+
+                        let deleted = process_mutation(&kvs3, &sess3, ast).await?;
+                        Ok(deleted.map(|t| FieldValue::value(GqlValue::String(t.to_string()))))
+                    })
+                },
+            )
+                .description(format!(
+                    "Deletes a record in the table `{tb_name}`, returns the ID of the deleted record or null if the record does not exist."
+                ))
+                .argument(InputValue::new("id", TypeRef::named_nn(TypeRef::ID))),
+        );
+    }
+
+    Ok(Some(mutation))
+}
+
+/// Whether the field can be set through mutation inputs. Only top level fields are used as
+/// nested fields are set through their parent object.
+fn is_input_field(fd: &DefineFieldStatement) -> bool {
+    !fd.name.is_id()
+        && matches!(fd.name.0.as_slice(), [Part::Field(_)])
+        && !matches!(fd.kind, None | Some(Kind::References(_, _)))
+}
+
+/// Returns the name of a top level field.
+fn field_name(fd: &DefineFieldStatement) -> String {
+    match fd.name.0.as_slice() {
+        [Part::Field(ident)] => ident.to_raw(),
+        _ => fd.name.to_string(),
+    }
+}
+
+/// Returns the input type of a field of the given kind.
+///
+/// Kinds which are represented by object types in the output, like records and geometries,
+/// are input through their scalar representation.
+fn kind_to_input_type(kind: &Kind) -> TypeRef {
+    match kind {
+        Kind::Bool => TypeRef::named(TypeRef::BOOLEAN),
+        Kind::Datetime => TypeRef::named("DateTime"),
+        Kind::Decimal => TypeRef::named("Decimal"),
+        Kind::Duration => TypeRef::named("Duration"),
+        Kind::Float => TypeRef::named(TypeRef::FLOAT),
+        Kind::Int => TypeRef::named(TypeRef::INT),
+        Kind::Number => TypeRef::named("Number"),
+        Kind::String => TypeRef::named(TypeRef::STRING),
+        Kind::Uuid => TypeRef::named("UUID"),
+        Kind::Bytes => TypeRef::named("Bytes"),
+        Kind::Record(_) => TypeRef::named(TypeRef::ID),
+        Kind::Object => TypeRef::named("Object"),
+        Kind::Point | Kind::Geometry(_) => TypeRef::named("Geometry"),
+        Kind::Array(k, _) => TypeRef::List(Box::new(kind_to_input_type(k))),
+        Kind::Option(k) => kind_to_input_type(k),
+        _ => TypeRef::named("JSON"),
+    }
+}
+
+/// Converts a mutation input object into a SQL object, coercing each value into the kind of its
+/// field. Values which can not be coerced fail with their path from the table, e.g. `user.tags[1]`.
+fn input_to_object(
+    input: Option<&GqlValue>,
+    tb_name: &str,
+    fds: &[DefineFieldStatement],
+    naming: GqlNaming,
+) -> Result<SqlValue, GqlError> {
+    let Some(GqlValue::Object(input)) = input else {
+        return Err(input_error("Expected `input` to be an object"));
+    };
+
+    let mut out = BTreeMap::new();
+    for (name, val) in input {
+        let fd = fds
+            .iter()
+            .find(|fd| field_gql_name(fd, naming).is_ok_and(|n| n == name.as_str()))
+            .ok_or_else(|| input_error(format!("Unknown input field: {name}")))?;
+        let kind = fd.kind.clone().unwrap_or(Kind::Any);
+        let val = gql_to_sql_kind(val, kind).map_err(|e| e.in_field(name).in_field(tb_name))?;
+        out.insert(field_name(fd), val);
+    }
+
+    Ok(SqlValue::Object(out.into()))
+}
+
+fn thing_from_args(args: &IndexMap<Name, GqlValue>, tb_name: &str) -> Result<Thing, GqlError> {
+    let Some(id) = args.get("id").and_then(GqlValueUtils::as_string) else {
+        return Err(input_error("Schema validation failed: No id found in arguments"));
+    };
+    parse_record_id(tb_name, &id)
+}
+
+/// Runs a mutation in its own write transaction, returning the id of the affected record, if any.
+async fn process_mutation(
+    kvs: &Arc<Datastore>,
+    sess: &Session,
+    stmt: Statement,
+) -> Result<Option<Thing>, GqlError> {
+    let gtx = GQLTx::new_write(kvs, sess).await?;
+    let res = gtx.process_stmt(stmt).await;
+    let res = gtx.finish(res).await?;
+    trace!("mutation result: {res:?}");
+
+    let record = match res {
+        SqlValue::Array(mut a) if a.len() <= 1 => a.pop(),
+        v => return Err(internal_error(format!("Expected at most one record, found: {v:?}"))),
+    };
+    match record.map(|r| r.pick(&*ID)) {
+        Some(SqlValue::Thing(t)) => Ok(Some(t)),
+        Some(v) => Err(internal_error(format!("Expected record id, found: {v:?}"))),
+        None => Ok(None),
+    }
+}
@@ -0,0 +1,146 @@
+//! The names of the types and fields generated from definitions, following the
+//! [`GqlNaming`] strategy of the GraphQL config.
+//!
+//! Generated names which are made up of several words, e.g. the filter input type of a table, are
+//! built by joining the words with underscores before applying the strategy, so that
+//! `user_filter_input` becomes `UserFilterInput` in PascalCase and stays as it is verbatim. Types
+//! which are not generated from a definition, such as `PageInfo`, keep their names.
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+use inflector::Inflector;
+
+use super::error::{schema_error, GqlError};
+use crate::sql::statements::define::config::graphql::{GqlNaming, NameCase};
+
+impl GqlNaming {
+    /// Returns the name of the type generated from the definition with the given name.
+    pub(crate) fn type_name(&self, name: &str) -> String {
+        apply_case(self.type_case(), name)
+    }
+
+    /// Returns the name of the field generated from the definition with the given name.
+    pub(crate) fn field_name(&self, name: &str) -> String {
+        apply_case(self.field_case(), name)
+    }
+
+    /// Returns the singular of a field name, used for fields resolving to a single record. Field
+    /// names are not singularized when they are kept verbatim.
+    pub(crate) fn singular(&self, name: &str) -> String {
+        match self.field_case() {
+            NameCase::Verbatim => name.to_string(),
+            _ => name.to_singular(),
+        }
+    }
+
+    /// Returns the plural of a field name, used for fields resolving to a list of records.
+    pub(crate) fn plural(&self, name: &str) -> String {
+        name.to_plural()
+    }
+
+    fn type_case(&self) -> NameCase {
+        match self {
+            GqlNaming::Verbatim => NameCase::Verbatim,
+            GqlNaming::Custom {
+                types,
+                ..
+            } => *types,
+            _ => NameCase::Pascal,
+        }
+    }
+
+    fn field_case(&self) -> NameCase {
+        match self {
+            GqlNaming::Verbatim => NameCase::Verbatim,
+            GqlNaming::Custom {
+                fields,
+                ..
+            } => *fields,
+            _ => NameCase::Camel,
+        }
+    }
+}
+
+fn apply_case(case: NameCase, name: &str) -> String {
+    match case {
+        NameCase::Pascal => name.to_pascal_case(),
+        NameCase::Camel => name.to_camel_case(),
+        NameCase::Snake => name.to_snake_case(),
+        // GraphQL names may only contain letters, digits and underscores
+        _ => name.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect(),
+    }
+}
+
+/// The names in a scope of the schema, e.g. the fields of the `Query` object, which detects
+/// different definitions mapping to the same GraphQL name.
+#[derive(Default)]
+pub(crate) struct NameScope {
+    names: HashMap<String, String>,
+}
+
+impl NameScope {
+    /// Claims a name for the described origin, failing if it is already taken by another one.
+    pub(crate) fn claim(&mut self, name: &str, origin: impl Into<String>) -> Result<(), GqlError> {
+        match self.names.entry(name.to_string()) {
+            Entry::Occupied(e) => Err(schema_error(format!(
+                "Both {} and {} have the GraphQL name `{name}`",
+                e.get(),
+                origin.into()
+            ))),
+            Entry::Vacant(e) => {
+                e.insert(origin.into());
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pascal() {
+        let naming = GqlNaming::Pascal;
+        assert_eq!(naming.type_name("user_profile"), "UserProfile");
+        assert_eq!(naming.type_name("user_profile_filter_input"), "UserProfileFilterInput");
+        assert_eq!(naming.field_name("created_at"), "createdAt");
+        assert_eq!(naming.singular(&naming.field_name("user_profiles")), "userProfile");
+        assert_eq!(naming.plural(&naming.field_name("user_profile")), "userProfiles");
+    }
+
+    #[test]
+    fn verbatim() {
+        let naming = GqlNaming::Verbatim;
+        assert_eq!(naming.type_name("user_profile"), "user_profile");
+        assert_eq!(naming.type_name("user_profile_filter_input"), "user_profile_filter_input");
+        assert_eq!(naming.type_name("fn::get_user"), "fn__get_user");
+        assert_eq!(naming.field_name("createdAt"), "createdAt");
+        assert_eq!(naming.singular("user_profiles"), "user_profiles");
+        assert_eq!(naming.plural("user_profile"), "user_profiles");
+    }
+
+    #[test]
+    fn custom() {
+        let naming = GqlNaming::Custom {
+            types: NameCase::Snake,
+            fields: NameCase::Pascal,
+        };
+        assert_eq!(naming.type_name("UserProfile"), "user_profile");
+        assert_eq!(naming.field_name("created_at"), "CreatedAt");
+        assert_eq!(naming.singular("Users"), "User");
+    }
+
+    #[test]
+    fn name_collision() {
+        let mut scope = NameScope::default();
+        scope.claim("news", "the query of the table `news`").unwrap();
+        let err = scope.claim("news", "the list query of the table `news`").unwrap_err();
+        assert!(matches!(err, GqlError::SchemaError(_)));
+        assert_eq!(
+            err.to_string(),
+            "Error generating schema: Both the query of the table `news` and the list query of the table `news` have the GraphQL name `news`"
+        );
+    }
+}
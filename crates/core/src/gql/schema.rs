@@ -3,40 +3,47 @@ use std::ops::Add;
 use std::sync::Arc;
 
 use crate::dbs::Session;
+use crate::fnc::time::format;
 use crate::gql::functions::process_fns;
+use crate::gql::mutations::process_mutations;
 use crate::gql::tables::process_tbs;
 use crate::kvs::Datastore;
 use crate::sql;
 use crate::sql::kind::Literal;
-use crate::sql::statements::define::config::graphql::{FunctionsConfig, TablesConfig};
+use crate::sql::statements::define::config::graphql::{FunctionsConfig, GqlNaming, TablesConfig};
 use crate::sql::Kind;
 use crate::sql::{Geometry, Ident};
 use async_graphql::dynamic::Schema;
 use async_graphql::dynamic::{Enum, Type, Union};
-use async_graphql::dynamic::{EnumItem, Interface};
-use async_graphql::dynamic::{Field, InterfaceField, ResolverContext};
-use async_graphql::dynamic::{FieldFuture, Object};
+use async_graphql::dynamic::Interface;
+use async_graphql::dynamic::InterfaceField;
+use async_graphql::dynamic::Object;
 use async_graphql::dynamic::{Scalar, TypeRef};
 use async_graphql::indexmap::IndexMap;
 use async_graphql::Name;
 use async_graphql::Value as GqlValue;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
 use geo::{Coord, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon};
 use inflector::Inflector;
 use rust_decimal::prelude::FromPrimitive;
 use rust_decimal::Decimal;
 use serde_json::Number;
 
-use super::error::{resolver_error, GqlError};
+use super::error::{resolver_error, ErrorCodes, GqlError};
+use super::limits::QueryLimits;
 #[cfg(debug_assertions)]
 use super::ext::ValidatorExt;
 use crate::gql::error::{internal_error, schema_error, type_error};
-use crate::gql::ext::{NamedContainer, TryFromExt, TryIntoExt};
+use crate::gql::ext::{NamedContainer, TryFromExt};
 use crate::gql::geometry;
-use crate::gql::utils::GqlValueUtils;
 use crate::kvs::LockType;
 use crate::kvs::TransactionType;
 use crate::sql::Value as SqlValue;
 
+/// The format of datetimes returned by the `DateTime` scalar, an RFC 3339 date and time.
+const DATETIME_FORMAT: &str = "%+";
+
 pub async fn generate_schema(
     datastore: &Arc<Datastore>,
     session: &Session,
@@ -95,25 +102,33 @@ pub async fn generate_schema(
     }
 
     let mut query = Object::new("Query");
+    let mut mutation = None;
     let mut types: Vec<Type> = Vec::new();
     let cursor = config.cursor; //TODO: use cursor config here
+    let naming = config.naming;
+    let wrap_lists = config.wrap_lists;
 
     trace!(ns, db, ?tbs, ?fns, "generating schema");
 
     match tbs {
         Some(tbs) if !tbs.is_empty() => {
-            query = process_tbs(tbs, query, &mut types, &tx, ns, db, session, datastore, cursor).await?;
+            mutation = process_mutations(&tbs, Object::new("Mutation"), &mut types, &tx, ns, db, session, datastore, naming).await?;
+            query = process_tbs(tbs, query, &mut types, &tx, ns, db, session, datastore, cursor, wrap_lists, naming).await?;
         }
         _ => {}
     }
 
     if let Some(fns) = fns {
-        query = process_fns(fns, query, &mut types, session, datastore).await?;
+        query = process_fns(fns, query, &mut types, session, datastore, naming).await?;
     }
 
     // trace!("current Query object for schema: {:?}", query);
 
-    let mut schema = Schema::build("Query", None, None).register(query);
+    let mut schema =
+        Schema::build("Query", mutation.as_ref().map(|_| "Mutation"), None).register(query);
+    if let Some(mutation) = mutation {
+        schema = schema.register(mutation);
+    }
     for ty in types {
         // trace!("adding type: {ty:?}");
         schema = schema.register(ty);
@@ -134,7 +149,13 @@ pub async fn generate_schema(
 			)
 		};
 		($schema:ident, $name:expr, $kind:expr, $desc:literal) => {
-			scalar_debug_validated!($schema, $name, $kind, std::option::Option::Some($desc), None)
+			scalar_debug_validated!(
+				$schema,
+				$name,
+				$kind,
+				std::option::Option::Some($desc),
+				::std::option::Option::<&str>::None
+			)
 		};
 		($schema:ident, $name:expr, $kind:expr, $desc:literal, $url:literal) => {
 			scalar_debug_validated!(
@@ -169,127 +190,43 @@ pub async fn generate_schema(
 		"String encoded UUID",
 		"https://datatracker.ietf.org/doc/html/rfc4122"
 	);
-    scalar_debug_validated!(schema, "Decimal", Kind::Decimal);
+    scalar_debug_validated!(
+		schema,
+		"Decimal",
+		Kind::Decimal,
+		"String encoded arbitrary precision decimal number"
+	);
     scalar_debug_validated!(schema, "Number", Kind::Number);
     scalar_debug_validated!(schema, "Null", Kind::Null);
-    scalar_debug_validated!(schema, "DateTime", Kind::Datetime);
-    scalar_debug_validated!(schema, "Duration", Kind::Duration);
+    scalar_debug_validated!(
+		schema,
+		"DateTime",
+		Kind::Datetime,
+		"String encoded RFC 3339 date and time",
+		"https://datatracker.ietf.org/doc/html/rfc3339"
+	);
+    scalar_debug_validated!(
+		schema,
+		"Duration",
+		Kind::Duration,
+		"String encoded duration, like `1h30m`"
+	);
+    scalar_debug_validated!(schema, "Bytes", Kind::Bytes, "Base64 encoded bytes");
     scalar_debug_validated!(schema, "Object", Kind::Object);
-    scalar_debug_validated!(schema, "Any", Kind::Any);
-
-    // =======================================================
-    // Geometry types
-    // =======================================================
-
-    macro_rules! geometry_type {
-		($schema:ident, $name:expr, $type:expr) => {
-			$schema = $schema.register(
-				Object::new(format!("Geometry{}", $name))
-					.field(Field::new(
-						"type",
-						TypeRef::named("GeometryType"),
-						|_: ResolverContext| {
-							async_graphql::dynamic::FieldFuture::Value(Some(
-								GqlValue::String($name.to_string().to_screaming_snake_case()).into(),
-							))
-						},
-					))
-					.field(Field::new("coordinates", $type.clone(), |ctx: ResolverContext| {
-						FieldFuture::new({
-							async move {
-								let val = ctx
-									.parent_value
-									.try_to_value()?
-									.as_object()
-									.ok_or_else(|| internal_error("Expected object"))?
-									.get("coordinates");
-								Ok(val.cloned())
-							}
-						})
-					})),
-			);
-			// $schema = $schema.register(
-			// 	InputObject::new(format!("{}_input", $name))
-			// 		.field(InputValue::new("type", TypeRef::named("GeometryType")))
-			// 		// .field(InputValue::new("geotype", TypeRef::named(TypeRef::STRING)))
-			// 		.field(InputValue::new("coordinates", $type.clone())),
-			// );
-		};
-	}
-
-    schema = schema.register(Enum::new("GeometryType")
-        .items([
-            EnumItem::new("POINT")
-                .description("A geolocation point with longitude and latitude."),
-            EnumItem::new("LINE_STRING")
-                .description("A GeoJSON LineString value for storing a geometric path."),
-            EnumItem::new("POLYGON")
-                .description("A GeoJSON Polygon value for storing a geometric area."),
-            EnumItem::new("MULTI_POINT")
-                .description("A value which contains multiple geometry points"),
-            EnumItem::new("MULTI_LINE_STRING")
-                .description("A value which contains multiple geometry lines."),
-            EnumItem::new("MULTI_POLYGON")
-                .description("A value which contains multiple geometry polygons."),
-            EnumItem::new("COLLECTION")
-                .description("A value which contains multiple different geometry types."),
-        ])
-        .description("A type of geometry value."));
-
-    let coordinate_type = TypeRef::named_nn_list_nn(TypeRef::FLOAT);
-    let coordinate_list_type = TypeRef::NonNull(Box::new(TypeRef::List(Box::new(
-        TypeRef::NonNull(Box::new(coordinate_type.clone())),
-    ))));
-    let coordinate_list_list_type = TypeRef::NonNull(Box::new(TypeRef::List(Box::new(
-        TypeRef::NonNull(Box::new(coordinate_list_type.clone())),
-    ))));
-    let coordinate_list_list_list_type = TypeRef::NonNull(Box::new(TypeRef::List(Box::new(
-        TypeRef::NonNull(Box::new(coordinate_list_type.clone())),
-    ))));
-
-    //FIXME: adapt macro to fit collection case as well
-    geometry_type!(schema, "Point", coordinate_type);
-    geometry_type!(schema, "LineString", coordinate_list_type);
-    geometry_type!(schema, "Polygon", coordinate_list_list_type);
-    geometry_type!(schema, "MultiPoint", coordinate_list_type);
-    geometry_type!(schema, "MultiLineString", coordinate_list_list_type);
-    geometry_type!(schema, "MultiPolygon", coordinate_list_list_list_type);
-    schema = schema.register(
-        Object::new(format!("Geometry{}", "Collection"))
-            .field(Field::new("type", TypeRef::named("GeometryType"), |_: ResolverContext| {
-                FieldFuture::Value(Some(
-                    GqlValue::String("COLLECTION".to_string()).into(),
-                ))
-            }))
-            .field(Field::new(
-                "geometries",
-                TypeRef::named_nn_list_nn("Geometry"),
-                |ctx: ResolverContext| {
-                    FieldFuture::new({
-                        async move {
-                            let val = ctx
-                                .parent_value
-                                .try_to_value()?
-                                .as_object()
-                                .ok_or_else(|| internal_error("Expected Object"))?
-                                .get("geometries");
-                            Ok(val.cloned())
-                        }
-                    })
-                },
-            )),
-    );
-
-    let mut geometry_union = Union::new("Geometry");
-    geometry_union = geometry_union.possible_type("GeometryPoint");
-    geometry_union = geometry_union.possible_type("GeometryLineString");
-    geometry_union = geometry_union.possible_type("GeometryPolygon");
-    geometry_union = geometry_union.possible_type("GeometryMultiPoint");
-    geometry_union = geometry_union.possible_type("GeometryMultiLineString");
-    geometry_union = geometry_union.possible_type("GeometryMultiPolygon");
-    geometry_union = geometry_union.possible_type("GeometryCollection");
-    schema = schema.register(geometry_union);
+    scalar_debug_validated!(
+		schema,
+		"JSON",
+		Kind::Any,
+		"Arbitrary JSON value, used for values without a more specific type"
+	);
 
+    scalar_debug_validated!(
+		schema,
+		"Geometry",
+		Kind::Geometry(vec![]),
+		"GeoJSON encoded geometry",
+		"https://datatracker.ietf.org/doc/html/rfc7946"
+	);
 
     // =======================================================
     // Interfaces
@@ -310,10 +247,17 @@ pub async fn generate_schema(
 
 
     schema
+        .extension(ErrorCodes)
+        .extension(QueryLimits {
+            depth: config.depth_limit.map(|d| d as usize),
+            complexity: config.complexity_limit.map(|c| c as usize),
+        })
         .finish()
         .map_err(|e| schema_error(format!("there was an error generating schema: {e:?}")))
 }
 
+/// Converts a SQL value into a GraphQL value. A value nested within arrays and objects which can
+/// not be converted fails with its path within the value, e.g. `size.width`.
 pub fn sql_value_to_gql_value(v: SqlValue) -> Result<GqlValue, GqlError> {
     let out = match v {
         SqlValue::None => GqlValue::Null,
@@ -325,119 +269,48 @@ pub fn sql_value_to_gql_value(v: SqlValue) -> Result<GqlValue, GqlError> {
                 Number::from_f64(f)
                     .ok_or(resolver_error("unimplemented: graceful NaN and Inf handling"))?,
             ),
-            num @ crate::sql::Number::Decimal(_) => GqlValue::String(num.to_string()),
+            crate::sql::Number::Decimal(d) => GqlValue::String(d.to_string()),
         },
         SqlValue::Strand(s) => GqlValue::String(s.0),
         d @ SqlValue::Duration(_) => GqlValue::String(d.to_string()),
-        SqlValue::Datetime(d) => GqlValue::String(d.to_rfc3339()),
-        SqlValue::Uuid(uuid) => GqlValue::String(uuid.to_string()),
-        SqlValue::Array(a) => {
-            GqlValue::List(a.into_iter().map(|v| sql_value_to_gql_value(v).unwrap()).collect())
-        }
+        SqlValue::Datetime(d) => match format((d, DATETIME_FORMAT.to_string()))? {
+            SqlValue::Strand(s) => GqlValue::String(s.0),
+            v => return Err(internal_error(format!("expected formatted datetime, found: {v:?}"))),
+        },
+        SqlValue::Uuid(uuid) => GqlValue::String(uuid.to_raw()),
+        SqlValue::Array(a) => GqlValue::List(
+            a.into_iter()
+                .enumerate()
+                .map(|(i, v)| sql_value_to_gql_value(v).map_err(|e| e.at_index(i)))
+                .collect::<Result<_, _>>()?,
+        ),
         SqlValue::Object(o) => GqlValue::Object(
             o.0.into_iter()
-                .map(|(k, v)| (Name::new(k), sql_value_to_gql_value(v).unwrap()))
-                .collect(),
+                .map(|(k, v)| {
+                    let v = sql_value_to_gql_value(v).map_err(|e| e.in_field(&k))?;
+                    Ok((Name::new(k), v))
+                })
+                .collect::<Result<_, GqlError>>()?,
         ),
-        SqlValue::Geometry(kind) => match kind {
-            Geometry::Point(point) => GqlValue::Object(
-                [
-                    (Name::new("type"), GqlValue::String("POINT".to_string())),
-                    (
-                        Name::new("coordinates"),
-                        GqlValue::List(vec![point.x().try_intox()?, point.y().try_intox()?]),
-                    ),
-                ]
-                    .into(),
-            ),
-            Geometry::Line(line) => GqlValue::Object(
-                [
-                    (Name::new("type"), GqlValue::String("LINE_STRING".to_string())),
-                    (Name::new("coordinates"), geometry::coord_collection_to_list(line)?),
-                ]
-                    .into(),
-            ),
-            Geometry::MultiLine(multiline) => GqlValue::Object(
-                [
-                    (Name::new("type"), GqlValue::String("MULTI_LINE".to_string())),
-                    (
-                        Name::new("coordinates"),
-                        GqlValue::List(
-                            multiline
-                                .into_iter()
-                                .map(geometry::coord_collection_to_list)
-                                .collect::<Result<Vec<_>, GqlError>>()?,
-                        ),
-                    ),
-                ]
-                    .into(),
-            ),
-            Geometry::MultiPoint(multipoint) => GqlValue::Object(
-                [
-                    (Name::new("type"), GqlValue::String("MULTI_POINT".to_string())),
-                    (
-                        Name::new("coordinates"),
-                        geometry::coord_collection_to_list(multipoint.into_iter().map(|p| p.0))?,
-                    ),
-                ]
-                    .into(),
-            ),
-            Geometry::Polygon(polygon) => GqlValue::Object(
-                [
-                    (Name::new("type"), GqlValue::String("POLYGON".to_string())),
-                    (Name::new("coordinates"), geometry::polygon_to_list(&polygon)?),
-                ]
-                    .into(),
-            ),
-            Geometry::MultiPolygon(multipolygon) => GqlValue::Object(
-                [
-                    (Name::new("type"), GqlValue::String("MULTI_POLYGON".to_string())),
-                    (
-                        Name::new("coordinates"),
-                        GqlValue::List(
-                            multipolygon
-                                .iter()
-                                .map(geometry::polygon_to_list)
-                                .collect::<Result<Vec<_>, _>>()?,
-                        ),
-                    ),
-                ]
-                    .into(),
-            ),
-            Geometry::Collection(collection) => GqlValue::Object(
-                [
-                    (Name::new("type"), GqlValue::String("COLLECTION".to_string())),
-                    (
-                        Name::new("geometries"),
-                        GqlValue::List(
-                            collection
-                                .into_iter()
-                                .map(|g| sql_value_to_gql_value(SqlValue::Geometry(g)).unwrap())
-                                .collect(),
-                        ),
-                    ),
-                ]
-                    .into(),
-            ),
-        },
-        SqlValue::Bytes(b) => GqlValue::Binary(b.into_inner().into()),
+        SqlValue::Geometry(g) => geometry::geometry_to_geojson(&g)?,
+        SqlValue::Bytes(b) => GqlValue::String(STANDARD.encode(b.into_inner())),
         SqlValue::Thing(t) => GqlValue::String(t.to_string()),
         v => return Err(internal_error(format!("found unsupported value variant: {v:?}"))),
     };
     Ok(out)
 }
 
-pub fn kind_to_type(kind: Kind, types: &mut Vec<Type>, path: &[&Ident]) ->
+pub fn kind_to_type(kind: Kind, types: &mut Vec<Type>, path: &[&Ident], naming: GqlNaming) ->
 Result<TypeRef,
     GqlError> {
-    let ty_name = path.iter().map(|i| i.0.to_string())
-        .collect::<Vec<_>>().join(".").to_pascal_case();
+    let path_name = path.iter().map(|i| i.0.to_string())
+        .collect::<Vec<_>>().join("_");
     let (optional, match_kind) = match kind {
         Kind::Option(op_ty) => (true, *op_ty),
         _ => (false, kind),
     };
     let out_ty = match match_kind {
-        Kind::Any => TypeRef::named("Any"),
+        Kind::Any => TypeRef::named("JSON"),
         Kind::Null => TypeRef::named("Null"),
         Kind::Bool => TypeRef::named(TypeRef::BOOLEAN),
         Kind::Bytes => TypeRef::named("Bytes"),
@@ -447,18 +320,18 @@ Result<TypeRef,
         Kind::Float => TypeRef::named(TypeRef::FLOAT),
         Kind::Int => TypeRef::named(TypeRef::INT),
         Kind::Number => TypeRef::named("Number"),
-        Kind::Object => TypeRef::named(format!("{}Object", ty_name)),
-        Kind::Point => TypeRef::named("GeometryPoint"),
+        Kind::Object => TypeRef::named(naming.type_name(&format!("{path_name}_object"))),
+        Kind::Point => TypeRef::named("Geometry"),
         Kind::Regex => return Err(schema_error("Kind::Regex is not yet supported")),
         Kind::String => TypeRef::named(TypeRef::STRING),
         Kind::Uuid => TypeRef::named("UUID"),
         Kind::Record(mut r) => match r.len() {
             0 => TypeRef::named("Record"),
-            1 => TypeRef::named(r.pop().unwrap().0.to_pascal_case()),
+            1 => TypeRef::named(naming.type_name(&r.pop().unwrap().0)),
             _ => {
-                let names: Vec<String> = r.into_iter().map(|t| t.0.to_pascal_case()).collect();
+                let names: Vec<String> = r.into_iter().map(|t| naming.type_name(&t.0)).collect();
 
-                let union_name = format!("{}RecordUnion", ty_name);
+                let union_name = naming.type_name(&format!("{path_name}_record_union"));
                 let mut tmp_union = Union::new(&union_name)
                     .description(format!("A record which is one of: {}", names.join(", ")));
                 for n in names {
@@ -469,35 +342,13 @@ Result<TypeRef,
                 TypeRef::named(union_name)
             }
         },
-        Kind::Geometry(g) => match g.len() {
-            0 => TypeRef::named("Geometry"),
-            1 => {
-                let name = g.into_iter().next().expect("checked that length is 1");
-                TypeRef::named(geometry::geometry_kind_name_to_type_name(&name)?)
-            }
-            _ => {
-                let geo_types = g
-                    .iter()
-                    .map(|n| geometry::geometry_kind_name_to_type_name(n).map(TypeRef::named))
-                    .collect::<Result<Vec<_>, GqlError>>()?;
-
-                let union_name = format!("{}Union", ty_name);
-                let mut tmp_union = Union::new(&union_name)
-                    .description(format!("A geometry which is one of: {}",
-                                         geo_types.iter().map(|t| t.to_string()).collect::<Vec<_>>().join(", ")));
-                for geo_type in geo_types {
-                    tmp_union = tmp_union.possible_type(geo_type.type_name())
-                }
-                types.push(Type::Union(tmp_union));
-                TypeRef::named(union_name)
-            }
-        },
+        Kind::Geometry(_) => TypeRef::named("Geometry"),
         Kind::Option(t) => {
             let mut non_op_ty = *t;
             while let Kind::Option(inner) = non_op_ty {
                 non_op_ty = *inner;
             }
-            kind_to_type(non_op_ty, types, path)?
+            unwrap_type(kind_to_type(non_op_ty, types, path, naming)?)
         }
         Kind::Either(ks) => {
             let (ls, others): (Vec<Kind>, Vec<Kind>) =
@@ -516,40 +367,45 @@ Result<TypeRef,
                     })
                     .collect();
 
-                let enum_name = format!("{}Enum", ty_name);
+                let enum_name = naming.type_name(&format!("{path_name}_enum"));
                 let tmp_enum = Enum::new(&enum_name)
                     .description(format!("Represents one of the following states: {}", vals.join(", ")))
                     .items(vals);
 
                 types.push(Type::Enum(tmp_enum));
-                if others.is_empty() {
-                    return Ok(TypeRef::named(enum_name));
-                }
                 Some(enum_name)
             } else {
                 None
             };
 
-            let pos_names: Result<Vec<TypeRef>, GqlError> =
-                others.into_iter().map(|k| kind_to_type(k, types, path)).collect();
-            let pos_names: Vec<String> = pos_names?.into_iter().map(|tr| tr.to_string()).collect();
+            match enum_ty {
+                // Only string literals, the enum is the type itself. It still has to go through
+                // the optional handling below, so that required enums are non-null.
+                Some(enum_name) if others.is_empty() => TypeRef::named(enum_name),
+                enum_ty => {
+                    let pos_names: Result<Vec<TypeRef>, GqlError> =
+                        others.into_iter().map(|k| kind_to_type(k, types, path, naming)).collect();
+                    let pos_names: Vec<String> =
+                        pos_names?.into_iter().map(|tr| tr.to_string()).collect();
+
+                    let union_name = naming.type_name(&format!("{path_name}_union"));
+                    let mut tmp_union = Union::new(&union_name)
+                        .description(format!("A union of: {}", pos_names.join(", ")));
+                    for n in pos_names {
+                        tmp_union = tmp_union.possible_type(n);
+                    }
 
-            let union_name = format!("{}Union", ty_name);
-            let mut tmp_union = Union::new(&union_name)
-                .description(format!("A union of: {}", pos_names.join(", ")));
-            for n in pos_names {
-                tmp_union = tmp_union.possible_type(n);
-            }
+                    if let Some(ty) = enum_ty {
+                        tmp_union = tmp_union.possible_type(ty);
+                    }
 
-            if let Some(ty) = enum_ty {
-                tmp_union = tmp_union.possible_type(ty);
+                    types.push(Type::Union(tmp_union));
+                    TypeRef::named(union_name)
+                }
             }
-
-            types.push(Type::Union(tmp_union));
-            TypeRef::named(union_name)
         }
         Kind::Set(_, _) => return Err(schema_error("Kind::Set is not yet supported")),
-        Kind::Array(k, _) => TypeRef::List(Box::new(kind_to_type(*k, types, path)?)),
+        Kind::Array(k, _) => TypeRef::List(Box::new(kind_to_type(*k, types, path, naming)?)),
         Kind::Function(_, _) => return Err(schema_error("Kind::Function is not yet supported")),
         Kind::Range => return Err(schema_error("Kind::Range is not yet supported")),
         //TODO(raphaeldarley): check if union is of literals and generate enum
@@ -560,7 +416,7 @@ Result<TypeRef,
                 Some(ft) => Kind::Record(vec![ft]),
                 None => Kind::Record(vec![]),
             };
-            TypeRef::List(Box::new(kind_to_type(inner, types, path)?))
+            TypeRef::List(Box::new(kind_to_type(inner, types, path, naming)?))
         }
         Kind::File(_) => return Err(schema_error("Kind::File is not yet supported")),
     };
@@ -629,6 +485,9 @@ macro_rules! any_try_kinds {
 	};
 }
 
+/// Converts a GraphQL value into a SQL value of the given kind. A value nested within lists and
+/// objects which does not match its kind fails with its path within the value, e.g. `tags[1]`, to
+/// which callers prepend the path of the value itself with [`GqlError::in_field`].
 pub fn gql_to_sql_kind(val: &GqlValue, kind: Kind) -> Result<SqlValue, GqlError> {
     use crate::gql::geometry;
     use crate::syn;
@@ -637,7 +496,12 @@ pub fn gql_to_sql_kind(val: &GqlValue, kind: Kind) -> Result<SqlValue, GqlError>
             GqlValue::String(s) => {
                 use Kind::*;
                 any_try_kinds!(val, Datetime, Duration, Uuid);
-                syn::value_legacy_strand(s.as_str()).map_err(|_| type_error(kind, val))
+                // Other strings are only parsed as record links, as parsing them as arbitrary
+                // values would turn plain strings into idioms.
+                match syn::thing(s.as_str()) {
+                    Ok(t) => Ok(SqlValue::Thing(t)),
+                    Err(_) => Ok(SqlValue::Strand(s.as_str().into())),
+                }
             }
             GqlValue::Null => Ok(SqlValue::Null),
             obj @ GqlValue::Object(_) => gql_to_sql_kind(obj, Kind::Object),
@@ -657,6 +521,10 @@ pub fn gql_to_sql_kind(val: &GqlValue, kind: Kind) -> Result<SqlValue, GqlError>
         },
         Kind::Bytes => match val {
             GqlValue::Binary(b) => Ok(SqlValue::Bytes(b.to_owned().to_vec().into())),
+            GqlValue::String(s) => match STANDARD.decode(s) {
+                Ok(b) => Ok(SqlValue::Bytes(b.into())),
+                Err(_) => Err(type_error(kind, val)),
+            },
             _ => Err(type_error(kind, val)),
         },
         Kind::Datetime => match val {
@@ -678,16 +546,22 @@ pub fn gql_to_sql_kind(val: &GqlValue, kind: Kind) -> Result<SqlValue, GqlError>
                     Err(type_error(kind, val))
                 }
             }
-            GqlValue::String(s) => match syn::value(s) {
-                Ok(SqlValue::Number(n)) => match n {
-                    sql::Number::Int(i) => Ok(SqlValue::Number(sql::Number::Decimal(i.into()))),
-                    sql::Number::Float(f) => match Decimal::from_f64(f) {
-                        Some(d) => Ok(SqlValue::Number(sql::Number::Decimal(d))),
-                        None => Err(type_error(kind, val)),
+            // Parse plain decimals directly, as parsing them as a value would go through a float
+            GqlValue::String(s) => match s.parse::<Decimal>() {
+                Ok(d) => Ok(SqlValue::Number(sql::Number::Decimal(d))),
+                Err(_) => match syn::value(s) {
+                    Ok(SqlValue::Number(n)) => match n {
+                        sql::Number::Int(i) => {
+                            Ok(SqlValue::Number(sql::Number::Decimal(i.into())))
+                        }
+                        sql::Number::Float(f) => match Decimal::from_f64(f) {
+                            Some(d) => Ok(SqlValue::Number(sql::Number::Decimal(d))),
+                            None => Err(type_error(kind, val)),
+                        },
+                        sql::Number::Decimal(d) => Ok(SqlValue::Number(sql::Number::Decimal(d))),
                     },
-                    sql::Number::Decimal(d) => Ok(SqlValue::Number(sql::Number::Decimal(d))),
+                    _ => Err(type_error(kind, val)),
                 },
-                _ => Err(type_error(kind, val)),
             },
             _ => Err(type_error(kind, val)),
         },
@@ -772,7 +646,10 @@ pub fn gql_to_sql_kind(val: &GqlValue, kind: Kind) -> Result<SqlValue, GqlError>
             GqlValue::Object(o) => {
                 let out: Result<BTreeMap<String, SqlValue>, GqlError> = o
                     .iter()
-                    .map(|(k, v)| gql_to_sql_kind(v, Kind::Any).map(|sqlv| (k.to_string(), sqlv)))
+                    .map(|(k, v)| match gql_to_sql_kind(v, Kind::Any) {
+                        Ok(sqlv) => Ok((k.to_string(), sqlv)),
+                        Err(e) => Err(e.in_field(k)),
+                    })
                     .collect();
                 Ok(SqlValue::Object(out?.into()))
             }
@@ -783,6 +660,10 @@ pub fn gql_to_sql_kind(val: &GqlValue, kind: Kind) -> Result<SqlValue, GqlError>
             _ => Err(type_error(kind, val)),
         },
         Kind::Point => match val {
+            GqlValue::Object(map) => match geometry::extract_geometry(map) {
+                Some(point @ Geometry::Point(_)) => Ok(SqlValue::Geometry(point)),
+                _ => Err(type_error(kind, val)),
+            },
             GqlValue::List(l) => match l.as_slice() {
                 [GqlValue::Number(x), GqlValue::Number(y)] => match (x.as_f64(), y.as_f64()) {
                     (Some(x), Some(y)) => Ok(SqlValue::Geometry(Geometry::Point((x, y).into()))),
@@ -815,28 +696,21 @@ pub fn gql_to_sql_kind(val: &GqlValue, kind: Kind) -> Result<SqlValue, GqlError>
             _ => Err(type_error(kind, val)),
         },
         Kind::Geometry(ref ts) => match &val {
-            GqlValue::Object(map) => match map.get("type") {
-                Some(t) => match t {
-                    GqlValue::String(acutal_t) => {
-                        let mut included = false;
-                        for ty in ts {
-                            if geometry::geometry_kind_name_to_type_name(ty)? == acutal_t {
-                                included = true;
-                                break;
-                            }
-                        }
-                        if included {
-                            geometry::extract_geometry(map)
-                                .map(SqlValue::Geometry)
-                                .ok_or_else(|| type_error(kind, val))
-                        } else {
-                            Err(type_error(kind, val))
-                        }
-                    }
-                    _ => Err(type_error(kind, val)),
-                },
-                None => Err(type_error(kind, val)),
-            },
+            GqlValue::Object(map) => {
+                let geometry =
+                    geometry::extract_geometry(map).ok_or_else(|| type_error(kind.clone(), val))?;
+                // An empty list of geometry types or the `feature` type allows any geometry
+                let included = ts.is_empty()
+                    || ts.iter().any(|ty| {
+                        ty == "feature"
+                            || geometry::geometry_kind_name_to_geojson_type(ty)
+                                .is_ok_and(|ty| ty == geometry.as_type())
+                    });
+                match included {
+                    true => Ok(SqlValue::Geometry(geometry)),
+                    false => Err(type_error(kind, val)),
+                }
+            }
             _ => Err(type_error(kind, val)),
         },
         Kind::Option(k) => match val {
@@ -893,7 +767,10 @@ pub fn gql_to_sql_kind(val: &GqlValue, kind: Kind) -> Result<SqlValue, GqlError>
         Kind::Set(_k, _n) => Err(resolver_error("Sets are not yet supported")),
         Kind::Array(ref k, n) => match val {
             GqlValue::List(l) => {
-                let list_iter = l.iter().map(|v| gql_to_sql_kind(v, *k.to_owned()));
+                let list_iter = l
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| gql_to_sql_kind(v, *k.to_owned()).map_err(|e| e.at_index(i)));
                 let list: Result<Vec<SqlValue>, GqlError> = list_iter.collect();
 
                 match (list, n) {
@@ -4,20 +4,28 @@ use std::mem;
 use std::ops::Add;
 use std::sync::{Arc, LazyLock};
 
+use super::cursor::{decode_cursor, encode_cursor};
 use super::error::{input_error, resolver_error, schema_error, GqlError};
 use super::ext::IntoExt;
+use super::naming::NameScope;
 use super::schema::{gql_to_sql_kind, sql_value_to_gql_value};
 use crate::dbs::Session;
-use crate::fnc::time::format;
+use crate::iam::Action;
 use crate::gql::error::internal_error;
-use crate::gql::ext::TryAsExt;
+use crate::gql::ext::{Named, TryAsExt};
 use crate::gql::schema::{kind_to_type, unwrap_type};
-use crate::gql::utils::{field_val_erase_owned, ErasedRecord, GQLTx, GqlValueUtils};
+use crate::gql::utils::{
+    check_field_names, field_gql_description, field_gql_name, field_val_erase_owned,
+    parse_record_id, with_comment, ErasedRecord, GQLTx, GqlValueUtils,
+};
 use crate::kvs::{Datastore, Transaction};
+use crate::sql::index::Index;
 use crate::sql::order::{OrderList, Ordering};
+use crate::sql::paths::ID;
+use crate::sql::statements::define::config::graphql::GqlNaming;
 use crate::sql::statements::{DefineFieldStatement, DefineTableStatement, SelectStatement};
-use crate::sql::{self, Ident, Literal, Part, Table, TableType};
-use crate::sql::{Cond, Fields};
+use crate::sql::{self, Ident, Literal, Part, Permission, Table, TableType};
+use crate::sql::{Cond, Fields, Groups};
 use crate::sql::{Expression, Value as SqlValue};
 use crate::sql::{Idiom, Kind};
 use crate::sql::{Statement, Thing};
@@ -32,20 +40,6 @@ use async_graphql::types::connection::{Connection, Edge, PageInfo};
 use async_graphql::Name;
 use async_graphql::Value as GqlValue;
 use inflector::Inflector;
-use log::trace;
-// macro_rules! order {
-// 	(asc, $field:expr) => {{
-// 		let mut tmp = sql::Order::default();
-// 		tmp.value = $field.into();
-// 		tmp.direction = true;
-// 		tmp
-// 	}};
-// 	(desc, $field:expr) => {{
-// 		let mut tmp = sql::Order::default();
-// 		tmp.value = $field.into();
-// 		tmp
-// 	}};
-// }
 macro_rules! first_input {
 	() => {
 		InputValue::new("first", TypeRef::named(TypeRef::INT))
@@ -77,7 +71,14 @@ macro_rules! after_input {
 macro_rules! limit_input {
 	() => {
 		InputValue::new("limit", TypeRef::named(TypeRef::INT))
-        .description("xxx")
+        .description("The maximum number of records to return.")
+	};
+}
+
+macro_rules! start_input {
+	() => {
+		InputValue::new("start", TypeRef::named(TypeRef::INT))
+        .description("The number of records to skip before returning records.")
 	};
 }
 
@@ -89,15 +90,17 @@ macro_rules! id_input {
 
 /// This macro needs the order input types to be defined with `define_order_input_types`.
 macro_rules! order_input {
-	($name: expr) => {
-		InputValue::new("orderBy", TypeRef::named(format!("{}Order", $name.to_pascal_case())))
+	($name: expr, $naming: expr) => {
+		InputValue::new("orderBy", TypeRef::named($naming.type_name(&format!("{}_order", $name))))
         .description(format!("Ordering options for `{}` connections.", $name))
 	};
 }
 
+/// This macro needs the filter input types to be defined in `process_tbs`.
 macro_rules! filter_input {
-	($name: expr) => {
-		InputValue::new("filterBy", TypeRef::named(format!("{}Filter", $name.to_pascal_case())))
+	($name: expr, $naming: expr) => {
+		InputValue::new("filterBy", TypeRef::named(filter_name_from_table($name, $naming)))
+        .description(format!("Filtering options for `{}` queries.", $name))
 	};
 }
 
@@ -109,28 +112,28 @@ macro_rules! define_page_info_type {
                 Field::new(
                 "hasNextPage",
                 TypeRef::named_nn(TypeRef::BOOLEAN),
-                page_info_resolver("".to_string(), None),
+                make_page_info_resolver("hasNextPage"),
                 ).description("When paginating forwards, are there more items?")
             )
             .field(
                 Field::new(
                 "hasPreviousPage",
                 TypeRef::named_nn(TypeRef::BOOLEAN),
-                page_info_resolver("".to_string(), None),
+                make_page_info_resolver("hasPreviousPage"),
                 ).description("When paginating backwards, are there more items?")
             )
             .field(
                 Field::new(
                 "startCursor",
                 TypeRef::named(TypeRef::STRING),
-                page_info_resolver("".to_string(), None),
+                make_page_info_resolver("startCursor"),
                 ).description("When paginating backwards, the cursor to continue.")
             )
             .field(
                 Field::new(
                 "endCursor",
                 TypeRef::named(TypeRef::STRING),
-                page_info_resolver("".to_string(), None),
+                make_page_info_resolver("endCursor"),
                 ).description("When paginating forwards, the cursor to continue.")
             )
             .description("Information about pagination in a connection.")
@@ -154,22 +157,35 @@ macro_rules! define_order_direction_enum {
 
 /// This macro needs the order direction enum type defined. you may use
 /// `define_order_direction_enum` for it.
+///
+/// `$field_names` are the names and descriptions of the fields, besides `id`, which can be ordered
+/// by.
+/// `$comment` is the comment of the table, which is added to the descriptions of the types.
 macro_rules! define_order_input_types {
     (
         $types:ident,
         $base_name:expr,
-        $( $field_enum_name:ident ),* $(,)?
+        $field_names:expr,
+        naming: $naming:expr,
+        comment: $comment:expr $(,)?
     ) => {
-        let base_name_pascal = $base_name.to_pascal_case();
-        let enum_name = format!("{}OrderField", base_name_pascal);
-        let obj_name = format!("{}Order", base_name_pascal);
+        let enum_name = $naming.type_name(&format!("{}_order_field", $base_name));
+        let obj_name = $naming.type_name(&format!("{}_order", $base_name));
 
-        let order_by_enum = Enum::new(&enum_name)
+        let mut order_by_enum = Enum::new(&enum_name)
             .item(EnumItem::new("ID").description(format!("{} by ID.", $base_name)))
-            $(.item(EnumItem::new(stringify!($field_enum_name).to_screaming_snake_case())
-                .description(format!("{} by {}.",
-                $base_name, stringify!($field_enum_name).to_screaming_snake_case()))))*
-            .description(format!("Properties by which {} can be ordered.", $base_name));
+            .description(with_comment(
+                format!("Properties by which {} can be ordered.", $base_name),
+                $comment,
+            ));
+        for (field_name, field_comment) in $field_names {
+            let item_name = order_field_name(&field_name);
+            order_by_enum = order_by_enum.item(EnumItem::new(&item_name)
+                .description(with_comment(
+                    format!("{} by {}.", $base_name, item_name),
+                    field_comment,
+                )));
+        }
         $types.push(Type::Enum(order_by_enum));
 
         let order_by_obj = InputObject::new(&obj_name)
@@ -179,7 +195,13 @@ macro_rules! define_order_input_types {
             .field(
                 InputValue::new("direction", TypeRef::named("OrderDirection"))
                 .description("The ordering direction."))
-            .description(format!("Ordering options for {} connections", $base_name));
+            .field(
+                InputValue::new("then", TypeRef::named(&obj_name))
+                .description("Ordering options applied to items which are equal in this order."))
+            .description(with_comment(
+                format!("Ordering options for {} connections", $base_name),
+                $comment,
+            ));
         $types.push(Type::InputObject(order_by_obj))
     };
 }
@@ -189,59 +211,94 @@ macro_rules! define_order_input_types {
 /// # Parameters
 /// - (`obj`: The object to which the connection field is added.)
 /// - `types`: The types vector to which the connection and edge types are added.
+/// - `naming`: The naming strategy of the names of the connection and edge types.
 /// - `fd_name`: The name of the connection field.
 /// - `node_ty_name`: The name of the node type.
-/// - `connection_resolver`: The resolver for the connection field.
+/// - `connection_resolver`: The resolver for the connection field, resolving to a
+///   `ConnectionPage`.
+/// - `node_resolver`: The resolver for the `node` field of an edge.
 /// - `edges`: Additional edge fields.
 /// - `args`: Additional connection arguments.
+/// - `comment`: The comment of the definition the connection is generated from, if any.
+/// - `ty_name`: The prefix of the names of the connection and edge types, defaults to the name of
+///   the node type.
 #[macro_export]
 macro_rules! cursor_pagination {
     (
         $types:ident,
+        $naming:expr,
         $fd_name:expr,
         $node_ty_name:expr,
-        //TODO
-        // $connection_resolver:expr,      // The actual resolver for the connection field on $obj
+        $connection_resolver:expr,
+        node_resolver: $node_resolver:expr,
         edge_fields: $edge_fields_expr:expr,
-        args: [ $( $extra_connection_arg:expr ),* $(,)? ]
+        args: [ $( $extra_connection_arg:expr ),* $(,)? ],
+        comment: $comment:expr
+    ) => {
+        $crate::cursor_pagination!(
+            $types,
+            $naming,
+            $fd_name,
+            $node_ty_name,
+            $connection_resolver,
+            node_resolver: $node_resolver,
+            edge_fields: $edge_fields_expr,
+            args: [ $( $extra_connection_arg ),* ],
+            comment: $comment,
+            ty_name: $node_ty_name
+        )
+    };
+    (
+        $types:ident,
+        $naming:expr,
+        $fd_name:expr,
+        $node_ty_name:expr,
+        $connection_resolver:expr,      // The actual resolver for the connection field on $obj
+        node_resolver: $node_resolver:expr,
+        edge_fields: $edge_fields_expr:expr,
+        args: [ $( $extra_connection_arg:expr ),* $(,)? ],
+        comment: $comment:expr,
+        ty_name: $ty_name:expr
     ) => {
         {
-            let mut edge = Object::new(format!("{}Edge", $node_ty_name))
+            let edge_name = $naming.type_name(&format!("{}_edge", $ty_name));
+            let connection_name = $naming.type_name(&format!("{}_connection", $ty_name));
+            let mut edge = Object::new(&edge_name)
                 .field(Field::new(
                     "cursor",
                     TypeRef::named_nn(TypeRef::STRING),
-                    page_info_resolver("".to_string(), None),
+                    make_edge_resolver("cursor"),
                 ).description("A cursor for use in pagination."))
                 .field(Field::new(
                     "node",
                     TypeRef::named($node_ty_name),
-                    page_info_resolver("".to_string(), None),
+                    $node_resolver,
                 ).description("The item at the end of the edge."))
                 .description("An edge in a connection.");
             for fd in $edge_fields_expr {
                 edge = edge.field(fd);
             }
 
-            let connection = Object::new(format!("{}Connection", $node_ty_name))
+            let connection = Object::new(&connection_name)
                 .field(Field::new(
                     "edges",
-                    TypeRef::named_list(format!("{}Edge", $node_ty_name)),
-                    page_info_resolver("".to_string(), None),
+                    TypeRef::named_list(&edge_name),
+                    make_connection_resolver("edges"),
                 ).description("A list of edges."))
                 .field(Field::new(
                     "nodes",
                     TypeRef::named_list($node_ty_name),
-                    page_info_resolver("".to_string(), None),
+                    make_connection_resolver("nodes"),
                 ).description("A list of nodes."))
                 .field(Field::new(
                     "pageInfo",
                     TypeRef::named_nn("PageInfo"),
-                    page_info_resolver("".to_string(), None),
+                    make_connection_resolver("pageInfo"),
                 ).description("Information to aid in pagination."))
                 .field(Field::new(
                     "totalCount",
                     TypeRef::named_nn(TypeRef::INT),
-                    page_info_resolver("".to_string(), None),
+                    make_connection_resolver("totalCount"),
                 ).description("Identifies the total count of items in the connection."))
                 .description(format!("The connection type for {}.", $node_ty_name));
 
@@ -250,10 +307,13 @@ macro_rules! cursor_pagination {
 
             Field::new(
                 $fd_name,
-                TypeRef::named_nn(format!("{}Connection", $node_ty_name)),
-                page_info_resolver("".to_string(), None),
+                TypeRef::named_nn(&connection_name),
+                $connection_resolver,
             )
-            .description(format!("The connection object for the table `{}`", $fd_name))
+            .description(with_comment(
+                format!("The connection object for the table `{}`", $fd_name),
+                $comment,
+            ))
             .argument(after_input!())
             .argument(before_input!())
             .argument(first_input!())
@@ -271,6 +331,7 @@ macro_rules! cursor_pagination {
 /// - `$fd`: The field definition to parse.
 /// - `$types`: The types vector to which the field type is added.
 /// - `$cursor`: A boolean indicating whether to use cursor pagination.
+/// - `$naming`: The naming strategy of the schema.
 /// - `$tb_name`: The name of the table.
 /// - `$map`: The object map to which the field is added.
 /// - `$field_ident`: The identifier for the field.
@@ -280,6 +341,7 @@ macro_rules! parse_field {
         $fd:ident,
         $types:ident,
         $cursor:ident,
+        $naming:ident,
         $tb_name:ident,
         $map:ident,
         |$field_ident:ident| $($action_tokens:tt)*
@@ -298,8 +360,7 @@ macro_rules! parse_field {
         // Should always contain at least the field name
         if parts.is_empty() { continue; }
 
-        let fd_name = parts.as_slice().last().unwrap().to_string();
-        let fd_name_gql = fd_name.to_camel_case();
+        let fd_name_gql = field_gql_name($fd, $naming)?;
 
         let fd_path = $fd.name.to_path()
             .replace("/", ".")
@@ -315,18 +376,14 @@ macro_rules! parse_field {
         path.push(&table_ident);
         path.extend_from_slice(parts.as_slice());
 
-        let fd_ty = kind_to_type(kind.clone(), $types, path.as_slice())?;
+        let fd_ty = kind_to_type(kind.clone(), $types, path.as_slice(), $naming)?;
 
         // object map used to add fields step by step to the objects
         if kind_non_optional == Kind::Object {
             $map.insert(
                 fd_path.clone(),
                 Object::new(fd_ty.type_name())
-                    .description(if let Some(ref c) = $fd.comment {
-                        format!("{c}")
-                    } else {
-                        "".to_string()
-                    }),
+                    .description(field_gql_description($fd)),
             );
         }
 
@@ -335,11 +392,13 @@ macro_rules! parse_field {
                 // cursor connections only if specified in config
                 Kind::Array(_, _) if $cursor => {
                     if let kind = kind.inner_kind().unwrap() {
-                        let ty_ref = kind_to_type(kind.clone(), $types, path.as_slice())?;
+                        let ty_ref = kind_to_type(kind.clone(), $types, path.as_slice(), $naming)?;
                         let ty_name = ty_ref.type_name();
 
-                        let $field_ident = cursor_pagination!($types, &fd_name_gql, ty_name,
-                        edge_fields: [], args: []);
+                        let $field_ident = cursor_pagination!($types, $naming, &fd_name_gql, ty_name,
+                        make_unsupported_connection_resolver("array fields"),
+                        node_resolver: make_edge_resolver("node"),
+                        edge_fields: [], args: [], comment: Some(field_gql_description($fd)));
                         $($action_tokens)*;
                     }
                 }
@@ -347,15 +406,11 @@ macro_rules! parse_field {
                      let $field_ident = Field::new(
                             fd_name_gql,
                             fd_ty,
-                            make_table_field_resolver(fd_path.as_str(), $fd.kind.clone()),
+                            make_table_field_resolver($fd.name.clone(), $fd.kind.clone(), $naming),
                          // hier der resolver muss handlen koennen simple fields and
                          // arbitrary nested objects
                         )
-                        .description(if let Some(ref c) = $fd.comment {
-                            format!("{c}")
-                        } else {
-                            "".to_string()
-                        });
+                        .description(field_gql_description($fd));
                     $($action_tokens)*;
                 }
             }
@@ -371,13 +426,9 @@ macro_rules! parse_field {
                         .field(Field::new(
                             fd_name_gql,
                             fd_ty,
-                            make_table_field_resolver(fd_path.as_str(), $fd.kind.clone()),
+                            make_table_field_resolver($fd.name.clone(), $fd.kind.clone(), $naming),
                         ))
-                        .description(if let Some(ref c) = $fd.comment {
-                            format!("{c}")
-                        } else {
-                            "".to_string()
-                        }),
+                        .description(field_gql_description($fd)),
                     );
                 }
                 None => return Err(internal_error("Nested field should have parent object.")),
@@ -387,9 +438,9 @@ macro_rules! parse_field {
 }
 
 
-fn filter_name_from_table(tb_name: impl Display) -> String {
+fn filter_name_from_table(tb_name: impl Display, naming: GqlNaming) -> String {
     // format!("Filter{}", tb_name.to_string().to_sentence_case())
-    format!("{}FilterInput", tb_name.to_string().to_pascal_case())
+    naming.type_name(&format!("{tb_name}_filter_input"))
 }
 
 
@@ -414,6 +465,8 @@ pub async fn process_tbs(
     session: &Session,
     datastore: &Arc<Datastore>,
     cursor: bool,
+    wrap_lists: bool,
+    naming: GqlNaming,
 ) -> Result<Object, GqlError> {
     // Type::Any is not supported. FIXME: throw error in the future.
     let (tables, relations): (Vec<&DefineTableStatement>, Vec<&DefineTableStatement>) = tbs
@@ -428,16 +481,34 @@ pub async fn process_tbs(
     // trace!("tables: {:?}", tables);
     // trace!("relations: {:?}", relations);
 
+    // Field permissions only apply to sessions which table permissions are checked for
+    let perms = datastore.setup_options(session).check_perms(Action::View)?;
+
+    // Different names can map to the same GraphQL name, depending on the naming strategy
+    let mut type_names = NameScope::default();
+    let mut query_names = NameScope::default();
+
     for tb in tables.iter() {
         let tb_name = tb.name.to_string();
         let first_tb_name = tb_name.clone();
         let second_tb_name = tb_name.clone();
-        let tb_name_gql = tb_name.to_pascal_case();
-        let tb_name_query = tb_name.to_camel_case(); // field name for the table in the query
+        let tb_name_gql = naming.type_name(&tb_name);
+        let tb_name_query = naming.field_name(&tb_name); // field name for the table in the query
+        type_names.claim(&tb_name_gql, format!("the table `{tb_name}`"))?;
 
         let mut gql_objects: BTreeMap<String, Object> = BTreeMap::new();
 
-        let fds = tx.all_tb_fields(ns, db, &tb.name.0, None).await?;
+        let fds = visible_fields(&tx.all_tb_fields(ns, db, &tb.name.0, None).await?, perms);
+        let fds = untyped_as_any(&fds);
+        check_field_names(&tb_name, &fds, naming)?;
+        // The fields of the table, which the relation fields must not collide with
+        let mut fd_names = NameScope::default();
+        fd_names.claim("id", format!("the field `id` of the table `{tb_name}`"))?;
+        for fd in fds.iter().filter(|fd| matches!(fd.name.0.as_slice(), [Part::Field(_)])) {
+            if fd.name.is_id() { continue; }
+            let origin = format!("the field `{}` of the table `{tb_name}`", fd.name);
+            fd_names.claim(&field_gql_name(fd, naming)?, origin)?;
+        }
 
         let mut tb_ty_obj = Object::new(tb_name_gql.clone())
             .field(Field::new(
@@ -446,9 +517,14 @@ pub async fn process_tbs(
                 make_table_field_resolver(
                     "id",
                     Some(Kind::Record(vec![Table::from(tb_name.clone())])),
+                    naming,
                 ),
             ))
-            .implement("Record");
+            .implement("Record")
+            .description(match &tb.comment {
+                Some(c) => c.as_str().to_string(),
+                None => format!("A record of the table `{tb_name}`."),
+            });
 
         // =======================================================
         // Parse Fields
@@ -457,7 +533,7 @@ pub async fn process_tbs(
             // We have already defined "id", so we don't take any new definition for it.
             if fd.name.is_id() { continue; };
 
-            parse_field!(fd, types, cursor, tb_name, gql_objects, |fd| tb_ty_obj = tb_ty_obj
+            parse_field!(fd, types, cursor, naming, tb_name, gql_objects, |fd| tb_ty_obj = tb_ty_obj
                 .field(fd));
         }
 
@@ -465,8 +541,54 @@ pub async fn process_tbs(
         // Add filters
         // =======================================================
 
+        let tb_filter_name = filter_name_from_table(&tb_name, naming);
+        let mut tb_filter = InputObject::new(&tb_filter_name)
+            .field(InputValue::new("id", TypeRef::named("IDFilterInput")))
+            .field(
+                InputValue::new("and", TypeRef::named_nn_list(&tb_filter_name))
+                    .description("Matches records which match all of the given filters."),
+            )
+            .field(
+                InputValue::new("or", TypeRef::named_nn_list(&tb_filter_name))
+                    .description("Matches records which match any of the given filters."),
+            )
+            .field(
+                InputValue::new("not", TypeRef::named(&tb_filter_name))
+                    .description("Matches records which do not match the given filter."),
+            )
+            .description(with_comment(
+                format!("Filtering options for the table `{}`.", tb_name),
+                tb.comment.as_deref(),
+            ));
+        types.push(Type::InputObject(filter_id()));
+
+        for fd in fds.iter() {
+            if fd.name.is_id() { continue; }
+            let Some(ref kind) = fd.kind else { continue; };
+            // Only top level fields of scalar kinds, or arrays of them, can be filtered on
+            let [Part::Field(_)] = fd.name.0.as_slice() else { continue; };
+            let Some(type_filter_name) = filter_type_name(kind) else { continue; };
+            let fd_name = field_gql_name(fd, naming)?;
+            // Fields sharing their name with a filter combinator can not be filtered on
+            if FILTER_COMBINATORS.contains(&fd_name.as_str()) { continue; }
+
+            let type_filter = filter_from_type(kind.clone(), type_filter_name.clone(), types)?;
+            types.push(Type::InputObject(type_filter));
+            tb_filter = tb_filter.field(InputValue::new(
+                fd_name,
+                TypeRef::named(type_filter_name),
+            ).description(field_gql_description(fd)));
+        }
+        types.push(Type::InputObject(tb_filter));
+
         // Add additional orderBy fields here:
-        define_order_input_types!(types, tb_name,);
+        define_order_input_types!(
+            types,
+            tb_name,
+            orderable_fields(&tb_name, &fds, naming)?,
+            naming: naming,
+            comment: tb.comment.as_deref(),
+        );
 
         // =======================================================
         // Add single instance query
@@ -476,9 +598,11 @@ pub async fn process_tbs(
         let kvs1 = datastore.clone();
         let fds1 = fds.clone();
 
+        let single_name = naming.singular(&tb_name_query);
+        query_names.claim(&single_name, format!("the query of the table `{tb_name}`"))?;
         query = query.field(
             Field::new(
-                tb_name_query.to_singular(),
+                single_name,
                 TypeRef::named(&tb_name_gql),
                 move |ctx| {
                     let tb_name = first_tb_name.clone();
@@ -498,12 +622,9 @@ pub async fn process_tbs(
                                         .into());
                                 }
                             };
-                            let thing = match id.clone().try_into() {
-                                Ok(t) => t,
-                                Err(_) => Thing::from((tb_name, id)),
-                            };
+                            let thing = parse_record_id(&tb_name, &id)?;
 
-                            match gtx.get_record_field(thing, "id").await? {
+                            match gtx.get_record_field(thing, &*ID).await? {
                                 SqlValue::Thing(t) => {
                                     let erased: ErasedRecord = (gtx, t);
                                     Ok(Some(field_val_erase_owned(erased)))
@@ -515,13 +636,54 @@ pub async fn process_tbs(
                 },
             )
                 .description(if let Some(ref c) = &tb.comment {
-                    format!("{c}")
+                    c.as_str().to_string()
                 } else {
                     format!("Generated from table `{}`\nallows querying a single record in a table by ID", &tb_name)
                 })
                 .argument(id_input!()),
         );
 
+        // =======================================================
+        // Add unique field queries
+        // =======================================================
+
+        for ix in tx.all_tb_indexes(ns, db, &tb.name.0).await?.iter() {
+            // Only unique indexes on a single top level field identify a single record
+            if ix.index != Index::Uniq { continue; }
+            let [col] = ix.cols.0.as_slice() else { continue; };
+            let Some(fd) = fds.iter().find(|fd| fd.name == *col) else { continue; };
+            let [Part::Field(_)] = fd.name.0.as_slice() else { continue; };
+            let Some(ref kind) = fd.kind else { continue; };
+            let Some(ty) = filter_value_type(kind) else { continue; };
+            let fd_name = field_gql_name(fd, naming)?;
+
+            let unique_name =
+                naming.field_name(&format!("{}_by_{fd_name}", naming.singular(&tb_name_query)));
+            query_names.claim(
+                &unique_name,
+                format!("the query of the table `{tb_name}` by the field `{}`", fd.name),
+            )?;
+            query = query.field(
+                Field::new(
+                    unique_name,
+                    TypeRef::named(&tb_name_gql),
+                    make_unique_field_resolver(
+                        tb_name.clone(),
+                        fd_name.clone(),
+                        fd.name.clone(),
+                        kind.non_optional().clone(),
+                        session.to_owned(),
+                        datastore.clone(),
+                    ),
+                )
+                    .description(format!(
+                        "Generated from the unique index `{}` on table `{}`\nallows querying a single record in a table by `{}`",
+                        ix.name, tb_name, fd_name
+                    ))
+                    .argument(InputValue::new(&fd_name, TypeRef::NonNull(Box::new(ty)))),
+            );
+        }
+
         // =======================================================
         // Add all instances query
         // =======================================================
@@ -530,23 +692,39 @@ pub async fn process_tbs(
         let kvs2 = datastore.clone();
         let fds2 = fds.clone();
 
+        let list_name = naming.plural(&tb_name_query);
+        query_names.claim(&list_name, format!("the list query of the table `{tb_name}`"))?;
         if cursor {
             query = query.field(
                 cursor_pagination!(
                 types,
-                tb_name_query.to_plural(),
+                naming,
+                &list_name,
                 &tb_name_gql,
+                make_table_connection_resolver(tb_name.clone(), fds.clone(), sess2, kvs2, naming),
+                node_resolver: make_edge_resolver("node"),
                 edge_fields: [],
                 args: [
-                    order_input!(&tb_name)
-                ]
+                    order_input!(&tb_name, naming),
+                    filter_input!(&tb_name, naming)
+                ],
+                comment: tb.comment.as_deref()
             ));
             define_page_info_type!(types);
         } else {
+            // Lists are optionally wrapped with their total count and window
+            let list_ty = match wrap_lists {
+                true => {
+                    let list_result_name = naming.type_name(&format!("{tb_name}_list_result"));
+                    types.push(Type::Object(list_result_type(&list_result_name, &tb_name_gql)));
+                    TypeRef::named_nn(list_result_name)
+                }
+                false => TypeRef::named_nn_list_nn(&tb_name_gql),
+            };
             query = query.field(
                 Field::new(
-                    tb_name_query.to_plural(),
-                    TypeRef::named_nn_list_nn(&tb_name_gql),
+                    list_name,
+                    list_ty,
                     move |ctx| {
                         let tb_name = second_tb_name.clone();
                         let sess2 = sess2.clone();
@@ -558,84 +736,48 @@ pub async fn process_tbs(
                             let args = ctx.args.as_index_map();
                             trace!("received request with args: {args:?}");
 
-                            // let start = args.get("start").and_then(|v| v.as_i64()).map(|s| s.intox());
-                            //
-                            // let limit = args.get("limit").and_then(|v| v.as_i64()).map(|l| l.intox());
-                            //
-                            // let order = args.get("order");
-                            //
-                            // let filter = args.get("filter");
-
-                            // let orders = match order {
-                            //     Some(GqlValue::Object(o)) => {
-                            //         let mut orders = vec![];
-                            //         let mut current = o;
-                            //         loop {
-                            //             let asc = current.get("asc");
-                            //             let desc = current.get("desc");
-                            //             match (asc, desc) {
-                            //                 (Some(_), Some(_)) => {
-                            //                     return Err("Found both ASC and DESC in order".into());
-                            //                 }
-                            //                 (Some(GqlValue::Enum(a)), None) => {
-                            //                     orders.push(order!(asc, a.as_str()))
-                            //                 }
-                            //                 (None, Some(GqlValue::Enum(d))) => {
-                            //                     orders.push(order!(desc, d.as_str()))
-                            //                 }
-                            //                 (_, _) => {
-                            //                     break;
-                            //                 }
-                            //             }
-                            //             if let Some(GqlValue::Object(next)) = current.get("then") {
-                            //                 current = next;
-                            //             } else {
-                            //                 break;
-                            //             }
-                            //         }
-                            //         Some(orders)
-                            //     }
-                            //     _ => None,
-                            // };
-                            // trace!("parsed orders: {orders:?}");
-
-                            // let cond = match filter {
-                            //     Some(f) => {
-                            //         let o = match f {
-                            //             GqlValue::Object(o) => o,
-                            //             f => {
-                            //                 error!("Found filter {f}, which should be object and should have been rejected by async graphql.");
-                            //                 return Err("Value in cond doesn't fit schema".into());
-                            //             }
-                            //         };
-                            //
-                            //         let cond = cond_from_filter(o, &fds2)?;
-                            //
-                            //         Some(cond)
-                            //     }
-                            //     None => None,
-                            // };
-                            // trace!("parsed filter: {cond:?}");
+                            let start = parse_count_arg(args, "start")?.map(|s| s as i64);
+                            let limit = parse_count_arg(args, "limit")?.map(|l| l as i64);
+
+                            let orders = parse_order_input(args.get("orderBy"), &fds2, naming)?;
+                            trace!("parsed orders: {orders:?}");
+
+                            let cond = parse_filter_arg(args, &tb_name, &fds2, naming)?;
+                            trace!("parsed filter: {cond:?}");
 
                             // SELECT VALUE id FROM ...
-                            let ast = Statement::Select({
-                                SelectStatement {
-                                    what: vec![SqlValue::Table(tb_name.intox())].into(),
-                                    expr: Fields(
-                                        vec![sql::Field::Single {
-                                            expr: SqlValue::Idiom(Idiom::from("id")),
+                            // Records are ordered by the selected fields, so the fields ordered
+                            // by are selected alongside the id when an ordering is given.
+                            let expr = match &orders {
+                                Some(orders) => Fields(
+                                    std::iter::once(Idiom::from("id"))
+                                        .chain(orders.iter().map(|o| o.value.clone()))
+                                        .map(|i| sql::Field::Single {
+                                            expr: SqlValue::Idiom(i),
                                             alias: None,
-                                        }],
-                                        // this means the `value` keyword
-                                        true,
-                                    ),
-                                    // order: orders.map(|x| Ordering::Order(OrderList(x))),
-                                    // cond,
-                                    // limit,
-                                    // start,
-                                    ..Default::default()
-                                }
-                            });
+                                        })
+                                        .collect(),
+                                    false,
+                                ),
+                                None => Fields(
+                                    vec![sql::Field::Single {
+                                        expr: SqlValue::Idiom(Idiom::from("id")),
+                                        alias: None,
+                                    }],
+                                    // this means the `value` keyword
+                                    true,
+                                ),
+                            };
+                            let select = SelectStatement {
+                                what: vec![SqlValue::Table(tb_name.intox())].into(),
+                                expr,
+                                order: orders.map(|x| Ordering::Order(OrderList(x))),
+                                cond,
+                                limit: limit.map(IntoExt::intox),
+                                start: start.map(IntoExt::intox),
+                                ..Default::default()
+                            };
+                            let ast = Statement::Select(select.clone());
                             trace!("generated query ast: {ast:?}");
 
                             let res = gtx.process_stmt(ast).await?;
@@ -653,35 +795,52 @@ pub async fn process_tbs(
 
                             trace!("query result array: {res_vec:?}");
 
-                            let out: Result<Vec<FieldValue>, SqlValue> = res_vec
+                            let out: Result<Vec<Thing>, SqlValue> = res_vec
                                 .0
                                 .into_iter()
-                                .map(|v| {
-                                    v.try_as_thing().map(|t| {
-                                        let erased: ErasedRecord = (gtx.clone(), t);
-                                        field_val_erase_owned(erased)
-                                    })
+                                .map(|v| match v {
+                                    SqlValue::Object(_) => v.pick(&*ID),
+                                    v => v,
                                 })
+                                .map(|v| v.try_as_thing())
                                 .collect();
 
-                            match out {
-                                Ok(l) => Ok(Some(FieldValue::list(l))),
+                            let records = match out {
+                                Ok(records) => records,
                                 Err(v) => {
-                                    Err(internal_error(format!("expected thing, found: {v:?}")).into())
+                                    return Err(internal_error(format!("expected thing, found: {v:?}")).into())
                                 }
+                            };
+                            // Fetch the records of the page at once, instead of one by one as
+                            // their fields are resolved
+                            gtx.get_records(records.clone()).await?;
+
+                            match wrap_lists {
+                                true => Ok(Some(FieldValue::owned_any(ListPage {
+                                    gtx,
+                                    select,
+                                    records,
+                                    start: start.unwrap_or(0),
+                                    limit,
+                                }))),
+                                false => Ok(Some(FieldValue::list(records.into_iter().map(|t| {
+                                    let erased: ErasedRecord = (gtx.clone(), t);
+                                    field_val_erase_owned(erased)
+                                })))),
                             }
                         })
                     },
                 )
                     .description(if let Some(ref c) = &tb.comment {
-                        format!("{c}")
+                        c.as_str().to_string()
                     } else {
                         format!("Generated from table `{}`\nallows querying a table with filters",
                                 &tb_name)
                     })
                     .argument(limit_input!())
-                    .argument(order_input!(&tb_name))
-                // .argument(filter_input!(&tb_name))
+                    .argument(start_input!())
+                    .argument(order_input!(&tb_name, naming))
+                    .argument(filter_input!(&tb_name, naming))
             );
         }
 
@@ -689,15 +848,8 @@ pub async fn process_tbs(
         // Add relations
         // =======================================================
 
-        for rel in relations.iter().filter(|stmt| {
-            match &stmt.kind {
-                TableType::Relation(r) => match &r.from {
-                    Some(Kind::Record(tbs)) => tbs.contains(&Table::from(tb_name.clone())),
-                    _ => false,
-                },
-                _ => false,
-            }
-        }) {
+        let tb = Table::from(tb_name.clone());
+        for rel in relations.iter() {
             let rel_name = rel.name.to_string();
 
             let (ins, outs) = match &rel.kind {
@@ -708,59 +860,126 @@ pub async fn process_tbs(
                 _ => continue,
             };
 
-            let mut fd_map: BTreeMap<String, Object> = BTreeMap::new();
-            let mut fd_vec = Vec::<Field>::new();
-
-            let fds = tx.all_tb_fields(ns, db, &rel.name.0, None).await?;
-
-            //todo?: das hier nur n mal machen. Also nur dann wenn nicht vec ins > 1, bzw schon in map
-            // possible performance improvements by skipping fields for prev relations
-            for fd in fds.iter().filter(|fd| {
-                match fd.name.to_string().as_str() {
-                    "in" => false,
-                    "out" => false,
-                    // "id" => false, // FIXME: prob not wanted
-                    _ => true,
-                }
-            }) {
-                parse_field!(fd, types, cursor, rel_name, fd_map, |fd| fd_vec.push(fd));
+            // Outgoing relations (`->rel->`) are listed on the `from` tables and resolve to the
+            // `to` records, incoming relations (`<-rel<-`) the other way around.
+            let dirs: Vec<(sql::Dir, &Vec<Table>)> = [(sql::Dir::Out, ins, outs), (sql::Dir::In, outs, ins)]
+                .into_iter()
+                .filter(|(_, tbs, _)| tbs.contains(&tb))
+                .map(|(dir, _, nodes)| (dir, nodes))
+                .collect();
+            if dirs.is_empty() {
+                continue;
             }
 
-            // Node type for the relation connection
-            let node_ty_name = match outs.len() {
-                // we have only one `to` table, thus we can use the object type directly
-                1 => outs.first().unwrap().to_string().to_pascal_case(),
-                // we have more than one `to` table, thus we need a union type
-                _ => {
-                    let mut tmp_union = Union::new(format!("{}Union", rel.name.to_raw().to_pascal_case()));
-                    for n in outs {
-                        tmp_union = tmp_union.possible_type(n.0.to_string().to_pascal_case());
+            let fds = visible_fields(&tx.all_tb_fields(ns, db, &rel.name.0, None).await?, perms);
+            let fds = untyped_as_any(&fds);
+            check_field_names(&rel_name, &fds, naming)?;
+
+            // Types of the records at the ends of the relation, a union type if there is more
+            // than one table at an end
+            let in_ty_name = records_type_name(
+                ins,
+                naming.type_name(&format!("incoming_{}_union", rel.name.to_raw())),
+                with_comment(
+                    format!("The records relating through `{}`.", rel.name.to_raw()),
+                    rel.comment.as_deref(),
+                ),
+                types,
+                naming,
+            );
+            let out_ty_name = records_type_name(
+                outs,
+                naming.type_name(&format!("{}_union", rel.name.to_raw())),
+                with_comment(
+                    format!("The records related through `{}`.", rel.name.to_raw()),
+                    rel.comment.as_deref(),
+                ),
+                types,
+                naming,
+            );
+
+            for (dir, nodes) in dirs {
+                let mut fd_map: BTreeMap<String, Object> = BTreeMap::new();
+                let mut fd_vec = Vec::<Field>::new();
+
+                //todo?: das hier nur n mal machen. Also nur dann wenn nicht vec ins > 1, bzw schon in map
+                // possible performance improvements by skipping fields for prev relations
+                for fd in fds.iter().filter(|fd| {
+                    match fd.name.to_string().as_str() {
+                        "in" => false,
+                        "out" => false,
+                        // "id" => false, // FIXME: prob not wanted
+                        _ => true,
                     }
-                    // async_graphql types do not implement clone, thus we need to get the typename
-                    // before the move
-                    let union_name = tmp_union.type_name().to_string();
-                    types.push(Type::Union(tmp_union));
+                }) {
+                    parse_field!(fd, types, cursor, naming, rel_name, fd_map, |fd| fd_vec.push(fd));
+                }
 
-                    union_name
+                let rel_base_name = match dir {
+                    sql::Dir::In => format!("incoming_{}", rel.name.to_raw()),
+                    _ => rel.name.to_raw(),
+                };
+                let rel_ty_name = naming.type_name(&rel_base_name);
+                let rel_fd_name = naming.plural(&naming.field_name(&rel_base_name));
+                fd_names.claim(
+                    &rel_fd_name,
+                    format!("the relation `{}` of the table `{tb_name}`", rel.name.to_raw()),
+                )?;
+
+                // Node type for the relation connection
+                let node_ty_name = match dir {
+                    sql::Dir::In => in_ty_name.clone(),
+                    _ => out_ty_name.clone(),
+                };
+
+                // The records at both ends of the relation, which the edges link
+                fd_vec.push(Field::new(
+                    naming.field_name("in"),
+                    TypeRef::named(&in_ty_name),
+                    make_relation_node_resolver(sql::Dir::In, ins.len() > 1, naming),
+                ).description("The record the relation is from."));
+                fd_vec.push(Field::new(
+                    naming.field_name("out"),
+                    TypeRef::named(&out_ty_name),
+                    make_relation_node_resolver(sql::Dir::Out, outs.len() > 1, naming),
+                ).description("The record the relation is to."));
+
+                // Incoming connections get their own connection and edge types, as their node
+                // type would otherwise clash with the connection types of the `from` table.
+                let ty_name = match dir {
+                    sql::Dir::In => rel_ty_name.clone(),
+                    _ => node_ty_name.clone(),
+                };
+
+                tb_ty_obj = tb_ty_obj.field(
+                    cursor_pagination!(
+                    types,
+                    naming,
+                    &rel_fd_name,
+                    &node_ty_name,
+                    make_relation_connection_resolver(rel.name.to_raw(), dir.clone(), nodes.len() > 1, naming),
+                    node_resolver: make_relation_node_resolver(dir.clone(), nodes.len() > 1, naming),
+                    edge_fields: fd_vec,
+                    args: [
+                        order_input!(&tb_name, naming)
+                    ],
+                    comment: rel.comment.as_deref(),
+                    ty_name: ty_name
+                ));
+
+                for (_, obj) in fd_map {
+                    types.push(Type::Object(obj));
                 }
-            };
+            }
 
-            tb_ty_obj = tb_ty_obj.field(
-                cursor_pagination!(
+            define_order_input_types!(
                 types,
-                rel.name.to_raw().to_camel_case().to_plural(),
-                &node_ty_name,
-                edge_fields: fd_vec,
-                args: [
-                    order_input!(&tb_name)
-                ]
-            ));
-
-            define_order_input_types!(types, rel.name.to_raw(),);
-
-            for (_, obj) in fd_map {
-                types.push(Type::Object(obj));
-            }
+                rel.name.to_raw(),
+                orderable_fields(&tb_name, &fds, naming)?,
+                naming: naming,
+                comment: rel.comment.as_deref(),
+            );
+            define_page_info_type!(types);
         }
 
         // =======================================================
@@ -775,15 +994,105 @@ pub async fn process_tbs(
         define_order_direction_enum!(types); // Needed for order_input
     }
 
+    // The types are pushed in the order the tables and fields are processed in, they are sorted
+    // so that the generated schema is the same on every run. The sort is stable, so that the last
+    // of several definitions of a type still wins when registering them.
+    types.sort_by(|a, b| Named::name(a).cmp(Named::name(b)));
+
     Ok(query)
 }
 
-//TODO: bug: type HomeTypeEnum enum is optional even though it shouldn't
+/// Returns the name of the type of records of the given tables, which is the union type
+/// `union_name` added to `types` if there is more than one table.
+fn records_type_name(
+    tbs: &[Table],
+    union_name: String,
+    description: String,
+    types: &mut Vec<Type>,
+    naming: GqlNaming,
+) -> String {
+    if let [tb] = tbs {
+        return naming.type_name(&tb.0);
+    }
+    let mut tmp_union = Union::new(&union_name).description(description);
+    for tb in tbs {
+        tmp_union = tmp_union.possible_type(naming.type_name(&tb.0));
+    }
+    types.push(Type::Union(tmp_union));
+    union_name
+}
+
+/// Returns the fields which are visible to the session, given whether field permissions apply to it.
+///
+/// Fields the session may never select are left out of the schema, so that their existence is not
+/// revealed. Fields with conditional permissions are made optional, as selecting a record leaves
+/// out the fields the session is not permitted to see, which then resolve to null.
+fn visible_fields(fds: &[DefineFieldStatement], perms: bool) -> Arc<[DefineFieldStatement]> {
+    if !perms {
+        return fds.into();
+    }
+    // Nested fields are hidden along with their parent
+    let hidden: Vec<&Idiom> =
+        fds.iter().filter(|fd| fd.permissions.select.is_none()).map(|fd| &fd.name).collect();
+    fds.iter()
+        .filter(|fd| !hidden.iter().any(|h| fd.name.0.starts_with(&h.0)))
+        .map(|fd| match (&fd.permissions.select, &fd.kind) {
+            (Permission::Specific(_), Some(kind)) if !matches!(kind, Kind::Option(_)) => {
+                let mut fd = fd.clone();
+                fd.kind = Some(Kind::Option(Box::new(kind.clone())));
+                fd
+            }
+            _ => fd.clone(),
+        })
+        .collect()
+}
+
+/// Returns the fields with objects whose structure is not defined through nested fields typed as
+/// `any`, so that they are represented by the `JSON` scalar instead of an object type without
+/// fields. Computed fields without a type are typed as `any` too, as their values are only known
+/// once they are computed.
+fn untyped_as_any(fds: &[DefineFieldStatement]) -> Arc<[DefineFieldStatement]> {
+    fn replace_object(kind: &Kind) -> Kind {
+        match kind {
+            Kind::Object => Kind::Any,
+            Kind::Option(k) => Kind::Option(Box::new(replace_object(k))),
+            Kind::Array(k, l) => Kind::Array(Box::new(replace_object(k)), *l),
+            Kind::Set(k, l) => Kind::Set(Box::new(replace_object(k)), *l),
+            k => k.clone(),
+        }
+    }
 
+    fds.iter()
+        .map(|fd| {
+            // The fields defining the elements of arrays, `field[*]`, do not define a structure
+            let has_children = fds.iter().any(|c| {
+                c.name.0.len() > fd.name.0.len()
+                    && c.name.0.starts_with(&fd.name.0)
+                    && !matches!(c.name.0.last(), Some(Part::All))
+            });
+            match &fd.kind {
+                Some(kind) if !has_children => {
+                    let mut fd = fd.clone();
+                    fd.kind = Some(replace_object(kind));
+                    fd
+                }
+                None if fd.value.is_some() && !has_children => {
+                    let mut fd = fd.clone();
+                    fd.kind = Some(Kind::Any);
+                    fd
+                }
+                _ => fd.clone(),
+            }
+        })
+        .collect()
+}
+
+/// Resolves the field at `fd_path` of the record which is the parent value. Nested fields are
+/// resolved with the full path from the record, as objects pass down their record as parent value.
 fn make_table_field_resolver(
-    // fd_name: impl Into<String>,
-    fd_path: impl Into<String>,
+    fd_path: impl Into<Idiom>,
     kind: Option<Kind>,
+    naming: GqlNaming,
 ) -> impl for<'a> Fn(ResolverContext<'a>) -> FieldFuture<'a> + Send + Sync + 'static {
     let fd_path = fd_path.into();
     move |ctx: ResolverContext| {
@@ -808,18 +1117,36 @@ fn make_table_field_resolver(
                 trace!("Parent is ErasedRecord for path '{}', RID: {}", fd_path, rid);
 
                 match field_kind {
-                    // A) Field is Object or Record link (not 'id'): Pass ErasedRecord context down
-                    Some(Kind::Object) | Some(Kind::Record(_)) if fd_path != "id" => {
-                        trace!("Field at path '{}' is Object/Record, passing down ErasedRecord", fd_path);
-                        // let gtx_clone = gtx.clone();
-                        // let rid_clone = rid.clone();
-                        // let nested_context: ErasedRecord = (gtx_clone, rid_clone);
-                        // let field_value = field_val_erase_owned(nested_context);
-                        // let field_value = ;
-                        // Optional: Add .with_type() hints for record link unions/interfaces here if needed
+                    // A) Field is Object: Pass ErasedRecord context down
+                    Some(Kind::Object) if !fd_path.is_id() => {
+                        trace!("Field at path '{}' is Object, passing down ErasedRecord", fd_path);
                         Ok(Some(field_val_erase_owned((gtx.clone(), rid.clone()))))
                     }
 
+                    // A') Field is Record link (not 'id'): Pass the linked record down
+                    Some(ref k) if !fd_path.is_id() && matches!(k.non_optional(), Kind::Record(_)) => {
+                        trace!("Field at path '{}' is Record, passing down linked ErasedRecord", fd_path);
+                        let Kind::Record(tbs) = k.non_optional() else {
+                            unreachable!("the kind was matched as a record link")
+                        };
+                        match gtx.get_record_field(rid.clone(), &fd_path).await? {
+                            SqlValue::Thing(thing_val) => {
+                                // Links to more than one table are of a union type,
+                                // which requires the type to be given explicitly
+                                let ty = (tbs.len() > 1).then(|| naming.type_name(&thing_val.tb));
+                                let nested = field_val_erase_owned((gtx.clone(), thing_val));
+                                Ok(Some(match ty {
+                                    Some(ty) => nested.with_type(ty),
+                                    None => nested,
+                                }))
+                            }
+                            SqlValue::None | SqlValue::Null => Ok(None),
+                            other => Err(internal_error(format!(
+                                "Expected Thing for Record field path '{}', got {:?}",
+                                fd_path, other
+                            )).into()),
+                        }
+                    }
 
                     // C) Field is an Array
                     Some(Kind::Array(inner_kind_box, _)) => {
@@ -832,7 +1159,7 @@ fn make_table_field_resolver(
                                 // &Box<Kind>
                                 let mut gql_item_values = Vec::new();
 
-                                for item_sql_value in surreal_array.0 { // Assuming surreal_array.0 is Vec<SqlValue>
+                                for (idx, item_sql_value) in surreal_array.0.into_iter().enumerate() {
                                     let concrete_item_kind = inner_kind_ref.non_optional();
                                     let item_is_nullable = inner_kind_ref.can_be_none();
 
@@ -849,12 +1176,19 @@ fn make_table_field_resolver(
                                     }
 
                                     match concrete_item_kind {
-                                        Kind::Record(_) => {
+                                        Kind::Record(tbs) => {
                                             match item_sql_value {
                                                 SqlValue::Thing(thing_val) => {
+                                                    // Links to more than one table are of a union type,
+                                                    // which requires the type to be given explicitly
+                                                    let ty = (tbs.len() > 1).then(|| naming.type_name(&thing_val.tb));
                                                     // Assuming ErasedRecord is (GQLTx, Thing)
                                                     let nested_context: ErasedRecord = (gtx.clone(), thing_val);
-                                                    gql_item_values.push(field_val_erase_owned(nested_context));
+                                                    let nested = field_val_erase_owned(nested_context);
+                                                    gql_item_values.push(match ty {
+                                                        Some(ty) => nested.with_type(ty),
+                                                        None => nested,
+                                                    });
                                                 }
                                                 _ => return Err(internal_error(format!(
                                                     "Expected Thing for Record array element at path '{}', got {:?}",
@@ -881,8 +1215,7 @@ fn make_table_field_resolver(
                                         // Other scalar types
                                         _ => {
                                             let gql_val = sql_value_to_gql_value(item_sql_value)
-                                                .map_err(|e| GqlError::ResolverError(format!("SQL\
-                                                 to GQL translation failed for path '{}': {}", fd_path, e)))?;
+                                                .map_err(|e| e.at_index(idx).in_field(&fd_path).in_field(&rid.tb))?;
                                             gql_item_values.push(FieldValue::value(gql_val));
                                         }
                                     }
@@ -906,7 +1239,6 @@ fn make_table_field_resolver(
                     _ => {
                         trace!("Field at path '{}' is scalar/id/terminal, fetching value via get_record_field", fd_path);
 
-                        // Call the modified get_record_field which accepts a path string
                         let sql_value: SqlValue = gtx
                             .get_record_field(rid.clone(), &fd_path)
                             .await?;
@@ -921,7 +1253,8 @@ fn make_table_field_resolver(
                             // unexpectedly for scalar paths.
                             SqlValue::Thing(thing_val) => {
                                 trace!("Value for path '{}' is Thing: {}", fd_path, thing_val);
-                                let gql_val = sql_value_to_gql_value(SqlValue::Thing(thing_val))?;
+                                let gql_val = sql_value_to_gql_value(SqlValue::Thing(thing_val))
+                                    .map_err(|e| e.in_field(&fd_path).in_field(&rid.tb))?;
                                 Ok(Some(FieldValue::value(gql_val)))
                             }
 
@@ -945,7 +1278,7 @@ fn make_table_field_resolver(
                                     }
                                 } else {
                                     sql_value_to_gql_value(v)
-                                        .map_err(|e| GqlError::ResolverError(format!("SQL to GQL translation failed for path '{}': {}", fd_path, e)))?
+                                        .map_err(|e| e.in_field(&fd_path).in_field(&rid.tb))?
                                 };
 
                                 trace!("Conversion successful for path '{}': {:?}", fd_path, gql_val);
@@ -1228,14 +1561,534 @@ macro_rules! filter_impl {
 	};
 }
 
-//FIXME: implement
-fn page_info_resolver(
-    db_name: String, // DB name (e.g., "created_at", "size")
-    kind: Option<Kind>,
+/// A page of records resolved by a connection field, used as the parent value of the fields of
+/// the connection and its `PageInfo`.
+#[derive(Clone)]
+struct ConnectionPage {
+    gtx: GQLTx,
+    /// Selects the ids of all records in the connection, regardless of pagination.
+    select: SelectStatement,
+    /// The records of the edges in the page, which are paginated over.
+    records: Vec<Thing>,
+    /// The records at the end of the edges, the same as `records` unless the edges are relations.
+    nodes: Vec<Thing>,
+    /// The naming strategy of the schema if the nodes are of a union type, which requires their
+    /// type to be given explicitly.
+    union_nodes: Option<GqlNaming>,
+    has_next_page: bool,
+    has_previous_page: bool,
+}
+
+/// A window of the records of a table resolved by a list query, used as the parent value of the
+/// fields of its list result type when lists are wrapped.
+#[derive(Clone)]
+struct ListPage {
+    gtx: GQLTx,
+    /// Selects the records in the window, its condition selects the records counted in the total.
+    select: SelectStatement,
+    records: Vec<Thing>,
+    start: i64,
+    limit: Option<i64>,
+}
+
+fn parse_cursor_arg(args: &IndexMap<Name, GqlValue>, name: &str) -> Result<Option<Thing>, GqlError> {
+    match args.get(name) {
+        None | Some(GqlValue::Null) => Ok(None),
+        Some(GqlValue::String(s)) => decode_cursor(s).map(Some),
+        Some(v) => Err(input_error(format!("Expected `{name}` to be a cursor, found: {v}"))),
+    }
+}
+
+fn parse_count_arg(args: &IndexMap<Name, GqlValue>, name: &str) -> Result<Option<usize>, GqlError> {
+    match args.get(name) {
+        None | Some(GqlValue::Null) => Ok(None),
+        Some(v) => match v.as_i64() {
+            Some(n) if n >= 0 && n <= u32::MAX as i64 => Ok(Some(n as usize)),
+            _ => Err(input_error(format!(
+                "Expected `{name}` to be a non-negative integer no greater than {}, found: {v}",
+                u32::MAX
+            ))),
+        },
+    }
+}
+
+/// Parses the `filterBy` argument into the condition selecting the matching records.
+fn parse_filter_arg(
+    args: &IndexMap<Name, GqlValue>,
+    tb_name: &str,
+    fds: &[DefineFieldStatement],
+    naming: GqlNaming,
+) -> Result<Option<Cond>, GqlError> {
+    match args.get("filterBy") {
+        None | Some(GqlValue::Null) => Ok(None),
+        Some(GqlValue::Object(o)) => cond_from_filter(o, tb_name, fds, naming).map(Some),
+        Some(f) => {
+            error!("Found filter {f}, which should be object and should have been rejected by async graphql.");
+            Err(input_error("Value in filterBy doesn't fit schema"))
+        }
+    }
+}
+
+/// Adds `id <op> thing` to the condition of the given statement.
+fn and_id_cond(mut stmt: SelectStatement, o: sql::Operator, thing: &Thing) -> SelectStatement {
+    let expr: SqlValue = Expression::Binary {
+        l: SqlValue::Idiom(Idiom::from("id")),
+        o,
+        r: SqlValue::Thing(thing.clone()),
+    }
+        .into();
+    let cond = match stmt.cond.take() {
+        Some(Cond(c)) => Expression::Binary { l: c, o: sql::Operator::And, r: expr }.into(),
+        None => expr,
+    };
+    stmt.cond = Some(cond.intox());
+    stmt
+}
+
+async fn select_records(gtx: &GQLTx, stmt: SelectStatement) -> Result<Vec<Thing>, GqlError> {
+    let res = gtx.process_stmt(Statement::Select(stmt)).await?;
+    let SqlValue::Array(a) = res else {
+        return Err(internal_error(format!("expected array, found: {res:?}")));
+    };
+    a.0.into_iter()
+        .map(TryAsExt::try_as_thing)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|v| internal_error(format!("expected thing, found: {v:?}")))
+}
+
+/// Applies the `first`, `last`, `after` and `before` arguments to the records selected by the
+/// given `SELECT VALUE id` statement, ordering the records by id.
+async fn paginate(
+    gtx: GQLTx,
+    select: SelectStatement,
+    args: &IndexMap<Name, GqlValue>,
+) -> Result<ConnectionPage, GqlError> {
+    let first = parse_count_arg(args, "first")?;
+    let last = parse_count_arg(args, "last")?;
+    let after = parse_cursor_arg(args, "after")?;
+    let before = parse_cursor_arg(args, "before")?;
+
+    let mut stmt = select.clone();
+    if let Some(ref after) = after {
+        stmt = and_id_cond(stmt, sql::Operator::MoreThan, after);
+    }
+    if let Some(ref before) = before {
+        stmt = and_id_cond(stmt, sql::Operator::LessThan, before);
+    }
+
+    // Only paginate backwards when `last` is given without `first`.
+    let backwards = last.is_some() && first.is_none();
+    let limit = if backwards { last } else { first };
+
+    let order = sql::Order {
+        value: Idiom::from("id"),
+        direction: !backwards,
+        ..Default::default()
+    };
+    stmt.order = Some(Ordering::Order(OrderList(vec![order])));
+    // Fetch one more record than requested to know whether there are more.
+    stmt.limit = limit.map(|l| (l as i64 + 1).intox());
+
+    let mut records = select_records(&gtx, stmt).await?;
+    let has_more = limit.is_some_and(|l| records.len() > l);
+    if let Some(l) = limit {
+        records.truncate(l);
+    }
+    if backwards {
+        records.reverse();
+    }
+
+    // When given both, `last` is applied to the page selected by `first`.
+    let mut truncated_start = false;
+    if let (Some(_), Some(last)) = (first, last) {
+        if records.len() > last {
+            records.drain(..records.len() - last);
+            truncated_start = true;
+        }
+    }
+
+    // Checks if there are any records on the other side of a cursor.
+    let exists_beyond = |o: sql::Operator, cursor: Option<Thing>| {
+        let gtx = gtx.clone();
+        let select = select.clone();
+        async move {
+            let Some(cursor) = cursor else { return Ok(false) };
+            let mut stmt = and_id_cond(select, o, &cursor);
+            stmt.limit = Some(1.intox());
+            select_records(&gtx, stmt).await.map(|r| !r.is_empty())
+        }
+    };
+
+    let (has_next_page, has_previous_page) = if backwards {
+        (exists_beyond(sql::Operator::MoreThanOrEqual, before).await?, has_more)
+    } else {
+        let has_previous =
+            truncated_start || exists_beyond(sql::Operator::LessThanOrEqual, after).await?;
+        (has_more, has_previous)
+    };
+
+    // Fetch the records of the page at once, instead of one by one as their fields are resolved
+    gtx.get_records(records.clone()).await?;
+
+    Ok(ConnectionPage {
+        gtx,
+        select,
+        nodes: records.clone(),
+        union_nodes: None,
+        records,
+        has_next_page,
+        has_previous_page,
+    })
+}
+
+/// Resolves the connection field listing the records of a table.
+fn make_table_connection_resolver(
+    tb_name: String,
+    fds: Arc<[DefineFieldStatement]>,
+    session: Session,
+    datastore: Arc<Datastore>,
+    naming: GqlNaming,
+) -> impl for<'a> Fn(ResolverContext<'a>) -> FieldFuture<'a> + Send + Sync + 'static {
+    move |ctx: ResolverContext| {
+        let tb_name = tb_name.clone();
+        let fds = fds.clone();
+        let session = session.clone();
+        let datastore = datastore.clone();
+        FieldFuture::new(async move {
+            let gtx = GQLTx::new(&datastore, &session).await?;
+            let args = ctx.args.as_index_map();
+            trace!("received connection request with args: {args:?}");
+
+            let cond = parse_filter_arg(args, &tb_name, &fds, naming)?;
+            trace!("parsed filter: {cond:?}");
+
+            // SELECT VALUE id FROM ...
+            let select = SelectStatement {
+                what: vec![SqlValue::Table(tb_name.intox())].into(),
+                expr: Fields(
+                    vec![sql::Field::Single {
+                        expr: SqlValue::Idiom(Idiom::from("id")),
+                        alias: None,
+                    }],
+                    // this means the `value` keyword
+                    true,
+                ),
+                cond,
+                ..Default::default()
+            };
+
+            let page = paginate(gtx, select, args).await?;
+            Ok(Some(FieldValue::owned_any(page)))
+        })
+    }
+}
+
+/// Resolves a query for the single record of the table `tb_name` with the given value for the
+/// field `fd`, which has a unique index, i.e. `SELECT VALUE id FROM $tb WHERE $fd = $val LIMIT 1`.
+fn make_unique_field_resolver(
+    tb_name: String,
+    arg_name: String,
+    fd: Idiom,
+    kind: Kind,
+    session: Session,
+    datastore: Arc<Datastore>,
+) -> impl for<'a> Fn(ResolverContext<'a>) -> FieldFuture<'a> + Send + Sync + 'static {
+    move |ctx: ResolverContext| {
+        let tb_name = tb_name.clone();
+        let arg_name = arg_name.clone();
+        let fd = fd.clone();
+        let kind = kind.clone();
+        let session = session.clone();
+        let datastore = datastore.clone();
+        FieldFuture::new(async move {
+            let gtx = GQLTx::new(&datastore, &session).await?;
+            let args = ctx.args.as_index_map();
+            trace!("received unique field request with args: {args:?}");
+
+            let Some(val) = args.get(arg_name.as_str()) else {
+                return Err(input_error(format!(
+                    "Schema validation failed: No {arg_name} found in arguments"
+                ))
+                .into());
+            };
+            let val = gql_to_sql_kind(val, kind)
+                .map_err(|e| e.in_field(&arg_name).in_field(&tb_name))?;
+
+            let select = SelectStatement {
+                what: vec![SqlValue::Table(tb_name.intox())].into(),
+                expr: Fields(
+                    vec![sql::Field::Single {
+                        expr: SqlValue::Idiom(Idiom::from("id")),
+                        alias: None,
+                    }],
+                    // this means the `value` keyword
+                    true,
+                ),
+                cond: Some(
+                    SqlValue::from(Expression::Binary {
+                        l: SqlValue::Idiom(fd),
+                        o: sql::Operator::Equal,
+                        r: val,
+                    })
+                    .intox(),
+                ),
+                limit: Some(1.intox()),
+                ..Default::default()
+            };
+
+            match select_records(&gtx, select).await?.into_iter().next() {
+                Some(t) => {
+                    let erased: ErasedRecord = (gtx, t);
+                    Ok(Some(field_val_erase_owned(erased)))
+                }
+                None => Ok(None),
+            }
+        })
+    }
+}
+
+/// Resolves the connection field listing the records related to the parent record through the
+/// relation table `rel_name`, i.e. `$parent->rel_name->?` for outgoing and `$parent<-rel_name<-?`
+/// for incoming relations.
+fn make_relation_connection_resolver(
+    rel_name: String,
+    dir: sql::Dir,
+    union_nodes: bool,
+    naming: GqlNaming,
+) -> impl for<'a> Fn(ResolverContext<'a>) -> FieldFuture<'a> + Send + Sync + 'static {
+    move |ctx: ResolverContext| {
+        let rel_name = rel_name.clone();
+        let dir = dir.clone();
+        FieldFuture::new(async move {
+            let (ref gtx, ref rid) = ctx
+                .parent_value
+                .downcast_ref::<ErasedRecord>()
+                .ok_or_else(|| internal_error("failed to downcast"))?;
+            let args = ctx.args.as_index_map();
+            trace!("received relation connection request with args: {args:?}");
+
+            // SELECT VALUE id FROM $rel WHERE in = $rid, or `out` for incoming relations
+            // The edges are paginated by the ids of the relation records.
+            let select = SelectStatement {
+                what: vec![SqlValue::Table(rel_name.intox())].into(),
+                expr: Fields(
+                    vec![sql::Field::Single {
+                        expr: SqlValue::Idiom(Idiom::from("id")),
+                        alias: None,
+                    }],
+                    // this means the `value` keyword
+                    true,
+                ),
+                cond: Some(
+                    SqlValue::from(Expression::Binary {
+                        l: SqlValue::Idiom(Idiom::from(match dir {
+                            sql::Dir::In => "out",
+                            _ => "in",
+                        })),
+                        o: sql::Operator::Equal,
+                        r: SqlValue::Thing(rid.clone()),
+                    })
+                    .intox(),
+                ),
+                ..Default::default()
+            };
+
+            let mut page = paginate(gtx.clone(), select, args).await?;
+            let mut nodes = Vec::with_capacity(page.records.len());
+            for edge in page.records.iter() {
+                nodes.push(relation_target(gtx, edge, &dir).await?);
+            }
+            gtx.get_records(nodes.clone()).await?;
+            page.nodes = nodes;
+            page.union_nodes = union_nodes.then_some(naming);
+            Ok(Some(FieldValue::owned_any(page)))
+        })
+    }
+}
+
+/// Resolves the `node` field of a relation edge to the record at the other end of the relation.
+fn make_relation_node_resolver(
+    dir: sql::Dir,
+    union_node: bool,
+    naming: GqlNaming,
+) -> impl for<'a> Fn(ResolverContext<'a>) -> FieldFuture<'a> + Send + Sync + 'static {
+    move |ctx: ResolverContext| {
+        let dir = dir.clone();
+        FieldFuture::new(async move {
+            let (ref gtx, ref rid) = ctx
+                .parent_value
+                .downcast_ref::<ErasedRecord>()
+                .ok_or_else(|| internal_error("failed to downcast"))?;
+
+            let target = relation_target(gtx, rid, &dir).await?;
+            let ty = naming.type_name(&target.tb);
+            let node = field_val_erase_owned((gtx.clone(), target));
+            Ok(Some(if union_node { node.with_type(ty) } else { node }))
+        })
+    }
+}
+
+/// Returns the record at the other end of a relation record, the `out` record when following the
+/// relation outwards and the `in` record when following it inwards.
+async fn relation_target(gtx: &GQLTx, rid: &Thing, dir: &sql::Dir) -> Result<Thing, GqlError> {
+    let field = match dir {
+        sql::Dir::In => "in",
+        _ => "out",
+    };
+    match gtx.get_record_field(rid.clone(), &[Part::from(field)]).await? {
+        SqlValue::Thing(t) => Ok(t),
+        v => Err(internal_error(format!("expected relation target, found: {v:?}"))),
+    }
+}
+
+/// Placeholder resolver for connections which can not be resolved yet.
+fn make_unsupported_connection_resolver(
+    what: &'static str,
 ) -> impl for<'a> Fn(ResolverContext<'a>) -> FieldFuture<'a> + Send + Sync + 'static {
     move |_ctx: ResolverContext| {
         FieldFuture::new(async move {
-            Ok(Some(FieldValue::value("".to_string()))) // Return `None` as a placeholder
+            Err::<Option<FieldValue>, _>(
+                resolver_error(format!("Connections for {what} are not yet supported")).into(),
+            )
+        })
+    }
+}
+
+fn make_connection_resolver(
+    field: &'static str,
+) -> impl for<'a> Fn(ResolverContext<'a>) -> FieldFuture<'a> + Send + Sync + 'static {
+    move |ctx: ResolverContext| {
+        FieldFuture::new(async move {
+            let page = ctx
+                .parent_value
+                .downcast_ref::<ConnectionPage>()
+                .ok_or_else(|| internal_error("failed to downcast"))?;
+
+            let erased = |t: &Thing| field_val_erase_owned((page.gtx.clone(), t.clone()));
+            match field {
+                // Edges have their record as their parent value
+                "edges" => Ok(Some(FieldValue::list(page.records.iter().map(erased)))),
+                "nodes" => match page.union_nodes {
+                    Some(naming) => Ok(Some(FieldValue::list(
+                        page.nodes.iter().map(|t| erased(t).with_type(naming.type_name(&t.tb))),
+                    ))),
+                    None => Ok(Some(FieldValue::list(page.nodes.iter().map(erased)))),
+                },
+                "pageInfo" => Ok(Some(FieldValue::owned_any(page.clone()))),
+                "totalCount" => Ok(Some(FieldValue::value(count_records(&page.gtx, &page.select).await?))),
+                _ => Err(internal_error(format!("unknown connection field: {field}")).into()),
+            }
+        })
+    }
+}
+
+/// Counts the records matching the condition of a select statement, regardless of its window.
+async fn count_records(gtx: &GQLTx, select: &SelectStatement) -> Result<i64, GqlError> {
+    // SELECT count() FROM ... WHERE ... GROUP ALL
+    let count = SelectStatement {
+        what: select.what.clone(),
+        expr: Fields(
+            vec![sql::Field::Single {
+                expr: SqlValue::Function(Box::new(sql::Function::Normal("count".to_string(), vec![]))),
+                alias: None,
+            }],
+            false,
+        ),
+        cond: select.cond.clone(),
+        group: Some(Groups(vec![])),
+        ..Default::default()
+    };
+    let res = gtx.process_stmt(Statement::Select(count)).await?;
+    // Grouping yields no rows at all if no records match
+    match res.first().pick(&[Part::from("count")]) {
+        SqlValue::Number(n) => Ok(n.as_int()),
+        SqlValue::None => Ok(0),
+        v => Err(internal_error(format!("expected number, found: {v:?}"))),
+    }
+}
+
+/// The type wrapping a list of the records of the table with the given type, along with their
+/// total count and window.
+fn list_result_type(name: &str, node_ty_name: &str) -> Object {
+    Object::new(name)
+        .field(
+            Field::new("items", TypeRef::named_nn_list_nn(node_ty_name), make_list_result_resolver("items"))
+                .description("The records in the window."),
+        )
+        .field(
+            Field::new("total", TypeRef::named_nn(TypeRef::INT), make_list_result_resolver("total"))
+                .description("The total count of records matching the filter, regardless of the window."),
+        )
+        .field(
+            Field::new("start", TypeRef::named_nn(TypeRef::INT), make_list_result_resolver("start"))
+                .description("The number of records skipped before the window."),
+        )
+        .field(
+            Field::new("limit", TypeRef::named(TypeRef::INT), make_list_result_resolver("limit"))
+                .description("The maximum number of records in the window, if limited."),
+        )
+        .description(format!("A list of {node_ty_name} records with its total count and window."))
+}
+
+fn make_list_result_resolver(
+    field: &'static str,
+) -> impl for<'a> Fn(ResolverContext<'a>) -> FieldFuture<'a> + Send + Sync + 'static {
+    move |ctx: ResolverContext| {
+        FieldFuture::new(async move {
+            let page = ctx
+                .parent_value
+                .downcast_ref::<ListPage>()
+                .ok_or_else(|| internal_error("failed to downcast"))?;
+
+            match field {
+                "items" => Ok(Some(FieldValue::list(
+                    page.records.iter().map(|t| field_val_erase_owned((page.gtx.clone(), t.clone()))),
+                ))),
+                "total" => Ok(Some(FieldValue::value(count_records(&page.gtx, &page.select).await?))),
+                "start" => Ok(Some(FieldValue::value(page.start))),
+                "limit" => Ok(page.limit.map(FieldValue::value)),
+                _ => Err(internal_error(format!("unknown list result field: {field}")).into()),
+            }
+        })
+    }
+}
+
+fn make_edge_resolver(
+    field: &'static str,
+) -> impl for<'a> Fn(ResolverContext<'a>) -> FieldFuture<'a> + Send + Sync + 'static {
+    move |ctx: ResolverContext| {
+        FieldFuture::new(async move {
+            let (ref gtx, ref rid) = ctx
+                .parent_value
+                .downcast_ref::<ErasedRecord>()
+                .ok_or_else(|| internal_error("failed to downcast"))?;
+
+            match field {
+                "cursor" => Ok(Some(FieldValue::value(encode_cursor(rid)))),
+                "node" => Ok(Some(field_val_erase_owned((gtx.clone(), rid.clone())))),
+                _ => Err(internal_error(format!("unknown edge field: {field}")).into()),
+            }
+        })
+    }
+}
+
+fn make_page_info_resolver(
+    field: &'static str,
+) -> impl for<'a> Fn(ResolverContext<'a>) -> FieldFuture<'a> + Send + Sync + 'static {
+    move |ctx: ResolverContext| {
+        FieldFuture::new(async move {
+            let page = ctx
+                .parent_value
+                .downcast_ref::<ConnectionPage>()
+                .ok_or_else(|| internal_error("failed to downcast"))?;
+
+            match field {
+                "hasNextPage" => Ok(Some(FieldValue::value(page.has_next_page))),
+                "hasPreviousPage" => Ok(Some(FieldValue::value(page.has_previous_page))),
+                "startCursor" => Ok(page.records.first().map(|t| FieldValue::value(encode_cursor(t)))),
+                "endCursor" => Ok(page.records.last().map(|t| FieldValue::value(encode_cursor(t)))),
+                _ => Err(internal_error(format!("unknown page info field: {field}")).into()),
+            }
         })
     }
 }
@@ -1247,49 +2100,125 @@ fn filter_id() -> InputObject {
     filter_impl!(filter, ty, "ne");
     filter
 }
+/// Returns the type of the values a field of the given kind can be compared against in a filter,
+/// or `None` if filtering on fields of the kind is not supported.
+fn filter_value_type(kind: &Kind) -> Option<TypeRef> {
+    let name = match kind.non_optional() {
+        Kind::Bool => TypeRef::BOOLEAN,
+        Kind::Datetime => "DateTime",
+        Kind::Decimal => "Decimal",
+        Kind::Duration => "Duration",
+        Kind::Float => TypeRef::FLOAT,
+        Kind::Int => TypeRef::INT,
+        Kind::Number => "Number",
+        Kind::String => TypeRef::STRING,
+        Kind::Uuid => "UUID",
+        Kind::Record(_) => TypeRef::ID,
+        _ => return None,
+    };
+    Some(TypeRef::named(name))
+}
+
+/// Returns the name of the filter input type of fields of the given kind, or `None` if filtering
+/// on fields of the kind is not supported.
+fn filter_type_name(kind: &Kind) -> Option<String> {
+    let name = match kind.non_optional() {
+        // Arrays are filtered by their elements
+        Kind::Array(inner, _) | Kind::Set(inner, _) => {
+            format!("{}Array", filter_value_type(inner)?.type_name())
+        }
+        Kind::Point | Kind::Geometry(_) => "Geometry".to_string(),
+        kind => filter_value_type(kind)?.type_name().to_string(),
+    };
+    // Optional fields can also be filtered by whether they are set
+    match kind {
+        Kind::Option(_) => Some(format!("Optional{name}FilterInput")),
+        _ => Some(format!("{name}FilterInput")),
+    }
+}
+
 fn filter_from_type(
     kind: Kind,
     filter_name: String,
     types: &mut Vec<Type>,
 ) -> Result<InputObject, GqlError> {
+    let optional = matches!(kind, Kind::Option(_));
+    let kind = kind.non_optional().clone();
     let ty = match &kind {
-        Kind::Record(ts) => match ts.len() {
-            1 => TypeRef::named(TypeRef::ID),
-            _ => TypeRef::named(filter_name_from_table(
-                ts.first().expect("ts should have exactly one element").as_str(),
-            )),
-        },
-        //TODO: remove none
-        // k => unwrap_type(kind_to_type(k.clone(), types, None)?),
-        k => TypeRef::named("UNIMPLEMENTED"),
-    };
+        Kind::Array(inner, _) | Kind::Set(inner, _) => filter_value_type(inner),
+        // Geometries can be filtered on, but not ordered by
+        Kind::Point | Kind::Geometry(_) => Some(TypeRef::named("Geometry")),
+        kind => filter_value_type(kind),
+    }
+    .ok_or_else(|| schema_error(format!("Filtering on fields of kind {kind} is not supported")))?;
 
     let mut filter = InputObject::new(filter_name);
-    filter_impl!(filter, ty, "eq");
-    filter_impl!(filter, ty, "ne");
+    if !matches!(kind, Kind::Array(_, _) | Kind::Set(_, _)) {
+        filter_impl!(filter, ty, "eq");
+        filter_impl!(filter, ty, "ne");
+    }
+    if optional {
+        filter = filter.field(
+            InputValue::new("isNull", TypeRef::named(TypeRef::BOOLEAN))
+                .description("Matches records where the field is not set if `true`, or set if `false`."),
+        );
+    }
 
     match kind {
         Kind::Any => {}
         Kind::Null => {}
         Kind::Bool => {}
         Kind::Bytes => {}
-        Kind::Datetime => {}
-        Kind::Decimal => {}
-        Kind::Duration => {}
-        Kind::Float => {}
-        Kind::Int => {}
-        Kind::Number => {}
+        // Ordered kinds can also be compared
+        Kind::Datetime
+        | Kind::Decimal
+        | Kind::Duration
+        | Kind::Float
+        | Kind::Int
+        | Kind::Number => {
+            filter_impl!(filter, ty, "gt");
+            filter_impl!(filter, ty, "gte");
+            filter_impl!(filter, ty, "lt");
+            filter_impl!(filter, ty, "lte");
+        }
         Kind::Object => {}
-        Kind::Point => {}
-        Kind::String => {}
+        // Geometries can also be filtered spatially
+        Kind::Point | Kind::Geometry(_) => {
+            filter_impl!(filter, ty, "within");
+            filter_impl!(filter, ty, "intersects");
+            let near = InputObject::new("GeometryNearInput")
+                .field(
+                    InputValue::new("point", TypeRef::named_nn("Geometry"))
+                        .description("The GeoJSON point to measure the distance from."),
+                )
+                .field(
+                    InputValue::new("distance", TypeRef::named_nn(TypeRef::FLOAT))
+                        .description("The maximum distance from the point, in metres."),
+                )
+                .description("Matches points within a distance of another point.");
+            types.push(Type::InputObject(near));
+            let near = TypeRef::named("GeometryNearInput");
+            filter_impl!(filter, near, "near");
+        }
+        // Strings can also be matched against substrings and patterns
+        Kind::String => {
+            filter_impl!(filter, ty, "contains");
+            filter_impl!(filter, ty, "startsWith");
+            filter_impl!(filter, ty, "endsWith");
+            filter_impl!(filter, ty, "matches");
+        }
         Kind::Uuid => {}
         Kind::Regex => {}
         Kind::Record(_) => {}
-        Kind::Geometry(_) => {}
         Kind::Option(_) => {}
         Kind::Either(_) => {}
-        Kind::Set(_, _) => {}
-        Kind::Array(_, _) => {}
+        // Arrays can be filtered by the elements they contain
+        Kind::Set(_, _) | Kind::Array(_, _) => {
+            filter_impl!(filter, ty, "contains");
+            let list = TypeRef::List(Box::new(TypeRef::NonNull(Box::new(ty))));
+            filter_impl!(filter, list, "containsAny");
+            filter_impl!(filter, list, "containsAll");
+        }
         Kind::Function(_, _) => {}
         Kind::Range => {}
         Kind::Literal(_) => {}
@@ -1299,174 +2228,267 @@ fn filter_from_type(
     Ok(filter)
 }
 
-// fn cond_from_filter(
-//     filter: &IndexMap<Name, GqlValue>,
-//     fds: &[DefineFieldStatement],
-// ) -> Result<Cond, GqlError> {
-//     // val_from_filter(filter, fds).map(IntoExt::intox)
-//     // Start recursion with an empty path prefix
-//     val_from_filter(filter, fds, &[]).map(IntoExt::intox)
-// }
+fn cond_from_filter(
+    filter: &IndexMap<Name, GqlValue>,
+    tb_name: &str,
+    fds: &[DefineFieldStatement],
+    naming: GqlNaming,
+) -> Result<Cond, GqlError> {
+    val_from_filter(filter, tb_name, fds, naming).map(IntoExt::intox)
+}
 
-// fn val_from_filter(
-//     filter: &IndexMap<Name, GqlValue>,
-//     fds: &[DefineFieldStatement],
-//     current_path: &[String],
-// ) -> Result<SqlValue, GqlError> {
-//     if filter.len() != 1 {
-//         let path_str = current_path.join(".");
-//         return Err(resolver_error(format!("Filter object at path '{}' must have exactly one key (field, and, or, not)", path_str)));
-//     }
-//
-//     let (k, v) = filter.iter().next().unwrap();
-//     let key_str = k.as_str();
-//
-//     let cond = match key_str.to_lowercase().as_str() { // Keep matching lowercase for operators
-//         "or" => aggregate(v, AggregateOp::Or, fds, current_path), // Pass path down
-//         "and" => aggregate(v, AggregateOp::And, fds, current_path), // Pass path down
-//         "not" => negate(v, fds, current_path), // Pass path down
-//         _ => { // Assume it's a field name (camelCase from schema)
-//             // Construct the new path segment
-//             let mut next_path = current_path.to_vec();
-//             next_path.push(key_str.to_string()); // Add the camelCase field name
-//
-//             // Find the DB field definition matching the potential full path
-//             // This might require looking up the base field and checking if it's an object,
-//             // then checking the sub-field within the nested structure.
-//             // For simplicity here, we'll assume we can find the field kind based on the path.
-//             let field_kind = find_field_kind_by_path(&next_path, fds)?; // Implement this helper
-//
-//             match field_kind {
-//                 // If the path points to a nested object, recurse
-//                 Kind::Object => {
-//                     let inner_filter = v.as_object().ok_or_else(|| resolver_error(format!("Value for object filter '{}' must be an object", next_path.join("."))))?;
-//                     val_from_filter(inner_filter, fds, &next_path) // Recurse with extended path
-//                 }
-//                 // If it's a scalar/record/enum etc., call binop
-//                 _ => Ok({
-//                     binop(&next_path, v, field_kind)? // Pass full path and kind
-//                 })
-//             }
-//         }
-//     };
-//
-//     cond
-//     // if filter.len() != 1 {
-//     // 	return Err(resolver_error("Table Filter must have one item"));
-//     // }
-//     //
-//     // let (k, v) = filter.iter().next().unwrap();
-//     //
-//     // let cond = match k.as_str().to_lowercase().as_str() {
-//     // 	"or" => aggregate(v, AggregateOp::Or, fds),
-//     // 	"and" => aggregate(v, AggregateOp::And, fds),
-//     // 	"not" => negate(v, fds),
-//     // 	_ => binop(k.as_str(), v, fds),
-//     // };
-//     //
-//     // cond
-// }
+fn val_from_filter(
+    filter: &IndexMap<Name, GqlValue>,
+    tb_name: &str,
+    fds: &[DefineFieldStatement],
+    naming: GqlNaming,
+) -> Result<SqlValue, GqlError> {
+    if filter.len() != 1 {
+        return Err(input_error(
+            "Table filter must have exactly one key, either a field, `and`, `or` or `not`",
+        ));
+    }
+
+    let (k, v) = filter.iter().next().unwrap();
+
+    match k.as_str() {
+        "and" => aggregate(v, AggregateOp::And, tb_name, fds, naming),
+        "or" => aggregate(v, AggregateOp::Or, tb_name, fds, naming),
+        "not" => negate(v, tb_name, fds, naming),
+        _ => {
+            let (field, kind) = find_field_by_name(k.as_str(), tb_name, fds, naming)?;
+            binop(field, v, kind)
+        }
+    }
+}
 
 fn parse_op(name: impl AsRef<str>) -> Result<sql::Operator, GqlError> {
     match name.as_ref() {
         "eq" => Ok(sql::Operator::Equal),
         "ne" => Ok(sql::Operator::NotEqual),
+        "gt" => Ok(sql::Operator::MoreThan),
+        "gte" => Ok(sql::Operator::MoreThanOrEqual),
+        "lt" => Ok(sql::Operator::LessThan),
+        "lte" => Ok(sql::Operator::LessThanOrEqual),
+        "contains" => Ok(sql::Operator::Contain),
+        "containsAny" => Ok(sql::Operator::ContainAny),
+        "containsAll" => Ok(sql::Operator::ContainAll),
+        // Matched with a regex, see `binop`
+        "startsWith" | "endsWith" | "matches" => Ok(sql::Operator::Equal),
+        "within" => Ok(sql::Operator::Inside),
+        "intersects" => Ok(sql::Operator::Intersects),
         op => Err(resolver_error(format!("Unsupported op: {op}"))),
     }
 }
 
-fn find_field_kind_by_path(path: &[String], fds: &Arc<Vec<DefineFieldStatement>>) -> Result<Kind, GqlError> {
-    // Convert GQL camelCase path back to DB snake_case/dot.notation path
-    // This assumes a simple reversible mapping, might need adjustment
-    let db_path_str = path.iter()
-        .map(|p| p.to_snake_case()) // Convert each segment
-        .collect::<Vec<_>>()
-        .join("."); // Join with dots
+/// Finds the top level field with the given GraphQL name, returning its DB idiom and kind.
+fn find_field_by_name(
+    name: &str,
+    tb_name: &str,
+    fds: &[DefineFieldStatement],
+    naming: GqlNaming,
+) -> Result<(Idiom, Kind), GqlError> {
+    if name == "id" {
+        return Ok((Idiom::from("id"), Kind::Record(vec![Table::from(tb_name)])));
+    }
 
     fds.iter()
-        .find(|fd| fd.name.to_string() == db_path_str)
-        .and_then(|fd| fd.kind.clone())
-        .ok_or_else(|| resolver_error(format!("Field definition not found for path '{}' (DB path '{}')", path.join("."), db_path_str)))
+        .find(|fd| match fd.name.0.as_slice() {
+            [Part::Field(_)] => field_gql_name(fd, naming).is_ok_and(|n| n == name),
+            _ => false,
+        })
+        .and_then(|fd| Some((fd.name.clone(), fd.kind.clone()?)))
+        .ok_or_else(|| input_error(format!("Unknown field `{name}` in filter for table `{tb_name}`")))
 }
 
-// fn negate(filter: &GqlValue, fds: &Arc<Vec<DefineFieldStatement>>, current_path: &[String]) -> Result<SqlValue, GqlError> {
-//     let obj = filter.as_object().ok_or(resolver_error("Value of NOT must be object"))?;
-//
-//     let inner_cond = val_from_filter(obj, fds, current_path)?;
-//     Ok(Expression::Unary { o: sql::Operator::Not, v: inner_cond }.into())
-// }
+/// The keys of a table filter which combine other filters instead of filtering on a field.
+const FILTER_COMBINATORS: [&str; 3] = ["and", "or", "not"];
+
+fn negate(
+    filter: &GqlValue,
+    tb_name: &str,
+    fds: &[DefineFieldStatement],
+    naming: GqlNaming,
+) -> Result<SqlValue, GqlError> {
+    let obj = filter.as_object().ok_or_else(|| input_error("Value of `not` must be an object"))?;
+
+    let inner = val_from_filter(obj, tb_name, fds, naming)?;
+    Ok(Expression::Unary { o: sql::Operator::Not, v: inner }.into())
+}
 
 enum AggregateOp {
     And,
     Or,
 }
 
-// fn aggregate(
-//     filter: &GqlValue,
-//     op: AggregateOp,
-//     fds: &Arc<Vec<DefineFieldStatement>>,
-//     current_path: &[String],
-// ) -> Result<SqlValue, GqlError> {
-//     let op_str = match op {
-//         AggregateOp::And => "AND",
-//         AggregateOp::Or => "OR",
-//     };
-//     let op = match op {
-//         AggregateOp::And => sql::Operator::And,
-//         AggregateOp::Or => sql::Operator::Or,
-//     };
-//     let list =
-//         filter.as_list().ok_or(resolver_error(format!("Value of {op_str} should be a list")))?;
-//     let filter_arr = list
-//         .iter()
-//         .map(|v| v.as_object().map(|o| val_from_filter(o, fds, current_path)))
-//         .collect::<Option<Result<Vec<SqlValue>, GqlError>>>()
-//         .ok_or(resolver_error(format!("List of {op_str} should contain objects")))??;
-//
-//     let mut iter = filter_arr.into_iter();
-//
-//     let mut cond = iter
-//         .next()
-//         .ok_or(resolver_error(format!("List of {op_str} should contain at least one object")))?;
-//
-//     for clause in iter {
-//         cond = Expression::Binary {
-//             l: clause,
-//             o: op.clone(),
-//             r: cond,
-//         }
-//             .into();
-//     }
-//
-//     Ok(cond)
-// }
+/// Combines a list of filters into a single condition, joined in order by `AND` or `OR`.
+fn aggregate(
+    filter: &GqlValue,
+    op: AggregateOp,
+    tb_name: &str,
+    fds: &[DefineFieldStatement],
+    naming: GqlNaming,
+) -> Result<SqlValue, GqlError> {
+    let (op_str, op) = match op {
+        AggregateOp::And => ("and", sql::Operator::And),
+        AggregateOp::Or => ("or", sql::Operator::Or),
+    };
+    let list = filter
+        .as_list()
+        .ok_or_else(|| input_error(format!("Value of `{op_str}` must be a list")))?;
+
+    let mut conds = list.iter().map(|v| match v.as_object() {
+        Some(o) => val_from_filter(o, tb_name, fds, naming),
+        None => Err(input_error(format!("List of `{op_str}` must contain objects"))),
+    });
+
+    let mut cond = conds.next().ok_or_else(|| {
+        input_error(format!("List of `{op_str}` must contain at least one filter"))
+    })??;
+
+    for clause in conds {
+        cond = Expression::Binary {
+            l: cond,
+            o: op.clone(),
+            r: clause?,
+        }
+        .into();
+    }
+
+    Ok(cond)
+}
 
 fn binop(
-    gql_path: &[String], // e.g., ["size", "width"]
-    val: &GqlValue,     // e.g., { eq: 100 }
-    field_kind: Kind, // The Kind of the specific field at the end of the path
+    field: Idiom,     // e.g., size.width
+    val: &GqlValue,   // e.g., { eq: 100 }
+    field_kind: Kind, // The Kind of the field the idiom points to
 ) -> Result<SqlValue, GqlError> {
-    let obj = val.as_object().ok_or_else(|| resolver_error(format!("Filter value for '{}' must be an object", gql_path.join("."))))?;
+    let obj = val.as_object().ok_or_else(|| resolver_error(format!("Filter value for '{field}' must be an object")))?;
 
     if obj.len() != 1 {
-        return Err(resolver_error(format!("Filter operation object for '{}' must have exactly one key (e.g., eq, gt)", gql_path.join("."))));
+        return Err(resolver_error(format!("Filter operation object for '{field}' must have exactly one key (e.g., eq, gt)")));
     }
 
-    // Convert GQL path (camelCase) back to DB path (snake_case.dot) for SQL Idiom
-    // ASSUMPTION: Simple reversible mapping. May need adjustment.
-    let db_path_str = gql_path.iter().map(|p| p.to_snake_case()).collect::<Vec<_>>().join(".");
-    let lhs = sql::Value::Idiom(db_path_str.intox()); // Use the full DB path
+    let lhs = sql::Value::Idiom(field);
 
     let (k, v) = obj.iter().next().unwrap(); // k is the operator name (e.g., "eq")
+
+    // Optional fields are not set when they are NONE
+    if k == "isNull" {
+        let GqlValue::Boolean(is_null) = v else {
+            return Err(resolver_error(format!("Filter value of `isNull` for '{lhs}' must be a boolean")));
+        };
+        let op = if *is_null { sql::Operator::Equal } else { sql::Operator::NotEqual };
+        return Ok(sql::Expression::Binary { l: lhs, o: op, r: SqlValue::None }.into());
+    }
+
+    // Only points have a distance to another point, other values are never near
+    if k == "near" {
+        let near = v.as_object();
+        let (Some(point), Some(distance)) =
+            (near.and_then(|o| o.get("point")), near.and_then(|o| o.get("distance")))
+        else {
+            return Err(resolver_error(format!("Filter value of `near` for '{lhs}' must have a point and a distance")));
+        };
+        let point = gql_to_sql_kind(point, Kind::Point)
+            .map_err(|e| e.in_field("point").in_field(k).in_field(&lhs))?;
+        let distance = gql_to_sql_kind(distance, Kind::Float)
+            .map_err(|e| e.in_field("distance").in_field(k).in_field(&lhs))?;
+        let is_point = sql::Function::Normal("type::is::point".to_string(), vec![lhs.clone()]);
+        let dist = sql::Function::Normal("geo::distance".to_string(), vec![lhs, point]);
+        let within = sql::Expression::Binary {
+            l: SqlValue::Function(Box::new(dist)),
+            o: sql::Operator::LessThanOrEqual,
+            r: distance,
+        };
+        return Ok(sql::Expression::Binary {
+            l: SqlValue::Function(Box::new(is_point)),
+            o: sql::Operator::And,
+            r: within.into(),
+        }
+        .into());
+    }
+
     let op = parse_op(k)?; // Parse "eq", "ne", etc. (Needs expansion)
 
-    // Convert the GQL value 'v' (e.g., Number(100)) to SQL using the specific field's Kind
-    let rhs = gql_to_sql_kind(v, field_kind)?;
+    // Convert the GQL value 'v' (e.g., Number(100)) to SQL using the specific field's Kind, or
+    // the kind of its elements when filtering an array by the elements it contains
+    let rhs_kind = match (&op, field_kind.non_optional()) {
+        // Geometries can be compared spatially with any kind of geometry
+        (sql::Operator::Inside | sql::Operator::Intersects, Kind::Point | Kind::Geometry(_)) => {
+            Kind::Geometry(vec![])
+        }
+        (sql::Operator::Contain, Kind::Array(inner, _) | Kind::Set(inner, _)) => *inner.clone(),
+        (
+            sql::Operator::ContainAny | sql::Operator::ContainAll,
+            Kind::Array(inner, _) | Kind::Set(inner, _),
+        ) => Kind::Array(inner.clone(), None),
+        _ => field_kind,
+    };
+    let rhs = match k.as_str() {
+        // Strings compare equal to the regexes which match them
+        "startsWith" | "endsWith" | "matches" => {
+            let GqlValue::String(s) = v else {
+                return Err(resolver_error(format!("Filter value of `{k}` for '{lhs}' must be a string")));
+            };
+            let pattern = match k.as_str() {
+                "startsWith" => format!("^{}", regex::escape(s)),
+                "endsWith" => format!("{}$", regex::escape(s)),
+                _ => s.clone(),
+            };
+            let regex = pattern
+                .parse::<sql::Regex>()
+                .map_err(|e| resolver_error(format!("Invalid regex `{pattern}` for '{lhs}': {e}")))?;
+            SqlValue::Regex(regex)
+        }
+        _ => gql_to_sql_kind(v, rhs_kind).map_err(|e| e.in_field(k).in_field(&lhs))?,
+    };
 
     Ok(sql::Expression::Binary { l: lhs, o: op, r: rhs }.into())
 }
 
-fn parse_order_input(order: Option<&GqlValue>) -> Result<Option<Vec<sql::Order>>, GqlError> {
+/// The name of the `OrderField` enum item for the field with the given name.
+fn order_field_name(field_name: &str) -> String {
+    field_name.to_screaming_snake_case()
+}
+
+/// Returns the names and descriptions of the top level fields of scalar kinds, which records can be
+/// ordered by.
+///
+/// Fails if two of the fields, or a field and `id`, have the same name in the order field enum, as
+/// distinct field names like `fooBar` and `foo_bar` share their screaming snake case.
+fn orderable_fields(
+    tb_name: &str,
+    fds: &[DefineFieldStatement],
+    naming: GqlNaming,
+) -> Result<Vec<(String, Option<String>)>, GqlError> {
+    let fields: Vec<_> = fds.iter()
+        .filter(|fd| !fd.name.is_id())
+        .filter(|fd| fd.kind.as_ref().and_then(filter_value_type).is_some())
+        .filter_map(|fd| match fd.name.0.as_slice() {
+            [Part::Field(_)] => Some((field_gql_name(fd, naming).ok()?, Some(field_gql_description(fd)))),
+            _ => None,
+        })
+        .collect();
+
+    let mut items: HashMap<String, &str> = HashMap::new();
+    items.insert(order_field_name("id"), "id");
+    for (name, _) in fields.iter() {
+        if let Some(other) = items.insert(order_field_name(name), name) {
+            return Err(schema_error(format!(
+                "The fields `{other}` and `{name}` of the table `{tb_name}` both have the order field `{}`",
+                order_field_name(name)
+            )));
+        }
+    }
+    Ok(fields)
+}
+
+fn parse_order_input(
+    order: Option<&GqlValue>,
+    fds: &[DefineFieldStatement],
+    naming: GqlNaming,
+) -> Result<Option<Vec<sql::Order>>, GqlError> {
     let Some(GqlValue::Object(o)) = order else { return Ok(None) };
 
     let mut orders = vec![];
@@ -1480,16 +2502,28 @@ fn parse_order_input(order: Option<&GqlValue>) -> Result<Option<Vec<sql::Order>>
             return Err(resolver_error("Order input must contain 'direction' enum (ASC/DESC)"));
         };
 
-        let field_name_screaming = field_name_enum.as_str(); // e.g., "CREATED_AT", "SIZE_WIDTH"
-        // Convert SCREAMING_SNAKE_CASE back to DB snake_case.dot notation
-        let db_field_name = field_name_screaming.to_lowercase(); // Simple conversion, might need underscores replaced with dots
+        let field_name_screaming = field_name_enum.as_str(); // e.g., "CREATED_AT"
+        // Find the DB field the SCREAMING_SNAKE_CASE enum item was generated from
+        let field = if field_name_screaming == "ID" {
+            Idiom::from("id")
+        } else {
+            fds.iter()
+                .find(|fd| match fd.name.0.as_slice() {
+                    [Part::Field(_)] => field_gql_name(fd, naming)
+                        .is_ok_and(|n| order_field_name(&n) == field_name_screaming),
+                    _ => false,
+                })
+                .map(|fd| fd.name.clone())
+                .ok_or_else(|| input_error(format!("Unknown order field: {field_name_screaming}")))?
+        };
 
         let direction_is_asc = direction_enum.as_str() == "ASC";
 
-        let mut order_clause = sql::Order::default();
-        order_clause.value = db_field_name.into(); // Use DB name/path
-        order_clause.direction = direction_is_asc;
-        orders.push(order_clause);
+        orders.push(sql::Order {
+            value: field,
+            direction: direction_is_asc,
+            ..Default::default()
+        });
 
         // Check for chained 'then'
         if let Some(GqlValue::Object(next)) = current.get("then") {
@@ -1500,19 +2534,3 @@ fn parse_order_input(order: Option<&GqlValue>) -> Result<Option<Vec<sql::Order>>
     }
     Ok(Some(orders))
 }
-
-
-//TODO: resolve with get_record_field funktioniert fuer ein level nested.
-// hier bei 'size.location.`info`' findet er obvious nicht: None -> Error
-
-
-// TODO: auch bei .url oder users.0.image.size.height
-// weil hier path: size.height und der nicht gefunden wird fuer query
-// SELECT * FROM user:id
-
-
-// 2025-04-22T19:32:25.885157Z TRACE request: surrealdb_core::gql::tables: /Volumes/Development/Dev/RustroverProjects/surrealdb/crates/core/src/gql/tables.rs:659: Creating/Running resolver for DB path 'size.location.`info`' (Kind: Some(Float)) with parent: (surrealdb_core::gql::utils::GQLTx, surrealdb_core::sql::thing::Thing)     otel.kind="server" http.request.method="POST" url.path="/graphql" network.protocol.name="http" network.protocol.version="1.1" http.request.body.size="244" user_agent.original="PostmanClient/11.36.1 (AppId=90094569-b9aa-467a-902b-a9c21e5e66af)" otel.name="POST /graphql" http.route="/graphql" http.request.id="ff04a2ef-5af1-48dd-a50d-4d1ad73bd444" client.address="127.0.0.1"
-// 2025-04-22T19:32:25.885164Z TRACE request: surrealdb_core::gql::tables: /Volumes/Development/Dev/RustroverProjects/surrealdb/crates/core/src/gql/tables.rs:672: Parent is ErasedRecord for path 'size.location.`info`', RID: image:ppli74w1kj8biujquu43     otel.kind="server" http.request.method="POST" url.path="/graphql" network.protocol.name="http" network.protocol.version="1.1" http.request.body.size="244" user_agent.original="PostmanClient/11.36.1 (AppId=90094569-b9aa-467a-902b-a9c21e5e66af)" otel.name="POST /graphql" http.route="/graphql" http.request.id="ff04a2ef-5af1-48dd-a50d-4d1ad73bd444" client.address="127.0.0.1"
-// 2025-04-22T19:32:25.885172Z TRACE request: surrealdb_core::gql::tables: /Volumes/Development/Dev/RustroverProjects/surrealdb/crates/core/src/gql/tables.rs:688: Field at path 'size.location.`info`' is scalar/id/terminal, fetching value via get_record_field     otel.kind="server" http.request.method="POST" url.path="/graphql" network.protocol.name="http" network.protocol.version="1.1" http.request.body.size="244" user_agent.original="PostmanClient/11.36.1 (AppId=90094569-b9aa-467a-902b-a9c21e5e66af)" otel.name="POST /graphql" http.route="/graphql" http.request.id="ff04a2ef-5af1-48dd-a50d-4d1ad73bd444" client.address="127.0.0.1"
-// 2025-04-22T19:32:25.885188Z TRACE request: surrealdb::core::dbs: crates/core/src/dbs/iterator.rs:354: Iterating statement statement=SELECT * FROM image:ppli74w1kj8biujquu43 otel.kind="server" http.request.method="POST" url.path="/graphql" network.protocol.name="http" network.protocol.version="1.1" http.request.body.size="244" user_agent.original="PostmanClient/11.36.1 (AppId=90094569-b9aa-467a-902b-a9c21e5e66af)" otel.name="POST /graphql" http.route="/graphql" http.request.id="ff04a2ef-5af1-48dd-a50d-4d1ad73bd444" client.address="127.0.0.1"
-// 2025-04-22T19:32:25.885258Z TRACE request: surrealdb_core::gql::tables: /Volumes/Development/Dev/RustroverProjects/surrealdb/crates/core/src/gql/tables.rs:695: Fetched value for path 'size.location.`info`': None     otel.kind="server" http.request.method="POST" url.path="/graphql" network.protocol.name="http" network.protocol.version="1.1" http.request.body.size="244" user_agent.original="PostmanClient/11.36.1 (AppId=90094569-b9aa-467a-902b-a9c21e5e66af)" otel.name="POST /graphql" http.route="/graphql" http.request.id="ff04a2ef-5af1-48dd-a50d-4d1ad73bd444" client.address="127.0.0.1"
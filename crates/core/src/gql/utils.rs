@@ -1,6 +1,10 @@
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
-use crate::ctx::Context;
+use crate::ctx::reason::Reason;
+use crate::ctx::{Context, MutableContext};
+use crate::dbs::Notification;
 use crate::dbs::Options;
 use crate::dbs::Session;
 use crate::err::Error;
@@ -10,15 +14,25 @@ use crate::kvs::LockType;
 use crate::kvs::TransactionType;
 use crate::sql;
 use crate::sql::Function;
+use crate::sql::statements::define::config::graphql::GqlNaming;
+use crate::sql::statements::{DefineFieldStatement, SelectStatement};
+use crate::sql::{Idiom, Part};
 use crate::sql::Statement;
-use crate::sql::{FlowResultExt, Ident};
+use crate::sql::{Fields, Values};
+use crate::sql::FlowResultExt;
+use crate::sql::escape::EscapeRid;
+use crate::sql::paths::ID;
 use crate::sql::{Thing, Value as SqlValue};
+use crate::syn;
 
+use async_channel::Receiver;
 use async_graphql::dynamic::FieldValue;
 use async_graphql::{dynamic::indexmap::IndexMap, Name, Value as GqlValue};
+use dashmap::DashMap;
 use reblessive::TreeStack;
+use tokio::sync::OnceCell;
 
-use super::error::GqlError;
+use super::error::{input_error, resolver_error, schema_error, GqlError};
 
 pub(crate) trait GqlValueUtils {
     fn as_i64(&self) -> Option<i64>;
@@ -63,10 +77,36 @@ impl GqlValueUtils for GqlValue {
 pub struct GQLTx {
     opt: Options,
     ctx: Context,
+    /// The query timeout of the datastore, applied to every statement processed.
+    timeout: Option<Duration>,
+    /// The records fetched within this transaction, shared by all clones so that every resolver
+    /// reading from the same record only causes a single fetch. Writable transactions clear
+    /// them after every processed statement, as the statement may have changed them.
+    records: Arc<DashMap<Thing, Arc<OnceCell<SqlValue>>>>,
+    /// Whether the transaction was opened with [`GQLTx::new_write`].
+    writable: bool,
+    /// Receives the live query notifications of a writable transaction, which are only sent
+    /// once it is committed.
+    notifications: Option<Receiver<Notification>>,
 }
 
 impl GQLTx {
+    /// Opens a read-only transaction for resolving queries.
     pub async fn new(kvs: &Arc<Datastore>, sess: &Session) -> Result<Self, GqlError> {
+        Self::begin(kvs, sess, TransactionType::Read).await
+    }
+
+    /// Opens a writable transaction for resolving mutations. The transaction must be completed
+    /// with [`GQLTx::finish`] for any changes to be committed.
+    pub async fn new_write(kvs: &Arc<Datastore>, sess: &Session) -> Result<Self, GqlError> {
+        Self::begin(kvs, sess, TransactionType::Write).await
+    }
+
+    async fn begin(
+        kvs: &Arc<Datastore>,
+        sess: &Session,
+        tt: TransactionType,
+    ) -> Result<Self, GqlError> {
         kvs.check_anon(sess).map_err(|_| {
             Error::IamError(IamError::NotAllowed {
                 actor: "anonymous".to_string(),
@@ -75,57 +115,197 @@ impl GQLTx {
             })
         })?;
 
-        let tx = kvs.transaction(TransactionType::Read, LockType::Optimistic).await?;
+        let tx = kvs.transaction(tt, LockType::Optimistic).await?;
         let tx = Arc::new(tx);
         let mut ctx = kvs.setup_ctx()?;
         ctx.set_transaction(tx);
 
         sess.context(&mut ctx);
 
+        let mut opt = kvs.setup_options(sess);
+        let notifications = (matches!(tt, TransactionType::Write) && ctx.has_notifications()).then(|| {
+            let (send, recv) = async_channel::unbounded();
+            opt.sender = Some(send);
+            recv
+        });
+
         Ok(GQLTx {
             ctx: ctx.freeze(),
-            opt: kvs.setup_options(sess),
+            opt,
+            timeout: kvs.query_timeout(),
+            records: Default::default(),
+            writable: matches!(tt, TransactionType::Write),
+            notifications,
         })
     }
 
+    /// Returns the record with the given id, or `NONE` if it does not exist.
+    ///
+    /// The record is fetched once and cached for the lifetime of the transaction. Concurrent
+    /// calls for the same record wait for the first fetch instead of issuing their own.
+    pub async fn get_record(&self, rid: Thing) -> Result<SqlValue, GqlError> {
+        let cell = self.records.entry(rid.clone()).or_default().clone();
+        let record = cell
+            .get_or_try_init(|| async {
+                trace!("fetching record: {rid}");
+                let stmt = SelectStatement {
+                    expr: Fields::all(),
+                    what: Values(vec![SqlValue::Thing(rid)]),
+                    ..Default::default()
+                };
+                let mut stack = TreeStack::new();
+                stack
+                    .enter(|stk| stmt.compute(stk, &self.ctx, &self.opt, None))
+                    .finish()
+                    .await
+                    .map(|v| v.first())
+            })
+            .await?;
+        Ok(record.clone())
+    }
+
+    /// Returns the records with the given ids in the same order, with `NONE` for those which do
+    /// not exist.
+    ///
+    /// The records which are not cached yet are fetched with a single statement and cached like
+    /// those fetched by [`GQLTx::get_record`], so resolving many records costs one round trip.
+    pub async fn get_records(&self, rids: Vec<Thing>) -> Result<Vec<SqlValue>, GqlError> {
+        let cells: Vec<_> =
+            rids.iter().map(|rid| self.records.entry(rid.clone()).or_default().clone()).collect();
+
+        let mut missing: Vec<Thing> = rids
+            .iter()
+            .zip(&cells)
+            .filter(|(_, cell)| !cell.initialized())
+            .map(|(rid, _)| rid.clone())
+            .collect();
+        missing.sort();
+        missing.dedup();
+
+        if !missing.is_empty() {
+            trace!("fetching {} records", missing.len());
+            // SELECT * FROM $ids
+            let stmt = SelectStatement {
+                expr: Fields::all(),
+                what: Values(missing.iter().cloned().map(SqlValue::Thing).collect()),
+                ..Default::default()
+            };
+            let mut stack = TreeStack::new();
+            let res = stack
+                .enter(|stk| stmt.compute(stk, &self.ctx, &self.opt, None))
+                .finish()
+                .await?;
+
+            // Records which do not exist are left out of the result, so they are matched by id
+            let mut fetched: HashMap<Thing, SqlValue> = match res {
+                SqlValue::Array(a) => a
+                    .0
+                    .into_iter()
+                    .filter_map(|v| match v.pick(&*ID) {
+                        SqlValue::Thing(rid) => Some((rid, v)),
+                        _ => None,
+                    })
+                    .collect(),
+                v => return Err(resolver_error(format!("expected array of records, found: {v}"))),
+            };
+            for rid in missing {
+                let record = fetched.remove(&rid).unwrap_or_default();
+                // A concurrent fetch of the same record may have completed in the meantime
+                let _ = self.records.entry(rid).or_default().set(record);
+            }
+        }
+
+        let mut records = Vec::with_capacity(rids.len());
+        for (rid, cell) in rids.into_iter().zip(cells) {
+            match cell.get() {
+                Some(record) => records.push(record.clone()),
+                // The record is still being fetched by a concurrent call to `get_record`
+                None => records.push(self.get_record(rid).await?),
+            }
+        }
+        Ok(records)
+    }
+
+    /// Returns the value at the given path of a record. Each part of the path is resolved against
+    /// the value of the previous one, so fields can be nested arbitrarily deep.
     pub async fn get_record_field(
         &self,
         rid: Thing,
-        // field: impl Into<Part>,
-        // part: &[Part],
-        // path: &[&Ident]
-        field_path: &str,
+        path: &[sql::Part],
     ) -> Result<SqlValue, GqlError> {
-        let parts: Vec<sql::Part> = field_path.split('.')
-            .filter(|s| !s.is_empty())
-            .map(|s| sql::Part::Field(Ident::from(s.to_string())))
-            .collect();
-
-        if parts.is_empty() {
-            // Or return a more specific error if an empty path is invalid
+        if path.is_empty() {
             return Ok(SqlValue::Null);
         }
+        let record = self.get_record(rid).await?;
         let mut stack = TreeStack::new();
-        // let part = [field.into()];
-        let value = SqlValue::Thing(rid);
         stack
-            .enter(|stk| value.get(stk, &self.ctx, &self.opt, None, &*parts))
+            .enter(|stk| record.get(stk, &self.ctx, &self.opt, None, path))
             .finish()
             .await
             .catch_return()
             .map_err(Into::into)
     }
 
+    /// Processes a statement within this transaction, failing if it runs past the query timeout.
     pub async fn process_stmt(&self, stmt: Statement) -> Result<SqlValue, GqlError> {
+        let mut ctx = MutableContext::new(&self.ctx);
+        if let Some(timeout) = self.timeout {
+            ctx.add_timeout(timeout)?;
+        }
+        let ctx = ctx.freeze();
+
         let mut stack = TreeStack::new();
 
         let res = stack
-            .enter(|stk| stmt.compute(stk, &self.ctx, &self.opt, None))
+            .enter(|stk| stmt.compute(stk, &ctx, &self.opt, None))
             .finish()
             .await
-            .catch_return()?;
+            .catch_return();
 
-        Ok(res)
+        if self.writable {
+            self.records.clear();
+        }
+
+        match (ctx.done(true)?, res) {
+            (Some(Reason::Timedout), _) | (_, Err(Error::QueryTimedout)) => {
+                Err(resolver_error(Error::QueryTimedout.to_string()))
+            }
+            (_, res) => Ok(res?),
+        }
+    }
+
+    /// Completes the transaction with the result of the work done within it, committing the
+    /// transaction if the result is successful and cancelling it otherwise.
+    pub async fn finish<T>(&self, res: Result<T, GqlError>) -> Result<T, GqlError> {
+        let tx = self.ctx.tx();
+        match res {
+            Ok(v) => {
+                let mut lock = tx.lock().await;
+                // Write the change feeds of the transaction before committing it
+                let res = match lock.complete_changes(false).await {
+                    Ok(()) => lock.commit().await,
+                    Err(e) => Err(e),
+                };
+                if let Err(e) = res {
+                    let _ = lock.cancel().await;
+                    return Err(e.into());
+                }
+                drop(lock);
+                // Send the notifications of the committed changes
+                if let (Some(recv), Some(sink)) = (&self.notifications, self.ctx.notifications()) {
+                    while let Ok(x) = recv.try_recv() {
+                        if sink.send(x).await.is_err() {
+                            break;
+                        }
+                    }
+                }
+                Ok(v)
+            }
+            Err(e) => {
+                let _ = tx.cancel().await;
+                Err(e)
+            }
+        }
     }
 
     pub async fn run_fn(&self, name: &str, args: Vec<SqlValue>) -> Result<SqlValue, GqlError> {
@@ -148,3 +328,153 @@ pub type ErasedRecord = (GQLTx, Thing);
 pub fn field_val_erase_owned(val: ErasedRecord) -> FieldValue<'static> {
     FieldValue::owned_any(val)
 }
+
+/// Parses a record id argument of a query on the table `tb`.
+///
+/// The id can either be a full record id like `user:123` which must belong to `tb`, or a bare id
+/// like `123` which is scoped to `tb`. Both forms are parsed as SurrealQL, so ids which are not
+/// valid record ids result in an input error instead of being coerced into a string id.
+pub fn parse_record_id(tb: &str, id: &str) -> Result<Thing, GqlError> {
+    let thing = syn::thing(id)
+        .or_else(|_| syn::thing(&format!("{}:{id}", EscapeRid(tb))))
+        .map_err(|_| input_error(format!("Invalid record id for the table `{tb}`: {id}")))?;
+    match thing.tb == tb {
+        true => Ok(thing),
+        false => Err(input_error(format!("Expected a record of the table `{tb}`, found: {thing}"))),
+    }
+}
+
+/// Returns a generated description with the comment of the definition it was generated from
+/// appended, if any.
+pub fn with_comment(description: impl Into<String>, comment: Option<impl AsRef<str>>) -> String {
+    let description = description.into();
+    match comment.as_ref().map(AsRef::as_ref) {
+        Some(c) if !c.is_empty() => format!("{description}\n\n{c}"),
+        _ => description,
+    }
+}
+
+/// The annotation in the comment of a field which sets the GraphQL name of the field, e.g.
+/// `DEFINE FIELD created_at ON user COMMENT "@graphql(name: creation) When the user signed up"`.
+const NAME_ANNOTATION: &str = "@graphql(name:";
+
+/// Splits the comment of a field into the GraphQL name set by its annotation, if any, and the
+/// remaining description.
+fn split_name_annotation(comment: &str) -> (Option<&str>, String) {
+    let Some(start) = comment.find(NAME_ANNOTATION) else {
+        return (None, comment.trim().to_string());
+    };
+    let rest = &comment[start + NAME_ANNOTATION.len()..];
+    let Some(end) = rest.find(')') else {
+        return (None, comment.trim().to_string());
+    };
+    let description = format!("{} {}", &comment[..start], &rest[end + 1..]);
+    (Some(rest[..end].trim()), description.trim().to_string())
+}
+
+/// Returns the GraphQL name of a field, which is either set with an annotation in the comment of
+/// the field, or the name of the field following the naming strategy of the schema.
+pub fn field_gql_name(fd: &DefineFieldStatement, naming: GqlNaming) -> Result<String, GqlError> {
+    let alias = fd.comment.as_ref().and_then(|c| split_name_annotation(c.as_str()).0);
+    let Some(alias) = alias else {
+        let name = match fd.name.0.iter().rev().find_map(|p| match p {
+            Part::Field(ident) => Some(ident),
+            _ => None,
+        }) {
+            Some(ident) => ident.to_raw(),
+            None => fd.name.to_string(),
+        };
+        return Ok(naming.field_name(&name));
+    };
+    let mut chars = alias.chars();
+    let valid = chars.next().is_some_and(|c| c == '_' || c.is_ascii_alphabetic())
+        && chars.all(|c| c == '_' || c.is_ascii_alphanumeric())
+        && !alias.starts_with("__");
+    match valid {
+        true => Ok(alias.to_string()),
+        false => {
+            Err(schema_error(format!("Invalid GraphQL name `{alias}` for the field `{}`", fd.name)))
+        }
+    }
+}
+
+/// Returns the description of a field, which is its comment without the name annotation.
+pub fn field_gql_description(fd: &DefineFieldStatement) -> String {
+    fd.comment.as_ref().map(|c| split_name_annotation(c.as_str()).1).unwrap_or_default()
+}
+
+/// Checks that no two fields of an object map to the same GraphQL name, which can happen through
+/// name annotations or field names which only differ in their case style.
+pub fn check_field_names(
+    tb_name: &str,
+    fds: &[DefineFieldStatement],
+    naming: GqlNaming,
+) -> Result<(), GqlError> {
+    let id = Idiom::from("id");
+    // Keyed on the parent idiom as a string, as parts are not safe to hash
+    let mut names: HashMap<(String, String), &Idiom> = HashMap::new();
+    names.insert((String::new(), "id".to_string()), &id);
+    for fd in fds.iter().filter(|fd| !fd.name.is_id()) {
+        // The fields defining the elements of arrays, `field[*]`, have no name of their own
+        let Some((Part::Field(_), parent)) = fd.name.0.split_last() else { continue; };
+        let name = field_gql_name(fd, naming)?;
+        if let Some(other) = names.insert((Idiom::from(parent.to_vec()).to_string(), name.clone()), &fd.name) {
+            return Err(schema_error(format!(
+                "The fields `{other}` and `{}` of the table `{tb_name}` both have the GraphQL name `{name}`",
+                fd.name
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sql::Id;
+
+    #[test]
+    fn record_id() {
+        assert_eq!(parse_record_id("user", "user:123").unwrap(), Thing::from(("user", Id::from(123))));
+        assert_eq!(parse_record_id("user", "user:tobie").unwrap(), Thing::from(("user", "tobie")));
+        assert_eq!(
+            parse_record_id("foo bar", "⟨foo bar⟩:1").unwrap(),
+            Thing::from(("foo bar", Id::from(1)))
+        );
+    }
+
+    #[test]
+    fn bare_record_id() {
+        assert_eq!(parse_record_id("user", "123").unwrap(), Thing::from(("user", Id::from(123))));
+        assert_eq!(parse_record_id("user", "tobie").unwrap(), Thing::from(("user", "tobie")));
+        assert_eq!(parse_record_id("foo bar", "1").unwrap(), Thing::from(("foo bar", Id::from(1))));
+    }
+
+    #[test]
+    fn invalid_record_id() {
+        for id in ["", "user:", ":1", "user:1:2", "user:1; DELETE user", "{ a: 1 ", "hello world"] {
+            assert!(
+                matches!(parse_record_id("user", id), Err(GqlError::InputError(_))),
+                "`{id}` should be rejected"
+            );
+        }
+        assert!(matches!(parse_record_id("user", "post:1"), Err(GqlError::InputError(_))));
+    }
+
+    #[test]
+    fn name_annotation() {
+        assert_eq!(split_name_annotation("Creation date"), (None, "Creation date".to_string()));
+        assert_eq!(
+            split_name_annotation("@graphql(name: creation) Creation date"),
+            (Some("creation"), "Creation date".to_string())
+        );
+        assert_eq!(
+            split_name_annotation("Creation date @graphql(name:creation)"),
+            (Some("creation"), "Creation date".to_string())
+        );
+        assert_eq!(
+            split_name_annotation("@graphql(name: creation"),
+            (None, "@graphql(name: creation".to_string())
+        );
+    }
+}
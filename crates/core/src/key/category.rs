@@ -24,8 +24,12 @@ pub enum Category {
 	Node,
 	/// crate::key::root::ni                 /!ni
 	NamespaceIdentifier,
+	/// crate::key::root::nr                 /!nr{ns}
+	NamespaceRemoval,
 	/// crate::key::root::ns                 /!ns{ns}
 	Namespace,
+	/// crate::key::root::sg                 /!sg
+	SchemaGeneration,
 	/// crate::key::root::us                 /!us{us}
 	User,
 	///
@@ -178,7 +182,9 @@ impl Display for Category {
 			Self::AccessGrant => "AccessGrant",
 			Self::Node => "Node",
 			Self::NamespaceIdentifier => "NamespaceIdentifier",
+			Self::NamespaceRemoval => "NamespaceRemoval",
 			Self::Namespace => "Namespace",
+			Self::SchemaGeneration => "SchemaGeneration",
 			Self::User => "User",
 			Self::NodeRoot => "NodeRoot",
 			Self::NodeLiveQuery => "NodeLiveQuery",
@@ -6,7 +6,9 @@
 /// crate::key::root::ac                 /!ac{ac}
 /// crate::key::root::nd                 /!nd{nd}
 /// crate::key::root::ni                 /!ni
+/// crate::key::root::nr                 /!nr{ns}
 /// crate::key::root::ns                 /!ns{ns}
+/// crate::key::root::sg                 /!sg
 /// crate::key::root::us                 /!us{us}
 ///
 /// crate::key::node::all                /${nd}
@@ -3,5 +3,7 @@ pub mod access;
 pub mod all;
 pub mod nd;
 pub mod ni;
+pub mod nr;
 pub mod ns;
+pub mod sg;
 pub mod us;
@@ -0,0 +1,68 @@
+//! Stores a namespace which is pending background removal
+use crate::key::category::Categorise;
+use crate::key::category::Category;
+use crate::kvs::impl_key;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Nr<'a> {
+	__: u8,
+	_a: u8,
+	_b: u8,
+	_c: u8,
+	pub ns: &'a str,
+}
+impl_key!(Nr<'a>);
+
+pub fn new(ns: &str) -> Nr<'_> {
+	Nr::new(ns)
+}
+
+pub fn prefix() -> Vec<u8> {
+	let mut k = super::all::kv();
+	k.extend_from_slice(b"!nr\x00");
+	k
+}
+
+pub fn suffix() -> Vec<u8> {
+	let mut k = super::all::kv();
+	k.extend_from_slice(b"!nr\xff");
+	k
+}
+
+impl Categorise for Nr<'_> {
+	fn categorise(&self) -> Category {
+		Category::NamespaceRemoval
+	}
+}
+
+impl<'a> Nr<'a> {
+	pub fn new(ns: &'a str) -> Self {
+		Self {
+			__: b'/',
+			_a: b'!',
+			_b: b'n',
+			_c: b'r',
+			ns,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::kvs::{KeyDecode, KeyEncode};
+	#[test]
+	fn key() {
+		use super::*;
+		#[rustfmt::skip]
+		let val = Nr::new(
+			"testns",
+		);
+		let enc = Nr::encode(&val).unwrap();
+		assert_eq!(enc, b"/!nrtestns\0");
+
+		let dec = Nr::decode(&enc).unwrap();
+		assert_eq!(val, dec);
+	}
+}
@@ -0,0 +1,52 @@
+//! Stores the generation of the schema definitions
+use crate::key::category::Categorise;
+use crate::key::category::Category;
+use crate::kvs::impl_key;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Sg {
+	__: u8,
+	_a: u8,
+	_b: u8,
+	_c: u8,
+}
+impl_key!(Sg);
+
+impl Default for Sg {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl Categorise for Sg {
+	fn categorise(&self) -> Category {
+		Category::SchemaGeneration
+	}
+}
+
+impl Sg {
+	pub fn new() -> Self {
+		Self {
+			__: b'/',
+			_a: b'!',
+			_b: b's',
+			_c: b'g',
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::kvs::{KeyDecode, KeyEncode};
+	#[test]
+	fn key() {
+		use super::*;
+		let val = Sg::new();
+		let enc = Sg::encode(&val).unwrap();
+		assert_eq!(enc, b"/!sg");
+		let dec = Sg::decode(&enc).unwrap();
+		assert_eq!(val, dec);
+	}
+}
@@ -4,6 +4,7 @@ use super::tx::Transaction;
 use super::version::Version;
 use crate::buc::BucketConnections;
 use crate::cf;
+use crate::cnf::NAMESPACE_REMOVAL_BATCH_SIZE;
 use crate::ctx::MutableContext;
 #[cfg(feature = "jwks")]
 use crate::dbs::capabilities::NetTarget;
@@ -25,7 +26,7 @@ use crate::kvs::clock::SizedClock;
 use crate::kvs::clock::SystemClock;
 #[cfg(not(target_family = "wasm"))]
 use crate::kvs::index::IndexBuilder;
-use crate::kvs::{LockType, LockType::*, TransactionType, TransactionType::*};
+use crate::kvs::{KeyDecode, LockType, LockType::*, TransactionType, TransactionType::*};
 use crate::sql::FlowResultExt as _;
 use crate::sql::{statements::DefineUserStatement, Base, Query, Value};
 use crate::syn;
@@ -285,6 +286,8 @@ impl Datastore {
 					let s = s.trim_start_matches("file://");
 					let s = s.trim_start_matches("file:");
 					let v = super::rocksdb::Datastore::new(s).await.map(DatastoreFlavor::RocksDB);
+					// Track the disk usage of the data directory
+					crate::sys::set_data_path(s).await;
 					let c = clock.unwrap_or_else(|| Arc::new(SizedClock::system()));
 					info!(target: TARGET, "Started kvs store at {}", path);
 					Ok((v, c))
@@ -303,6 +306,8 @@ impl Datastore {
 					let s = s.trim_start_matches("rocksdb://");
 					let s = s.trim_start_matches("rocksdb:");
 					let v = super::rocksdb::Datastore::new(s).await.map(DatastoreFlavor::RocksDB);
+					// Track the disk usage of the data directory
+					crate::sys::set_data_path(s).await;
 					let c = clock.unwrap_or_else(|| Arc::new(SizedClock::system()));
 					info!(target: TARGET, "Started kvs store at {}", path);
 					Ok((v, c))
@@ -323,6 +328,8 @@ impl Datastore {
 					let v = super::surrealkv::Datastore::new(path, enable_versions)
 						.await
 						.map(DatastoreFlavor::SurrealKV);
+					// Track the disk usage of the data directory
+					crate::sys::set_data_path(path).await;
 					let c = clock.unwrap_or_else(|| Arc::new(SizedClock::system()));
 					info!(target: TARGET, "Started kvs store at {} with versions {}", path, if enable_versions { "enabled" } else { "disabled" });
 					Ok((v, c))
@@ -434,6 +441,31 @@ impl Datastore {
 		}
 	}
 
+	/// Create a new datastore with the same persistent data (inner), with its own cache.
+	/// Simulating another node of a cluster
+	#[allow(dead_code)]
+	pub fn new_node(&self) -> Self {
+		Self {
+			id: Uuid::new_v4(),
+			strict: self.strict,
+			auth_enabled: self.auth_enabled,
+			query_timeout: self.query_timeout,
+			transaction_timeout: self.transaction_timeout,
+			capabilities: self.capabilities.clone(),
+			notification_channel: None,
+			index_stores: Default::default(),
+			#[cfg(not(target_family = "wasm"))]
+			index_builder: IndexBuilder::new(self.transaction_factory.clone()),
+			#[cfg(feature = "jwks")]
+			jwks_cache: Arc::new(Default::default()),
+			#[cfg(storage)]
+			temporary_directory: self.temporary_directory.clone(),
+			transaction_factory: self.transaction_factory.clone(),
+			cache: Arc::new(DatastoreCache::new()),
+			buckets: Arc::new(DashMap::new()),
+		}
+	}
+
 	/// Specify whether this Datastore should run in strict mode
 	pub fn with_node_id(mut self, id: Uuid) -> Self {
 		self.id = id;
@@ -492,6 +524,11 @@ impl Datastore {
 		self.auth_enabled
 	}
 
+	/// The global query timeout for this Datastore, if any
+	pub(crate) fn query_timeout(&self) -> Option<Duration> {
+		self.query_timeout
+	}
+
 	pub fn id(&self) -> Uuid {
 		self.id
 	}
@@ -684,6 +721,39 @@ impl Datastore {
 		Ok(())
 	}
 
+	/// Run the background task to reclaim the data of removed namespaces
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::ds", skip(self))]
+	pub async fn namespace_removal_process(&self) -> Result<(), Error> {
+		// Output function invocation details to logs
+		trace!(target: TARGET, "Reclaiming data of removed namespaces");
+		// Fetch the namespaces pending removal
+		let keys = {
+			let txn = self.transaction(Read, Optimistic).await?;
+			let beg = crate::key::root::nr::prefix();
+			let end = crate::key::root::nr::suffix();
+			let keys = catch!(txn, txn.keys(beg..end, u32::MAX, None).await);
+			catch!(txn, txn.cancel().await);
+			keys
+		};
+		// Remove each namespace in batches of separate transactions
+		for key in keys.iter() {
+			let nr = crate::key::root::nr::Nr::decode(key)?;
+			// Log the namespace removal
+			trace!(target: TARGET, ns = %nr.ns, "Reclaiming data of removed namespace");
+			loop {
+				let txn = self.transaction(Write, Optimistic).await?;
+				let done =
+					catch!(txn, txn.reclaim_ns_batch(nr.ns, *NAMESPACE_REMOVAL_BATCH_SIZE).await);
+				catch!(txn, txn.commit().await);
+				if done {
+					break;
+				}
+			}
+		}
+		// Everything ok
+		Ok(())
+	}
+
 	/// Run the background task to perform changefeed garbage collection
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::ds", skip(self))]
 	pub async fn changefeed_process(&self) -> Result<(), Error> {
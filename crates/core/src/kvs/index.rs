@@ -1,5 +1,5 @@
 use super::KeyDecode;
-use crate::cnf::{INDEXING_BATCH_SIZE, NORMAL_FETCH_SIZE};
+use crate::cnf::{INDEXING_BATCH_SIZE, INDEXING_PROGRESS_COUNT, NORMAL_FETCH_SIZE};
 use crate::ctx::{Context, MutableContext};
 use crate::dbs::Options;
 use crate::doc::{CursorDoc, Document};
@@ -27,6 +27,8 @@ use tokio::sync::RwLock;
 use tokio::task;
 use tokio::task::JoinHandle;
 
+const TARGET: &str = "surrealdb::core::kvs::index";
+
 #[derive(Debug, Clone)]
 pub(crate) enum BuildingStatus {
 	Started,
@@ -35,6 +37,8 @@ pub(crate) enum BuildingStatus {
 		initial: Option<usize>,
 		updated: Option<usize>,
 		pending: Option<usize>,
+		/// The number of records in the table when the build started
+		total: Option<usize>,
 	},
 	Ready {
 		initial: Option<usize>,
@@ -69,6 +73,75 @@ impl BuildingStatus {
 	fn is_ready(&self) -> bool {
 		matches!(self, Self::Ready { .. })
 	}
+
+	/// Estimates the progress of the build, between 0 and 1, returns none if the build failed.
+	///
+	/// The progress is the share of the records indexed so far, out of the records indexed so
+	/// far plus the records of the table and the appended records still to be indexed. While
+	/// the table is first indexed, there is no estimate unless its records were counted.
+	fn progress(&self) -> Option<f64> {
+		match self {
+			Self::Started | Self::Cleaning => Some(0.0),
+			Self::Indexing {
+				updated: None,
+				total: None,
+				..
+			} => None,
+			Self::Indexing {
+				initial,
+				updated,
+				pending,
+				total,
+			} => {
+				let done = initial.unwrap_or(0) + updated.unwrap_or(0);
+				let remaining =
+					total.unwrap_or(0).saturating_sub(initial.unwrap_or(0)) + pending.unwrap_or(0);
+				match done + remaining {
+					0 => Some(0.0),
+					all => Some(done as f64 / all as f64),
+				}
+			}
+			Self::Ready {
+				..
+			} => Some(1.0),
+			Self::Aborted | Self::Error(_) => None,
+		}
+	}
+
+	/// Returns the output of `INFO FOR INDEX` for this status.
+	///
+	/// While the index is building, `building` holds the status of the build, once the index is
+	/// built it is `false`.
+	pub(crate) fn into_info(self) -> Object {
+		let mut out = Object::default();
+		if let Some(progress) = self.progress() {
+			out.insert("progress".to_string(), progress.into());
+		}
+		if let Self::Indexing {
+			initial,
+			updated,
+			pending,
+			..
+		}
+		| Self::Ready {
+			initial,
+			updated,
+			pending,
+		} = &self
+		{
+			for (k, v) in [("initial", initial), ("pending", pending), ("updated", updated)] {
+				if let Some(v) = v {
+					out.insert(k.to_string(), (*v).into());
+				}
+			}
+		}
+		let building = match self.is_ready() {
+			true => Value::Bool(false),
+			false => self.into(),
+		};
+		out.insert("building".to_string(), building);
+		out
+	}
 }
 
 impl From<BuildingStatus> for Value {
@@ -81,6 +154,7 @@ impl From<BuildingStatus> for Value {
 				initial,
 				pending,
 				updated,
+				..
 			} => {
 				if let Some(c) = initial {
 					o.insert("initial".to_string(), c.into());
@@ -417,6 +491,22 @@ impl Building {
 		// First iteration, we index every key
 		let beg = thing::prefix(ns, db, &self.tb)?;
 		let end = thing::suffix(ns, db, &self.tb)?;
+		// Count the records to estimate the progress, if enabled, as this scans the whole table
+		let total = match *INDEXING_PROGRESS_COUNT {
+			true => {
+				let tx = self.new_read_tx().await?;
+				let total = tx.count(beg.clone()..end.clone()).await;
+				tx.cancel().await?;
+				match total {
+					Ok(total) => Some(total),
+					Err(e) => {
+						warn!(target: TARGET, "Failed to count the records of the index being built: {e}");
+						None
+					}
+				}
+			}
+			false => None,
+		};
 		let mut next = Some(beg..end);
 		let mut initial_count = 0;
 		// Set the initial status
@@ -424,6 +514,7 @@ impl Building {
 			initial: Some(initial_count),
 			pending: Some(self.queue.read().await.pending() as usize),
 			updated: None,
+			total,
 		})
 		.await;
 		while let Some(rng) = next {
@@ -450,7 +541,8 @@ impl Building {
 				// Index the batch
 				catch!(
 					tx,
-					self.index_initial_batch(&ctx, &tx, batch.result, &mut initial_count).await
+					self.index_initial_batch(&ctx, &tx, batch.result, &mut initial_count, total)
+						.await
 				);
 				tx.commit().await?;
 			}
@@ -460,6 +552,7 @@ impl Building {
 			initial: Some(initial_count),
 			pending: Some(self.queue.read().await.pending() as usize),
 			updated: Some(0),
+			total,
 		})
 		.await;
 		let mut updates_count = 0;
@@ -499,8 +592,15 @@ impl Building {
 				let tx = ctx.tx();
 				catch!(
 					tx,
-					self.index_appending_range(&ctx, &tx, range, initial_count, &mut updates_count)
-						.await
+					self.index_appending_range(
+						&ctx,
+						&tx,
+						range,
+						initial_count,
+						&mut updates_count,
+						total,
+					)
+					.await
 				);
 				tx.commit().await?;
 			}
@@ -514,6 +614,7 @@ impl Building {
 		tx: &Transaction,
 		values: Vec<(Key, Val)>,
 		count: &mut usize,
+		total: Option<usize>,
 	) -> Result<(), Error> {
 		let mut stack = TreeStack::new();
 		// Index the records
@@ -561,6 +662,7 @@ impl Building {
 				initial: Some(*count),
 				pending: Some(self.queue.read().await.pending() as usize),
 				updated: None,
+				total,
 			})
 			.await;
 		}
@@ -574,6 +676,7 @@ impl Building {
 		range: Range<u32>,
 		initial: usize,
 		count: &mut usize,
+		total: Option<usize>,
 	) -> Result<(), Error> {
 		let mut stack = TreeStack::new();
 		for i in range {
@@ -599,6 +702,7 @@ impl Building {
 					initial: Some(initial),
 					pending: Some(self.queue.read().await.pending() as usize),
 					updated: Some(*count),
+					total,
 				})
 				.await;
 			}
@@ -638,3 +742,75 @@ impl Building {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::BuildingStatus;
+	use crate::sql::Value;
+
+	#[test]
+	fn info_while_indexing() {
+		let status = BuildingStatus::Indexing {
+			initial: Some(100),
+			updated: Some(20),
+			pending: Some(30),
+			total: Some(100),
+		};
+		assert_eq!(status.progress(), Some(0.8));
+		let info = status.into_info();
+		assert_eq!(info.get("progress"), Some(&Value::from(0.8)));
+		assert_eq!(info.get("initial"), Some(&Value::from(100)));
+		assert_eq!(info.get("pending"), Some(&Value::from(30)));
+		let Some(Value::Object(building)) = info.get("building") else {
+			panic!("Expected the building status: {info}");
+		};
+		assert_eq!(building.get("status"), Some(&Value::from("indexing")));
+	}
+
+	#[test]
+	fn info_while_indexing_without_count() {
+		let status = BuildingStatus::Indexing {
+			initial: Some(100),
+			updated: None,
+			pending: Some(30),
+			total: None,
+		};
+		assert_eq!(status.progress(), None);
+		let info = status.into_info();
+		assert_eq!(info.get("progress"), None);
+		assert_eq!(info.get("initial"), Some(&Value::from(100)));
+		// Once the table is indexed, the progress is estimated from the appended records
+		let status = BuildingStatus::Indexing {
+			initial: Some(100),
+			updated: Some(20),
+			pending: Some(30),
+			total: None,
+		};
+		assert_eq!(status.progress(), Some(0.8));
+	}
+
+	#[test]
+	fn info_when_ready() {
+		let status = BuildingStatus::Ready {
+			initial: Some(100),
+			updated: Some(10),
+			pending: Some(0),
+		};
+		assert_eq!(status.progress(), Some(1.0));
+		let info = status.into_info();
+		assert_eq!(info.get("progress"), Some(&Value::from(1.0)));
+		assert_eq!(info.get("initial"), Some(&Value::from(100)));
+		assert_eq!(info.get("pending"), Some(&Value::from(0)));
+		assert_eq!(info.get("building"), Some(&Value::Bool(false)));
+	}
+
+	#[test]
+	fn info_when_failed() {
+		let info = BuildingStatus::Error("failed".to_string()).into_info();
+		assert_eq!(info.get("progress"), None);
+		let Some(Value::Object(building)) = info.get("building") else {
+			panic!("Expected the building status: {info}");
+		};
+		assert_eq!(building.get("status"), Some(&Value::from("error")));
+	}
+}
@@ -33,6 +33,8 @@ pub struct Transaction {
 	check: Check,
 	/// The underlying datastore transaction
 	inner: Option<rocksdb::Transaction<'static, OptimisticTransactionDB>>,
+	/// The number of underlying save points held by each open save point
+	save_points: Vec<usize>,
 	/// The read options containing the Snapshot
 	ro: ReadOptions,
 	// The above, supposedly 'static transaction
@@ -291,6 +293,7 @@ impl Datastore {
 			write,
 			check,
 			inner: Some(inner),
+			save_points: Vec::new(),
 			ro,
 			_db: self.db.clone(),
 		})
@@ -788,18 +791,28 @@ impl Transaction {
 		let inner = self.inner.as_ref().unwrap();
 		// Set the save point
 		inner.set_savepoint();
+		self.save_points.push(1);
 	}
 
 	pub(crate) async fn rollback_to_save_point(&mut self) -> Result<(), Error> {
 		// Get the transaction
 		let inner = self.inner.as_ref().unwrap();
-		// Rollback
-		inner.rollback_to_savepoint()?;
+		// Rollback every underlying save point held by the last save point
+		let count = self.save_points.pop().unwrap_or(1);
+		for _ in 0..count {
+			inner.rollback_to_savepoint()?;
+		}
 		//
 		Ok(())
 	}
 
 	pub(crate) fn release_last_save_point(&mut self) -> Result<(), Error> {
+		// The underlying save point can't be released, so the enclosing save point takes it over
+		if let Some(count) = self.save_points.pop() {
+			if let Some(last) = self.save_points.last_mut() {
+				*last += count;
+			}
+		}
 		Ok(())
 	}
 }
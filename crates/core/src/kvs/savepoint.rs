@@ -67,6 +67,22 @@ impl SavePoints {
 		}
 	}
 
+	pub(super) fn release(&mut self) -> Result<(), Error> {
+		let sp = self.pop()?;
+		// The enclosing save point takes over the saved values
+		if let Some(current) = &mut self.current {
+			for (key, sv) in sp {
+				match current.get_mut(&key) {
+					Some(v) => v.last_operation = sv.last_operation,
+					None => {
+						current.insert(key, sv);
+					}
+				}
+			}
+		}
+		Ok(())
+	}
+
 	pub(super) fn is_saved_key(&self, key: &Key) -> Option<bool> {
 		self.current.as_ref().map(|current| current.contains_key(key))
 	}
@@ -126,12 +142,15 @@ pub(super) trait SavePointImpl: Transaction + Sized {
 
 	async fn rollback_to_save_point(&mut self) -> Result<(), Error> {
 		let sp = self.get_save_points().pop()?;
-		SavePoints::rollback(sp, self).await
+		// The restored values must not be saved in the enclosing save point
+		let save_points = std::mem::take(self.get_save_points());
+		let res = SavePoints::rollback(sp, self).await;
+		*self.get_save_points() = save_points;
+		res
 	}
 
 	fn release_last_save_point(&mut self) -> Result<(), Error> {
-		self.get_save_points().pop()?;
-		Ok(())
+		self.get_save_points().release()
 	}
 
 	async fn save_point_prepare(
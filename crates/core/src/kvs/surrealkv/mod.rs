@@ -28,6 +28,8 @@ pub struct Transaction {
 	check: Check,
 	/// The underlying datastore transaction
 	inner: Option<Tx>,
+	/// The number of underlying save points held by each open save point
+	save_points: Vec<usize>,
 }
 
 impl Drop for Transaction {
@@ -123,6 +125,7 @@ impl Datastore {
 			check,
 			write,
 			inner: Some(txn),
+			save_points: Vec::new(),
 		})
 	}
 }
@@ -619,16 +622,27 @@ impl Transaction {
 		if let Some(inner) = &mut self.inner {
 			let _ = inner.set_savepoint();
 		}
+		self.save_points.push(1);
 	}
 
 	pub(crate) async fn rollback_to_save_point(&mut self) -> Result<(), Error> {
+		// Rollback every underlying save point held by the last save point
+		let count = self.save_points.pop().unwrap_or(1);
 		if let Some(inner) = &mut self.inner {
-			inner.rollback_to_savepoint()?;
+			for _ in 0..count {
+				inner.rollback_to_savepoint()?;
+			}
 		}
 		Ok(())
 	}
 
 	pub(crate) fn release_last_save_point(&mut self) -> Result<(), Error> {
+		// The underlying save point can't be released, so the enclosing save point takes it over
+		if let Some(count) = self.save_points.pop() {
+			if let Some(last) = self.save_points.last_mut() {
+				*last += count;
+			}
+		}
 		Ok(())
 	}
 }
@@ -423,6 +423,37 @@ pub async fn batch(new_ds: impl CreateDs) {
 	tx.cancel().await.unwrap();
 }
 
+pub async fn save_points(new_ds: impl CreateDs) {
+	// Create a new datastore
+	let node_id = Uuid::parse_str("0e5e8d4d-8a6c-4f5c-9a54-3c1b1d1e2a7f").unwrap();
+	let clock = Arc::new(SizedClock::Fake(FakeClock::new(Timestamp::default())));
+	let (ds, _) = new_ds.create_ds(node_id, clock).await;
+	// Create a writeable transaction
+	let mut tx = ds.transaction(Write, Optimistic).await.unwrap().inner();
+	tx.set("test1", "1", None).await.unwrap();
+	// Set an outer save point
+	tx.new_save_point().await;
+	tx.set("test2", "2", None).await.unwrap();
+	// Set and release a nested save point
+	tx.new_save_point().await;
+	tx.set("test3", "3", None).await.unwrap();
+	tx.release_last_save_point().await.unwrap();
+	tx.set("test4", "4", None).await.unwrap();
+	// Set and rollback a nested save point
+	tx.new_save_point().await;
+	tx.set("test5", "5", None).await.unwrap();
+	tx.rollback_to_save_point().await.unwrap();
+	assert!(tx.exists("test4", None).await.unwrap());
+	assert!(!tx.exists("test5", None).await.unwrap());
+	// Rollback the outer save point
+	tx.rollback_to_save_point().await.unwrap();
+	assert!(tx.exists("test1", None).await.unwrap());
+	assert!(!tx.exists("test2", None).await.unwrap());
+	assert!(!tx.exists("test3", None).await.unwrap());
+	assert!(!tx.exists("test4", None).await.unwrap());
+	tx.cancel().await.unwrap();
+}
+
 macro_rules! define_tests {
 	($new_ds:ident) => {
 		#[tokio::test]
@@ -504,6 +535,12 @@ macro_rules! define_tests {
 		async fn batch() {
 			super::raw::batch($new_ds).await;
 		}
+
+		#[tokio::test]
+		#[serial_test::serial]
+		async fn save_points() {
+			super::raw::save_points($new_ds).await;
+		}
 	};
 }
 pub(crate) use define_tests;
@@ -31,6 +31,7 @@ use crate::sql::statements::DefineParamStatement;
 use crate::sql::statements::DefineTableStatement;
 use crate::sql::statements::DefineUserStatement;
 use crate::sql::statements::LiveStatement;
+use crate::sql::statements::RemoveNamespaceStatement;
 use crate::sql::Id;
 use crate::sql::Permissions;
 use crate::sql::Value;
@@ -39,6 +40,7 @@ use futures::lock::MutexGuard;
 use futures::stream::Stream;
 use std::fmt::Debug;
 use std::ops::Range;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -54,6 +56,8 @@ pub struct Transaction {
 	index_caches: IndexTreeCaches,
 	/// Does this supports reverse scan
 	reverse_scan: bool,
+	/// Were the cached definitions cleared
+	cleared: AtomicBool,
 }
 
 impl Transaction {
@@ -65,6 +69,7 @@ impl Transaction {
 			cache: TransactionCache::new(),
 			index_caches: IndexTreeCaches::default(),
 			reverse_scan,
+			cleared: AtomicBool::new(false),
 		}
 	}
 
@@ -1661,6 +1666,49 @@ impl Transaction {
 		Ok(())
 	}
 
+	/// Check that a namespace is not pending background removal, before it is defined again.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
+	pub async fn check_ns_reclaimed(&self, ns: &str) -> Result<(), Error> {
+		let key = crate::key::root::nr::new(ns);
+		if self.exists(key, None).await? {
+			return Err(Error::NsRemovalPending {
+				name: ns.to_owned(),
+			});
+		}
+		Ok(())
+	}
+
+	/// Delete a batch of at most `limit` keys of a namespace which is pending background removal.
+	///
+	/// The pending removal entry is only deleted once no data of the namespace remains, which
+	/// is the case when this returns `true`. Otherwise this should be called again in a new
+	/// transaction, so that large namespaces are reclaimed without exceeding transaction limits.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
+	pub async fn reclaim_ns_batch(&self, ns: &str, limit: u32) -> Result<bool, Error> {
+		// Check whether the namespace is pending removal
+		let key = crate::key::root::nr::new(ns);
+		let Some(val) = self.get(&key, None).await? else {
+			return Ok(true);
+		};
+		let stm: RemoveNamespaceStatement = revision::from_slice(&val)?;
+		// Delete the next batch of resource data
+		let rng = util::to_prefix_range(crate::key::namespace::all::new(ns))?;
+		let res = self.batch_keys(rng, limit, None).await?;
+		for k in res.result {
+			match stm.expunge {
+				true => self.clr(k).await?,
+				false => self.del(k).await?,
+			};
+		}
+		// Keep the pending removal entry while data remains
+		if res.next.is_some() {
+			return Ok(false);
+		}
+		// Delete the pending removal entry
+		self.clr(key).await?;
+		Ok(true)
+	}
+
 	/// Get or add a namespace with a default configuration, only if we are in dynamic mode.
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
 	pub async fn get_or_add_ns(
@@ -1768,9 +1816,39 @@ impl Transaction {
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
 	#[inline(always)]
 	pub fn clear(&self) {
+		self.cleared.store(true, Ordering::Relaxed);
 		self.cache.clear()
 	}
 
+	/// Checks if the transaction cache was cleared, which
+	/// happens whenever any definitions are modified.
+	pub(crate) fn cleared(&self) -> bool {
+		self.cleared.load(Ordering::Relaxed)
+	}
+
+	/// Retrieve the generation of the schema definitions, which
+	/// changes whenever definitions are modified on any node.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
+	pub async fn get_schema_generation(&self) -> Result<Option<crate::sql::Uuid>, Error> {
+		let key = crate::key::root::sg::Sg::new();
+		match self.get(key, None).await? {
+			Some(val) => Ok(Some(revision::from_slice(&val)?)),
+			None => Ok(None),
+		}
+	}
+
+	/// Store a new generation of the schema definitions, if any
+	/// definitions were modified within this transaction.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
+	pub(crate) async fn new_schema_generation(&self) -> Result<(), Error> {
+		if self.cleared() {
+			let key = crate::key::root::sg::Sg::new();
+			let val = crate::sql::Uuid::new_v7();
+			self.set(key, revision::to_vec(&val)?, None).await?;
+		}
+		Ok(())
+	}
+
 	// --------------------------------------------------
 	// Private methods
 	// --------------------------------------------------
@@ -1800,6 +1878,8 @@ impl Transaction {
 					Err(Error::NsNotFound {
 						..
 					}) if !strict => {
+						// Ensure no data is left over from a previous namespace
+						self.check_ns_reclaimed(ns).await?;
 						let val = DefineNamespaceStatement {
 							name: ns.to_owned().into(),
 							..Default::default()
@@ -10,6 +10,7 @@ pub struct EngineOptions {
 	pub node_membership_check_interval: Duration,
 	pub node_membership_cleanup_interval: Duration,
 	pub changefeed_gc_interval: Duration,
+	pub namespace_removal_interval: Duration,
 }
 
 impl Default for EngineOptions {
@@ -19,6 +20,7 @@ impl Default for EngineOptions {
 			node_membership_check_interval: Duration::from_secs(15),
 			node_membership_cleanup_interval: Duration::from_secs(300),
 			changefeed_gc_interval: Duration::from_secs(10),
+			namespace_removal_interval: Duration::from_secs(10),
 		}
 	}
 }
@@ -40,4 +42,8 @@ impl EngineOptions {
 		self.changefeed_gc_interval = interval;
 		self
 	}
+	pub fn with_namespace_removal_interval(mut self, interval: Duration) -> Self {
+		self.namespace_removal_interval = interval;
+		self
+	}
 }
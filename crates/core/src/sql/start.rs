@@ -11,13 +11,42 @@ use std::fmt;
 
 use super::FlowResultExt as _;
 
-#[revisioned(revision = 1)]
+#[revisioned(revision = 2)]
 #[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
-pub struct Start(pub Value);
+pub struct Start(
+	pub Value,
+	/// Whether the value is a percentage of the result set
+	#[revision(start = 2)]
+	pub bool,
+);
 
 impl Start {
+	/// Check if this START clause is a percentage of the result set
+	pub(crate) fn is_percentage(&self) -> bool {
+		self.1
+	}
+	/// Process this START clause as a percentage of the result set
+	pub(crate) async fn percentage(
+		&self,
+		stk: &mut Stk,
+		ctx: &Context,
+		opt: &Options,
+		doc: Option<&CursorDoc>,
+	) -> Result<f64, Error> {
+		match self.0.compute(stk, ctx, opt, doc).await.catch_return() {
+			// This is a valid percentage
+			Ok(Value::Number(v)) if (0.0..=100.0).contains(&v.to_float()) => Ok(v.to_float()),
+			// An invalid value was specified
+			Ok(v) => Err(Error::InvalidStartPercentage {
+				value: v.as_string(),
+			}),
+			// A different error occurred
+			Err(e) => Err(e),
+		}
+	}
+
 	pub(crate) async fn process(
 		&self,
 		stk: &mut Stk,
@@ -48,6 +77,10 @@ impl Start {
 
 impl fmt::Display for Start {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		write!(f, "START {}", self.0)
+		write!(f, "START {}", self.0)?;
+		if self.1 {
+			write!(f, "%")?
+		}
+		Ok(())
 	}
 }
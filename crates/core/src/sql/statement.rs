@@ -10,8 +10,8 @@ use crate::sql::{
 		CommitStatement, ContinueStatement, CreateStatement, DefineStatement, DeleteStatement,
 		ForeachStatement, IfelseStatement, InfoStatement, InsertStatement, KillStatement,
 		LiveStatement, OptionStatement, OutputStatement, RelateStatement, RemoveStatement,
-		SelectStatement, SetStatement, ShowStatement, SleepStatement, ThrowStatement,
-		UpdateStatement, UpsertStatement, UseStatement,
+		SavepointStatement, SelectStatement, SetStatement, ShowStatement, SleepStatement,
+		ThrowStatement, UpdateStatement, UpsertStatement, UseStatement,
 	},
 	value::Value,
 };
@@ -56,7 +56,7 @@ impl Display for Statements {
 	}
 }
 
-#[revisioned(revision = 5)]
+#[revisioned(revision = 6)]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
@@ -97,6 +97,8 @@ pub enum Statement {
 	// TODO(gguillemas): Document once bearer access is no longer experimental.
 	#[revision(start = 5)]
 	Access(AccessStatement),
+	#[revision(start = 6)]
+	Savepoint(SavepointStatement),
 }
 
 impl Statement {
@@ -225,6 +227,7 @@ impl Display for Statement {
 			Self::Rebuild(v) => write!(Pretty::from(f), "{v}"),
 			Self::Relate(v) => write!(Pretty::from(f), "{v}"),
 			Self::Remove(v) => write!(Pretty::from(f), "{v}"),
+			Self::Savepoint(v) => write!(Pretty::from(f), "{v}"),
 			Self::Select(v) => write!(Pretty::from(f), "{v}"),
 			Self::Set(v) => write!(Pretty::from(f), "{v}"),
 			Self::Show(v) => write!(Pretty::from(f), "{v}"),
@@ -1,15 +1,28 @@
+use crate::sql::{Ident, Strand};
 use revision::revisioned;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-#[revisioned(revision = 1)]
+#[revisioned(revision = 3)]
 #[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
-pub struct CancelStatement;
+pub struct CancelStatement {
+	#[revision(start = 2)]
+	pub reason: Option<Strand>,
+	#[revision(start = 3)]
+	pub savepoint: Option<Ident>,
+}
 
 impl fmt::Display for CancelStatement {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		f.write_str("CANCEL TRANSACTION")
+		match self.savepoint {
+			Some(ref v) => write!(f, "CANCEL TO SAVEPOINT {v}")?,
+			None => f.write_str("CANCEL TRANSACTION")?,
+		}
+		if let Some(ref v) = self.reason {
+			write!(f, " {v}")?
+		}
+		Ok(())
 	}
 }
@@ -7,7 +7,7 @@ use crate::sql::{Ident, Part, Value};
 use revision::revisioned;
 use serde::{Deserialize, Serialize};
 
-#[revisioned(revision = 1)]
+#[revisioned(revision = 4)]
 #[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
@@ -15,6 +15,18 @@ pub struct GraphQLConfig {
     pub tables: TablesConfig,
     pub functions: FunctionsConfig,
     pub cursor: bool,
+    #[revision(start = 2)]
+    pub naming: GqlNaming,
+    /// Whether lists of records which are not cursor paginated are wrapped in an object with
+    /// their total count and window, instead of being returned as bare lists.
+    #[revision(start = 3)]
+    pub wrap_lists: bool,
+    /// The maximum depth of nested selections in a query, if limited.
+    #[revision(start = 4)]
+    pub depth_limit: Option<u32>,
+    /// The maximum complexity of a query, the number of fields it selects, if limited.
+    #[revision(start = 4)]
+    pub complexity_limit: Option<u32>,
 }
 
 #[revisioned(revision = 1)]
@@ -49,6 +61,36 @@ pub enum FunctionsConfig {
     Exclude(Vec<Ident>),
 }
 
+/// How the names of the types and fields of the GraphQL schema are derived from the names of the
+/// tables and fields they are generated from.
+#[revisioned(revision = 1)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum GqlNaming {
+    /// Types in PascalCase and fields in camelCase, e.g. `UserProfile` and `createdAt`.
+    #[default]
+    Pascal,
+    /// Names as they are defined, e.g. `user_profile` and `created_at`.
+    Verbatim,
+    /// Types and fields each in the given case.
+    Custom {
+        types: NameCase,
+        fields: NameCase,
+    },
+}
+
+#[revisioned(revision = 1)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum NameCase {
+    Verbatim,
+    Pascal,
+    Camel,
+    Snake,
+}
+
 impl Display for GraphQLConfig {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, " GRAPHQL")?;
@@ -56,10 +98,35 @@ impl Display for GraphQLConfig {
         write!(f, " TABLES {}", self.tables)?;
         write!(f, " FUNCTIONS {}", self.functions)?;
         if self.cursor { write!(f, " CURSOR")?; }
+        if self.naming != GqlNaming::Pascal { write!(f, " NAMING {}", self.naming)?; }
+        if self.wrap_lists { write!(f, " LISTS WRAPPED")?; }
+        if let Some(depth) = self.depth_limit { write!(f, " DEPTH {depth}")?; }
+        if let Some(complexity) = self.complexity_limit { write!(f, " COMPLEXITY {complexity}")?; }
         Ok(())
     }
 }
 
+impl Display for GqlNaming {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GqlNaming::Pascal => write!(f, "PASCAL"),
+            GqlNaming::Verbatim => write!(f, "VERBATIM"),
+            GqlNaming::Custom { types, fields } => write!(f, "TYPES {types} FIELDS {fields}"),
+        }
+    }
+}
+
+impl Display for NameCase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NameCase::Verbatim => write!(f, "VERBATIM"),
+            NameCase::Pascal => write!(f, "PASCAL"),
+            NameCase::Camel => write!(f, "CAMEL"),
+            NameCase::Snake => write!(f, "SNAKE"),
+        }
+    }
+}
+
 impl Display for TablesConfig {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -74,6 +74,32 @@ impl DefineFunctionStatement {
 	}
 }
 
+/// The signature of a custom function, like `fn::add($a: int, $b: int) -> int`.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub struct FunctionSignature {
+	pub name: Ident,
+	pub args: Vec<(Ident, Kind)>,
+	pub returns: Option<Kind>,
+}
+
+impl fmt::Display for FunctionSignature {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "fn::{}(", self.name.0)?;
+		for (i, (name, kind)) in self.args.iter().enumerate() {
+			if i > 0 {
+				f.write_str(", ")?;
+			}
+			write!(f, "${name}: {kind}")?;
+		}
+		f.write_char(')')?;
+		if let Some(ref v) = self.returns {
+			write!(f, " -> {v}")?;
+		}
+		Ok(())
+	}
+}
+
 impl fmt::Display for DefineFunctionStatement {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		write!(f, "DEFINE FUNCTION")?;
@@ -23,7 +23,7 @@ pub use config::DefineConfigStatement;
 pub use database::DefineDatabaseStatement;
 pub use event::DefineEventStatement;
 pub use field::DefineFieldStatement;
-pub use function::DefineFunctionStatement;
+pub use function::{DefineFunctionStatement, FunctionSignature};
 pub use index::DefineIndexStatement;
 pub use model::DefineModelStatement;
 pub use namespace::DefineNamespaceStatement;
@@ -46,6 +46,8 @@ impl DefineNamespaceStatement {
 				});
 			}
 		}
+		// Ensure no data is left over from a previous namespace
+		txn.check_ns_reclaimed(&self.name).await?;
 		// Process the statement
 		let key = crate::key::root::ns::new(&self.name);
 		txn.set(
@@ -1,10 +1,18 @@
+use crate::api::path::Path;
+use crate::buc::store::ListOptions;
+use crate::buc::BucketController;
+use crate::cnf::{COUNT_BATCH_SIZE, INFO_MAX_ENTRIES};
 use crate::ctx::Context;
 use crate::dbs::Options;
 use crate::doc::CursorDoc;
 use crate::err::Error;
 use crate::iam::Action;
 use crate::iam::ResourceKind;
-use crate::sql::{Base, Ident, Object, Value, Version};
+use crate::key::thing;
+use crate::kvs::Transaction;
+use crate::sql::escape::QuoteStr;
+use crate::sql::statements::access::Subject;
+use crate::sql::{AccessType, Base, Ident, Limit, Object, Start, Value, Version};
 use crate::sys::INFORMATION;
 
 use reblessive::tree::Stk;
@@ -13,7 +21,7 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::sync::Arc;
 
-#[revisioned(revision = 5)]
+#[revisioned(revision = 10)]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
@@ -27,10 +35,21 @@ pub enum InfoStatement {
 	Ns(#[revision(start = 2)] bool),
 
 	#[revision(override(revision = 2, discriminant = 5), override(revision = 3, discriminant = 5))]
-	Db(#[revision(start = 2)] bool, #[revision(start = 5)] Option<Version>),
+	Db(
+		#[revision(start = 2)] bool,
+		#[revision(start = 5)] Option<Version>,
+		#[revision(start = 6)] Option<InfoDbCategory>,
+		#[revision(start = 7)] Option<Limit>,
+		#[revision(start = 7)] Option<Start>,
+	),
 
 	#[revision(override(revision = 2, discriminant = 7), override(revision = 3, discriminant = 7))]
-	Tb(Ident, #[revision(start = 2)] bool, #[revision(start = 5)] Option<Version>),
+	Tb(
+		Ident,
+		#[revision(start = 2)] bool,
+		#[revision(start = 5)] Option<Version>,
+		#[revision(start = 8)] bool,
+	),
 
 	#[revision(override(revision = 2, discriminant = 9), override(revision = 3, discriminant = 9))]
 	User(Ident, Option<Base>, #[revision(start = 2)] bool),
@@ -38,6 +57,168 @@ pub enum InfoStatement {
 	#[revision(start = 3)]
 	#[revision(override(revision = 3, discriminant = 10))]
 	Index(Ident, Ident, bool),
+
+	#[revision(start = 9)]
+	Api(Ident),
+
+	#[revision(start = 10)]
+	Bucket(Ident, bool, Option<Limit>, Option<Start>),
+}
+
+/// A single category of definitions output by `INFO FOR DATABASE`.
+#[revisioned(revision = 1)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum InfoDbCategory {
+	Accesses,
+	Apis,
+	Analyzers,
+	Buckets,
+	Functions,
+	Models,
+	Params,
+	Tables,
+	Users,
+	Configs,
+}
+
+impl InfoDbCategory {
+	/// All categories, output together by `INFO FOR DATABASE`.
+	const ALL: [InfoDbCategory; 10] = [
+		Self::Accesses,
+		Self::Apis,
+		Self::Analyzers,
+		Self::Buckets,
+		Self::Functions,
+		Self::Models,
+		Self::Params,
+		Self::Tables,
+		Self::Users,
+		Self::Configs,
+	];
+
+	/// The key of this category in the output.
+	fn key(&self) -> &'static str {
+		match self {
+			Self::Accesses => "accesses",
+			Self::Apis => "apis",
+			Self::Analyzers => "analyzers",
+			Self::Buckets => "buckets",
+			Self::Functions => "functions",
+			Self::Models => "models",
+			Self::Params => "params",
+			Self::Tables => "tables",
+			Self::Users => "users",
+			Self::Configs => "configs",
+		}
+	}
+
+	/// Fetch the definitions of this category
+	async fn compute(
+		&self,
+		txn: &Transaction,
+		ns: &str,
+		db: &str,
+		version: Option<u64>,
+		structured: bool,
+		page: &mut InfoPage,
+	) -> Result<Value, Error> {
+		Ok(match (self, structured) {
+			(Self::Accesses, true) => process(
+				page.apply(txn.all_db_accesses(ns, db).await?)
+					.iter()
+					.map(|v| v.redacted())
+					.collect(),
+			),
+			(Self::Accesses, false) => {
+				let mut out = Object::default();
+				for v in page.apply(txn.all_db_accesses(ns, db).await?).iter().map(|v| v.redacted())
+				{
+					out.insert(v.name.to_raw(), v.to_string().into());
+				}
+				out.into()
+			}
+			(Self::Apis, true) => process(page.apply(txn.all_db_apis(ns, db).await?)),
+			(Self::Apis, false) => {
+				let mut out = Object::default();
+				for v in page.apply(txn.all_db_apis(ns, db).await?).iter() {
+					out.insert(v.path.to_string(), v.to_string().into());
+				}
+				out.into()
+			}
+			(Self::Analyzers, true) => process(page.apply(txn.all_db_analyzers(ns, db).await?)),
+			(Self::Analyzers, false) => {
+				let mut out = Object::default();
+				for v in page.apply(txn.all_db_analyzers(ns, db).await?).iter() {
+					out.insert(v.name.to_raw(), v.to_string().into());
+				}
+				out.into()
+			}
+			(Self::Buckets, true) => process(page.apply(txn.all_db_buckets(ns, db).await?)),
+			(Self::Buckets, false) => {
+				let mut out = Object::default();
+				for v in page.apply(txn.all_db_buckets(ns, db).await?).iter() {
+					out.insert(v.name.to_string(), v.to_string().into());
+				}
+				out.into()
+			}
+			(Self::Functions, true) => process(page.apply(txn.all_db_functions(ns, db).await?)),
+			(Self::Functions, false) => {
+				let mut out = Object::default();
+				for v in page.apply(txn.all_db_functions(ns, db).await?).iter() {
+					out.insert(v.name.to_raw(), v.to_string().into());
+				}
+				out.into()
+			}
+			(Self::Models, true) => process(page.apply(txn.all_db_models(ns, db).await?)),
+			(Self::Models, false) => {
+				let mut out = Object::default();
+				for v in page.apply(txn.all_db_models(ns, db).await?).iter() {
+					out.insert(v.name.to_raw(), v.to_string().into());
+				}
+				out.into()
+			}
+			(Self::Params, true) => process(page.apply(txn.all_db_params(ns, db).await?)),
+			(Self::Params, false) => {
+				let mut out = Object::default();
+				for v in page.apply(txn.all_db_params(ns, db).await?).iter() {
+					out.insert(v.name.to_raw(), v.to_string().into());
+				}
+				out.into()
+			}
+			(Self::Tables, true) => process(page.apply(txn.all_tb(ns, db, version).await?)),
+			(Self::Tables, false) => {
+				let mut out = Object::default();
+				for v in page.apply(txn.all_tb(ns, db, version).await?).iter() {
+					out.insert(v.name.to_raw(), v.to_string().into());
+				}
+				out.into()
+			}
+			(Self::Users, true) => process(page.apply(txn.all_db_users(ns, db).await?)),
+			(Self::Users, false) => {
+				let mut out = Object::default();
+				for v in page.apply(txn.all_db_users(ns, db).await?).iter() {
+					out.insert(v.name.to_raw(), v.to_string().into());
+				}
+				out.into()
+			}
+			(Self::Configs, true) => process(page.apply(txn.all_db_configs(ns, db).await?)),
+			(Self::Configs, false) => {
+				let mut out = Object::default();
+				for v in page.apply(txn.all_db_configs(ns, db).await?).iter() {
+					out.insert(v.inner.name(), v.to_string().into());
+				}
+				out.into()
+			}
+		})
+	}
+}
+
+impl fmt::Display for InfoDbCategory {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(&self.key().to_ascii_uppercase())
+	}
 }
 
 impl InfoStatement {
@@ -136,7 +317,7 @@ impl InfoStatement {
 					}),
 				})
 			}
-			InfoStatement::Db(structured, version) => {
+			InfoStatement::Db(structured, version, category, limit, start) => {
 				// Allowed to run?
 				opt.is_allowed(Action::View, ResourceKind::Any, &Base::Db)?;
 				// Get the NS and DB
@@ -148,95 +329,28 @@ impl InfoStatement {
 				};
 				// Get the transaction
 				let txn = ctx.tx();
+				// Output only the selected category, or all of them
+				let categories = match category {
+					Some(v) => std::slice::from_ref(v),
+					None => &InfoDbCategory::ALL[..],
+				};
+				// Limit the number of definitions output per category
+				let mut page = InfoPage::new(stk, ctx, opt, limit.as_ref(), start.as_ref()).await?;
 				// Create the result set
-				Ok(match structured {
-					true => Value::from(map! {
-						"accesses".to_string() => process(txn.all_db_accesses(ns, db).await?.iter().map(|v| v.redacted()).collect()),
-						"apis".to_string() => process(txn.all_db_apis(ns, db).await?),
-						"analyzers".to_string() => process(txn.all_db_analyzers(ns, db).await?),
-						"buckets".to_string() => process(txn.all_db_buckets(ns, db).await?),
-						"functions".to_string() => process(txn.all_db_functions(ns, db).await?),
-						"models".to_string() => process(txn.all_db_models(ns, db).await?),
-						"params".to_string() => process(txn.all_db_params(ns, db).await?),
-						"tables".to_string() => process(txn.all_tb(ns, db, version).await?),
-						"users".to_string() => process(txn.all_db_users(ns, db).await?),
-						"configs".to_string() => process(txn.all_db_configs(ns, db).await?),
-					}),
-					false => Value::from(map! {
-						"accesses".to_string() => {
-							let mut out = Object::default();
-							for v in txn.all_db_accesses(ns, db).await?.iter().map(|v| v.redacted()) {
-								out.insert(v.name.to_raw(), v.to_string().into());
-							}
-							out.into()
-						},
-						"apis".to_string() => {
-							let mut out = Object::default();
-							for v in txn.all_db_apis(ns, db).await?.iter() {
-								out.insert(v.path.to_string(), v.to_string().into());
-							}
-							out.into()
-						},
-						"analyzers".to_string() => {
-							let mut out = Object::default();
-							for v in txn.all_db_analyzers( ns, db).await?.iter() {
-								out.insert(v.name.to_raw(), v.to_string().into());
-							}
-							out.into()
-						},
-						"buckets".to_string() => {
-							let mut out = Object::default();
-							for v in txn.all_db_buckets(ns, db).await?.iter() {
-								out.insert(v.name.to_string(), v.to_string().into());
-							}
-							out.into()
-						},
-						"functions".to_string() => {
-							let mut out = Object::default();
-							for v in txn.all_db_functions(ns, db).await?.iter() {
-								out.insert(v.name.to_raw(), v.to_string().into());
-							}
-							out.into()
-						},
-						"models".to_string() => {
-							let mut out = Object::default();
-							for v in txn.all_db_models(ns, db).await?.iter() {
-								out.insert(v.name.to_raw(), v.to_string().into());
-							}
-							out.into()
-						},
-						"params".to_string() => {
-							let mut out = Object::default();
-							for v in txn.all_db_params(ns, db).await?.iter() {
-								out.insert(v.name.to_raw(), v.to_string().into());
-							}
-							out.into()
-						},
-						"tables".to_string() => {
-							let mut out = Object::default();
-							for v in txn.all_tb(ns, db, version).await?.iter() {
-								out.insert(v.name.to_raw(), v.to_string().into());
-							}
-							out.into()
-						},
-						"users".to_string() => {
-							let mut out = Object::default();
-							for v in txn.all_db_users(ns, db).await?.iter() {
-								out.insert(v.name.to_raw(), v.to_string().into());
-							}
-							out.into()
-						},
-						"configs".to_string() => {
-							let mut out = Object::default();
-							for v in txn.all_db_configs(ns, db).await?.iter() {
-								out.insert(v.inner.name(), v.to_string().into());
-							}
-							out.into()
-						},
-					}),
-				})
+				let mut out = Object::default();
+				for v in categories {
+					out.insert(
+						v.key().to_string(),
+						v.compute(&txn, ns, db, version, *structured, &mut page).await?,
+					);
+				}
+				// Mark the output if any category was not output in full
+				if page.truncated {
+					out.insert("truncated".to_string(), Value::Bool(true));
+				}
+				Ok(out.into())
 			}
-			InfoStatement::Tb(tb, structured, version) => {
+			InfoStatement::Tb(tb, structured, version, stats) => {
 				// Allowed to run?
 				opt.is_allowed(Action::View, ResourceKind::Any, &Base::Db)?;
 				// Get the NS and DB
@@ -249,7 +363,7 @@ impl InfoStatement {
 				// Get the transaction
 				let txn = ctx.tx();
 				// Create the result set
-				Ok(match structured {
+				let mut out = match structured {
 					true => Value::from(map! {
 						"events".to_string() => process(txn.all_tb_events(ns, db, tb).await?),
 						"fields".to_string() => process(txn.all_tb_fields(ns, db, tb, version).await?),
@@ -294,7 +408,14 @@ impl InfoStatement {
 							out.into()
 						},
 					}),
-				})
+				};
+				// Output the record statistics when requested
+				if *stats {
+					if let Value::Object(ref mut v) = out {
+						v.insert("stats".to_string(), table_stats(&txn, ns, db, tb).await?);
+					}
+				}
+				Ok(out)
 			}
 			InfoStatement::User(user, base, structured) => {
 				// Get the base type
@@ -315,28 +436,108 @@ impl InfoStatement {
 				};
 				// Ok all good
 				Ok(match structured {
-					true => res.as_ref().clone().structure(),
+					true => {
+						let mut out = res.as_ref().clone().structure();
+						// Add the access methods with active grants for this user
+						let access = user_accesses(&txn, opt, &base, user).await?;
+						if let Value::Object(ref mut v) = out {
+							v.insert("access".to_string(), access);
+						}
+						out
+					}
 					false => Value::from(res.to_string()),
 				})
 			}
-			#[allow(unused_variables)]
-			InfoStatement::Index(index, table, _structured) => {
+			InfoStatement::Index(index, table, structured) => {
 				// Allowed to run?
 				opt.is_allowed(Action::View, ResourceKind::Actor, &Base::Db)?;
+				// Get the NS and DB
+				let (ns, db) = opt.ns_db()?;
 				// Get the transaction
 				let txn = ctx.tx();
-				// Output
+				// Obtain the index
+				let res = txn.get_tb_index(ns, db, table, index).await?;
+				// Output the definition when structured
+				#[cfg_attr(target_family = "wasm", allow(unused_mut))]
+				let mut out = match structured {
+					true => match res.as_ref().clone().structure() {
+						Value::Object(v) => v,
+						_ => Object::default(),
+					},
+					false => Object::default(),
+				};
+				// Output the building status
 				#[cfg(not(target_family = "wasm"))]
 				if let Some(ib) = ctx.get_index_builder() {
-					// Obtain the index
-					let (ns, db) = opt.ns_db()?;
-					let res = txn.get_tb_index(ns, db, table, index).await?;
 					let status = ib.get_status(ns, db, &res).await;
-					let mut out = Object::default();
-					out.insert("building".to_string(), status.into());
-					return Ok(out.into());
+					out.extend(status.into_info().0);
 				}
-				Ok(Object::default().into())
+				Ok(out.into())
+			}
+			InfoStatement::Api(path) => {
+				// Allowed to run?
+				opt.is_allowed(Action::View, ResourceKind::Api, &Base::Db)?;
+				// Get the NS and DB
+				let (ns, db) = opt.ns_db()?;
+				// Get the transaction
+				let txn = ctx.tx();
+				// Normalize the path the same way as when the API was defined
+				let path: Path = path.parse()?;
+				// Obtain the API
+				let res = txn.get_db_api(ns, db, &path.to_string()).await?;
+				// Output the definition
+				Ok(res.as_ref().clone().structure())
+			}
+			InfoStatement::Bucket(bucket, files, limit, start) => {
+				// Allowed to run?
+				opt.is_allowed(Action::View, ResourceKind::Bucket, &Base::Db)?;
+				// Get the NS and DB
+				let (ns, db) = opt.ns_db()?;
+				// Obtain the bucket
+				let res = ctx.tx().get_db_bucket(ns, db, bucket).await?;
+				// Output the definition
+				let mut out = match res.as_ref().clone().structure() {
+					Value::Object(v) => v,
+					_ => Object::default(),
+				};
+				// Output the stored files when requested
+				if *files {
+					let limit = match limit {
+						Some(v) => {
+							(v.process(stk, ctx, opt, None).await? as usize).min(*INFO_MAX_ENTRIES)
+						}
+						None => *INFO_MAX_ENTRIES,
+					};
+					let start = match start {
+						Some(v) if v.is_percentage() => {
+							return Err(Error::InvalidStart {
+								value: v.to_string(),
+							})
+						}
+						Some(v) => v.process(stk, ctx, opt, None).await? as usize,
+						None => 0,
+					};
+					// Fetch one more file than requested, to know if the list was truncated
+					let opts = ListOptions {
+						limit: Some(start.saturating_add(limit).saturating_add(1)),
+						..Default::default()
+					};
+					let list = BucketController::new(stk, ctx, opt, None, bucket)
+						.await?
+						.list(&opts)
+						.await?;
+					if list.len() > start.saturating_add(limit) {
+						out.insert("truncated".to_string(), Value::Bool(true));
+					}
+					let list = list
+						.into_iter()
+						.skip(start)
+						.take(limit)
+						.map(|v| v.into_value(bucket.to_raw()))
+						.collect::<Vec<_>>();
+					out.insert("files".to_string(), list.into());
+				}
+				Ok(out.into())
 			}
 		}
 	}
@@ -349,23 +550,38 @@ impl fmt::Display for InfoStatement {
 			Self::Root(true) => f.write_str("INFO FOR ROOT STRUCTURE"),
 			Self::Ns(false) => f.write_str("INFO FOR NAMESPACE"),
 			Self::Ns(true) => f.write_str("INFO FOR NAMESPACE STRUCTURE"),
-			Self::Db(false, ref v) => match v {
-				Some(ref v) => write!(f, "INFO FOR DATABASE VERSION {v}"),
-				None => f.write_str("INFO FOR DATABASE"),
-			},
-			Self::Db(true, ref v) => match v {
-				Some(ref v) => write!(f, "INFO FOR DATABASE VERSION {v} STRUCTURE"),
-				None => f.write_str("INFO FOR DATABASE STRUCTURE"),
-			},
-			Self::Tb(ref t, false, ref v) => match v {
-				Some(ref v) => write!(f, "INFO FOR TABLE {t} VERSION {v}"),
-				None => write!(f, "INFO FOR TABLE {t}"),
-			},
-
-			Self::Tb(ref t, true, ref v) => match v {
-				Some(ref v) => write!(f, "INFO FOR TABLE {t} VERSION {v} STRUCTURE"),
-				None => write!(f, "INFO FOR TABLE {t} STRUCTURE"),
-			},
+			Self::Db(structured, ref v, ref c, ref l, ref s) => {
+				f.write_str("INFO FOR DATABASE")?;
+				if let Some(ref c) = c {
+					write!(f, " {c}")?;
+				}
+				if let Some(ref v) = v {
+					write!(f, " VERSION {v}")?;
+				}
+				if let Some(ref l) = l {
+					write!(f, " {l}")?;
+				}
+				if let Some(ref s) = s {
+					write!(f, " {s}")?;
+				}
+				if *structured {
+					f.write_str(" STRUCTURE")?;
+				}
+				Ok(())
+			}
+			Self::Tb(ref t, structured, ref v, stats) => {
+				write!(f, "INFO FOR TABLE {t}")?;
+				if let Some(ref v) = v {
+					write!(f, " VERSION {v}")?;
+				}
+				if *stats {
+					f.write_str(" STATS")?;
+				}
+				if *structured {
+					f.write_str(" STRUCTURE")?;
+				}
+				Ok(())
+			}
 			Self::User(ref u, ref b, false) => match b {
 				Some(ref b) => write!(f, "INFO FOR USER {u} ON {b}"),
 				None => write!(f, "INFO FOR USER {u}"),
@@ -376,6 +592,20 @@ impl fmt::Display for InfoStatement {
 			},
 			Self::Index(ref i, ref t, false) => write!(f, "INFO FOR INDEX {i} ON {t}"),
 			Self::Index(ref i, ref t, true) => write!(f, "INFO FOR INDEX {i} ON {t} STRUCTURE"),
+			Self::Api(ref p) => write!(f, "INFO FOR API {}", QuoteStr(p)),
+			Self::Bucket(ref b, files, ref l, ref s) => {
+				write!(f, "INFO FOR BUCKET {b}")?;
+				if *files {
+					f.write_str(" FILES")?;
+				}
+				if let Some(ref l) = l {
+					write!(f, " {l}")?;
+				}
+				if let Some(ref s) = s {
+					write!(f, " {s}")?;
+				}
+				Ok(())
+			}
 		}
 	}
 }
@@ -389,22 +619,96 @@ impl InfoStatement {
 		match self {
 			InfoStatement::Root(_) => InfoStatement::Root(true),
 			InfoStatement::Ns(_) => InfoStatement::Ns(true),
-			InfoStatement::Db(_, v) => InfoStatement::Db(true, v),
-			InfoStatement::Tb(t, _, v) => InfoStatement::Tb(t, true, v),
+			InfoStatement::Db(_, v, c, l, s) => InfoStatement::Db(true, v, c, l, s),
+			InfoStatement::Tb(t, _, v, st) => InfoStatement::Tb(t, true, v, st),
 			InfoStatement::User(u, b, _) => InfoStatement::User(u, b, true),
 			InfoStatement::Index(i, t, _) => InfoStatement::Index(i, t, true),
+			InfoStatement::Api(p) => InfoStatement::Api(p),
+			InfoStatement::Bucket(b, f, l, s) => InfoStatement::Bucket(b, f, l, s),
 		}
 	}
 
 	pub(crate) fn versionize(self, v: Version) -> Self {
 		match self {
-			InfoStatement::Db(s, _) => InfoStatement::Db(s, Some(v)),
-			InfoStatement::Tb(t, s, _) => InfoStatement::Tb(t, s, Some(v)),
+			InfoStatement::Db(s, _, c, l, st) => InfoStatement::Db(s, Some(v), c, l, st),
+			InfoStatement::Tb(t, s, _, st) => InfoStatement::Tb(t, s, Some(v), st),
+			_ => self,
+		}
+	}
+
+	pub(crate) fn with_stats(self) -> Self {
+		match self {
+			InfoStatement::Tb(t, s, v, _) => InfoStatement::Tb(t, s, v, true),
+			_ => self,
+		}
+	}
+
+	pub(crate) fn paginate(self, limit: Option<Limit>, start: Option<Start>) -> Self {
+		match self {
+			InfoStatement::Db(s, v, c, _, _) => InfoStatement::Db(s, v, c, limit, start),
+			InfoStatement::Bucket(b, f, _, _) => InfoStatement::Bucket(b, f, limit, start),
 			_ => self,
 		}
 	}
 }
 
+/// The part of the definitions of each category output by `INFO FOR DATABASE`.
+struct InfoPage {
+	/// The number of definitions to skip
+	start: usize,
+	/// The percentage of the definitions to skip, instead of a fixed number
+	start_percentage: Option<f64>,
+	/// The maximum number of definitions to output
+	limit: usize,
+	/// Whether any definitions after the page were left out
+	truncated: bool,
+}
+
+impl InfoPage {
+	async fn new(
+		stk: &mut Stk,
+		ctx: &Context,
+		opt: &Options,
+		limit: Option<&Limit>,
+		start: Option<&Start>,
+	) -> Result<Self, Error> {
+		let mut page = InfoPage {
+			start: 0,
+			start_percentage: None,
+			limit: *INFO_MAX_ENTRIES,
+			truncated: false,
+		};
+		if let Some(v) = limit {
+			page.limit = page.limit.min(v.process(stk, ctx, opt, None).await? as usize);
+		}
+		if let Some(v) = start {
+			if v.is_percentage() {
+				page.start_percentage = Some(v.percentage(stk, ctx, opt, None).await?);
+			} else {
+				page.start = v.process(stk, ctx, opt, None).await? as usize;
+			}
+		}
+		Ok(page)
+	}
+
+	/// Returns the definitions within this page, recording if any were left out after it.
+	fn apply<T: Clone>(&mut self, a: Arc<[T]>) -> Arc<[T]> {
+		let start = match self.start_percentage {
+			Some(p) => (a.len() as f64 * p / 100.0) as usize,
+			None => self.start,
+		}
+		.min(a.len());
+		let end = start.saturating_add(self.limit).min(a.len());
+		if end < a.len() {
+			self.truncated = true;
+		}
+		if start == 0 && end == a.len() {
+			return a;
+		}
+		a[start..end].into()
+	}
+}
+
 fn process<T>(a: Arc<[T]>) -> Value
 where
 	T: InfoStructure + Clone,
@@ -412,11 +716,75 @@ where
 	Value::Array(a.iter().cloned().map(InfoStructure::structure).collect())
 }
 
+/// Returns the names of the access methods on the base of a user which have an active grant
+/// for that user.
+async fn user_accesses(
+	txn: &Transaction,
+	opt: &Options,
+	base: &Base,
+	user: &Ident,
+) -> Result<Value, Error> {
+	let accesses = match base {
+		Base::Root => txn.all_root_accesses().await?,
+		Base::Ns => txn.all_ns_accesses(opt.ns()?).await?,
+		Base::Db => {
+			let (ns, db) = opt.ns_db()?;
+			txn.all_db_accesses(ns, db).await?
+		}
+		_ => return Err(Error::InvalidLevel(base.to_string())),
+	};
+	let mut out = Vec::new();
+	for ac in accesses.iter().filter(|ac| matches!(ac.kind, AccessType::Bearer(_))) {
+		let grants = match base {
+			Base::Root => txn.all_root_access_grants(&ac.name).await?,
+			Base::Ns => txn.all_ns_access_grants(opt.ns()?, &ac.name).await?,
+			_ => {
+				let (ns, db) = opt.ns_db()?;
+				txn.all_db_access_grants(ns, db, &ac.name).await?
+			}
+		};
+		if grants
+			.iter()
+			.any(|gr| gr.is_active() && matches!(&gr.subject, Subject::User(u) if u == user))
+		{
+			out.push(Value::from(ac.name.to_raw()));
+		}
+	}
+	Ok(out.into())
+}
+
+/// Returns the number of records in a table and the total size of their keys and values in bytes.
+///
+/// The statistics are computed by scanning the key range of the table, and always reflect the
+/// latest version of the records.
+async fn table_stats(txn: &Transaction, ns: &str, db: &str, tb: &str) -> Result<Value, Error> {
+	let beg = thing::prefix(ns, db, tb)?;
+	let end = thing::suffix(ns, db, tb)?;
+	let mut next = Some(beg..end);
+	let mut count = 0;
+	let mut size = 0;
+	while let Some(rng) = next {
+		let res = txn.batch_keys_vals(rng, *COUNT_BATCH_SIZE, None).await?;
+		next = res.next;
+		for (k, v) in res.result.iter() {
+			count += 1;
+			size += k.len() + v.len();
+		}
+	}
+	Ok(Value::from(map! {
+		"count".to_string() => count.into(),
+		"size".to_string() => size.into(),
+	}))
+}
+
 async fn system() -> Value {
 	let info = INFORMATION.lock().await;
 	Value::from(map! {
 		"available_parallelism".to_string() => info.available_parallelism.into(),
 		"cpu_usage".to_string() => info.cpu_usage.into(),
+		"disk_available".to_string() => info.disk_available.map_or(Value::Null, Value::from),
+		"disk_total".to_string() => info.disk_total.map_or(Value::Null, Value::from),
+		"disk_used".to_string() => info.disk_used.map_or(Value::Null, Value::from),
 		"load_average".to_string() => info.load_average.to_vec().into(),
 		"memory_usage".to_string() => info.memory_usage.into(),
 		"physical_cores".to_string() => info.physical_cores.into(),
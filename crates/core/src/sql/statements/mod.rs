@@ -20,6 +20,7 @@ pub(crate) mod output;
 pub(crate) mod rebuild;
 pub(crate) mod relate;
 pub(crate) mod remove;
+pub(crate) mod savepoint;
 pub(crate) mod select;
 pub(crate) mod set;
 pub(crate) mod show;
@@ -38,7 +39,7 @@ pub use self::create::CreateStatement;
 pub use self::delete::DeleteStatement;
 pub use self::foreach::ForeachStatement;
 pub use self::ifelse::IfelseStatement;
-pub use self::info::InfoStatement;
+pub use self::info::{InfoDbCategory, InfoStatement};
 pub use self::insert::InsertStatement;
 pub use self::kill::KillStatement;
 pub use self::live::LiveStatement;
@@ -48,6 +49,7 @@ pub use self::r#break::BreakStatement;
 pub use self::r#continue::ContinueStatement;
 pub use self::r#use::UseStatement;
 pub use self::relate::RelateStatement;
+pub use self::savepoint::SavepointStatement;
 pub use self::select::SelectStatement;
 pub use self::set::SetStatement;
 pub use self::show::ShowStatement;
@@ -62,7 +64,7 @@ pub use self::define::{
 	DefineAccessStatement, DefineAnalyzerStatement, DefineApiStatement, DefineDatabaseStatement,
 	DefineEventStatement, DefineFieldStatement, DefineFunctionStatement, DefineIndexStatement,
 	DefineModelStatement, DefineNamespaceStatement, DefineParamStatement, DefineStatement,
-	DefineTableStatement, DefineUserStatement,
+	DefineTableStatement, DefineUserStatement, FunctionSignature,
 };
 
 pub use self::remove::{
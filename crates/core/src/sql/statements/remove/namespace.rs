@@ -1,14 +1,15 @@
 use crate::ctx::Context;
-use crate::dbs::Options;
+use crate::dbs::{self, Notification, Options};
 use crate::err::Error;
 use crate::iam::{Action, ResourceKind};
-use crate::sql::{Base, Ident, Value};
+use crate::kvs::Transaction;
+use crate::sql::{Base, Datetime, Ident, Object, Value};
 
 use revision::revisioned;
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display, Formatter};
 
-#[revisioned(revision = 3)]
+#[revisioned(revision = 7)]
 #[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
@@ -18,6 +19,14 @@ pub struct RemoveNamespaceStatement {
 	pub if_exists: bool,
 	#[revision(start = 3)]
 	pub expunge: bool,
+	#[revision(start = 4)]
+	pub detail: bool,
+	#[revision(start = 5)]
+	pub dry_run: bool,
+	#[revision(start = 6)]
+	pub concurrently: bool,
+	#[revision(start = 7)]
+	pub report: bool,
 }
 
 impl RemoveNamespaceStatement {
@@ -28,6 +37,12 @@ impl RemoveNamespaceStatement {
 			opt.is_allowed(Action::Edit, ResourceKind::Namespace, &Base::Root)?;
 			// Get the transaction
 			let txn = ctx.tx();
+			// Get the definition
+			let ns = txn.get_ns(&self.name).await?;
+			// List the resources without removing anything
+			if self.dry_run {
+				return self.dry_run(&txn, &ns.name).await;
+			}
 			// Remove the index stores
 			#[cfg(not(target_family = "wasm"))]
 			ctx.get_index_stores()
@@ -35,8 +50,13 @@ impl RemoveNamespaceStatement {
 				.await?;
 			#[cfg(target_family = "wasm")]
 			ctx.get_index_stores().namespace_removed(&txn, &self.name).await?;
-			// Get the definition
-			let ns = txn.get_ns(&self.name).await?;
+			// Notify the live queries which are removed
+			self.notify(opt, &txn, &ns.name).await?;
+			// Count the resources which will be removed
+			let res = match self.detail {
+				true => self.detail(&txn, &ns.name).await?,
+				false => Value::None,
+			};
 			// Delete the definition
 			let key = crate::key::root::ns::new(&ns.name);
 			match self.expunge {
@@ -44,11 +64,30 @@ impl RemoveNamespaceStatement {
 				false => txn.del(key).await?,
 			};
 			// Delete the resource data
-			let key = crate::key::namespace::all::new(&ns.name);
-			match self.expunge {
-				true => txn.clrp(key).await?,
-				false => txn.delp(key).await?,
-			};
+			match self.concurrently {
+				// Enqueue the data for background removal
+				true => {
+					// Delete the database and table definitions
+					for db in txn.all_db(&ns.name).await?.iter() {
+						for tb in txn.all_tb(&ns.name, &db.name, None).await?.iter() {
+							txn.del(crate::key::database::tb::new(&ns.name, &db.name, &tb.name))
+								.await?;
+						}
+						txn.del(crate::key::namespace::db::new(&ns.name, &db.name)).await?;
+					}
+					// Mark the namespace as pending removal
+					let key = crate::key::root::nr::new(&ns.name);
+					txn.set(key, revision::to_vec(self)?, None).await?;
+				}
+				// Delete the data within this transaction
+				false => {
+					let key = crate::key::namespace::all::new(&ns.name);
+					match self.expunge {
+						true => txn.clrp(key).await?,
+						false => txn.delp(key).await?,
+					};
+				}
+			}
 			// Clear the cache
 			if let Some(cache) = ctx.get_cache() {
 				cache.clear();
@@ -56,16 +95,98 @@ impl RemoveNamespaceStatement {
 			// Clear the cache
 			txn.clear();
 			// Ok all good
-			Ok(Value::None)
+			Ok(self.report(res, true))
 		}
 		.await;
 		match future {
 			Err(Error::NsNotFound {
 				..
-			}) if self.if_exists => Ok(Value::None),
+			}) if self.if_exists => Ok(self.report(Value::None, false)),
 			v => v,
 		}
 	}
+	/// Add whether the namespace was removed to the result, if requested
+	fn report(&self, res: Value, removed: bool) -> Value {
+		if !self.report {
+			return res;
+		}
+		let mut res = match res {
+			Value::Object(v) => v,
+			_ => Object::default(),
+		};
+		res.insert("removed".to_string(), Value::from(removed));
+		Value::from(res)
+	}
+	/// Notify the live queries defined within the namespace that it has been removed
+	async fn notify(&self, opt: &Options, txn: &Transaction, ns: &str) -> Result<(), Error> {
+		// Check if we can send notifications
+		let Some(chn) = opt.sender.as_ref() else {
+			return Ok(());
+		};
+		let result = Value::from(map! {
+			"namespace".to_string() => Value::from(ns),
+			"expunge".to_string() => Value::from(self.expunge),
+			"timestamp".to_string() => Value::from(Datetime::default()),
+			"actor".to_string() => Value::from(map! {
+				"id".to_string() => Value::from(opt.auth.id()),
+				"level".to_string() => Value::from(opt.auth.level().to_string()),
+			}),
+		});
+		for db in txn.all_db(ns).await?.iter() {
+			for tb in txn.all_tb(ns, &db.name, None).await?.iter() {
+				for lv in txn.all_tb_lives(ns, &db.name, &tb.name).await?.iter() {
+					// Only live queries on this node are notified
+					if opt.id()? != lv.node.0 {
+						continue;
+					}
+					let notification = Notification::new(
+						lv.id,
+						dbs::Action::Remove,
+						Value::from(ns),
+						result.clone(),
+					);
+					if chn.send(notification).await.is_err() {
+						// The channel was closed, so nothing can be sent
+						return Ok(());
+					}
+				}
+			}
+		}
+		Ok(())
+	}
+	/// Count the resources defined within the namespace
+	async fn detail(&self, txn: &Transaction, ns: &str) -> Result<Value, Error> {
+		let dbs = txn.all_db(ns).await?;
+		let mut tables = 0;
+		for db in dbs.iter() {
+			tables += txn.all_tb(ns, &db.name, None).await?.len();
+		}
+		Ok(Value::from(map! {
+			"accesses".to_string() => Value::from(txn.all_ns_accesses(ns).await?.len()),
+			"databases".to_string() => Value::from(dbs.len()),
+			"tables".to_string() => Value::from(tables),
+			"users".to_string() => Value::from(txn.all_ns_users(ns).await?.len()),
+		}))
+	}
+	/// List the resources which would be removed with the namespace
+	async fn dry_run(&self, txn: &Transaction, ns: &str) -> Result<Value, Error> {
+		let dbs = txn.all_db(ns).await?;
+		let mut tables = Object::default();
+		for db in dbs.iter() {
+			let tbs = txn.all_tb(ns, &db.name, None).await?;
+			tables.insert(
+				db.name.to_raw(),
+				tbs.iter().map(|v| Value::from(v.name.to_raw())).collect(),
+			);
+		}
+		Ok(Value::from(map! {
+			"accesses".to_string() => txn.all_ns_accesses(ns).await?.iter().map(|v| Value::from(v.name.to_raw())).collect(),
+			"databases".to_string() => dbs.iter().map(|v| Value::from(v.name.to_raw())).collect(),
+			"namespace".to_string() => Value::from(ns),
+			"tables".to_string() => Value::from(tables),
+			"users".to_string() => txn.all_ns_users(ns).await?.iter().map(|v| Value::from(v.name.to_raw())).collect(),
+		}))
+	}
 }
 
 impl Display for RemoveNamespaceStatement {
@@ -75,6 +196,18 @@ impl Display for RemoveNamespaceStatement {
 			write!(f, " IF EXISTS")?
 		}
 		write!(f, " {}", self.name)?;
+		if self.detail {
+			write!(f, " DETAIL")?
+		}
+		if self.report {
+			write!(f, " REPORT")?
+		}
+		if self.dry_run {
+			write!(f, " DRY")?
+		}
+		if self.concurrently {
+			write!(f, " CONCURRENTLY")?
+		}
 		Ok(())
 	}
 }
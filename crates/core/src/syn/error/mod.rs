@@ -8,7 +8,7 @@ pub use location::Location;
 pub(crate) use mac::{bail, syntax_error};
 pub use render::{RenderedError, Snippet};
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum MessageKind {
 	Suggestion,
 	Error,
@@ -129,10 +129,9 @@ impl SyntaxError {
 				ref label,
 				ref kind,
 			} => {
-				let locations = Location::range_of_span(source, *span);
-				let snippet = Snippet::from_source_location_range(
+				let snippet = Snippet::from_source_span(
 					source,
-					locations,
+					*span,
 					label.as_ref().map(|x| x.as_str()),
 					*kind,
 				);
@@ -3,6 +3,7 @@
 use std::{cmp::Ordering, fmt, ops::Range};
 
 use super::{Location, MessageKind};
+use crate::syn::token::Span;
 
 #[derive(Clone, Debug)]
 #[non_exhaustive]
@@ -76,9 +77,9 @@ pub struct Snippet {
 	/// A possible explanation for this snippet.
 	label: Option<String>,
 	/// The kind of snippet,
-	// Unused for now but could in the future be used to color snippets.
-	#[allow(dead_code)]
 	kind: MessageKind,
+	/// The span in the original source code, if the snippet was created from one.
+	span: Option<Span>,
 }
 
 impl Snippet {
@@ -104,6 +105,7 @@ impl Snippet {
 			length: 1,
 			label: explain.map(|x| x.into()),
 			kind,
+			span: None,
 		}
 	}
 
@@ -128,9 +130,47 @@ impl Snippet {
 			length,
 			label: explain.map(|x| x.into()),
 			kind,
+			span: None,
 		}
 	}
 
+	pub fn from_source_span(
+		source: &str,
+		span: Span,
+		explain: Option<&str>,
+		kind: MessageKind,
+	) -> Self {
+		let location = Location::range_of_span(source, span);
+		Snippet {
+			span: Some(span),
+			..Self::from_source_location_range(source, location, explain, kind)
+		}
+	}
+
+	/// The span of the source code this snippet points to.
+	///
+	/// The span is in bytes relative to the start of the source the error was rendered on and is
+	/// unaffected by [`RenderedError::offset_location`]. Returns `None` if the snippet was not
+	/// created from a span.
+	pub fn span(&self) -> Option<Span> {
+		self.span
+	}
+
+	/// The human readable location of the start of the snippet.
+	pub fn location(&self) -> Location {
+		self.location
+	}
+
+	/// The kind of message this snippet belongs to.
+	pub fn kind(&self) -> MessageKind {
+		self.kind
+	}
+
+	/// The explanation for this snippet, if any.
+	pub fn label(&self) -> Option<&str> {
+		self.label.as_deref()
+	}
+
 	/// Trims whitespace of an line and additionally truncates the string around the target_col_offset if it is too long.
 	///
 	/// returns the trimmed string, how it is truncated, and the offset into truncated the string where the target_col is located.
@@ -344,6 +384,7 @@ mod test {
 				length: 5,
 				label: Some("this is wrong".to_owned()),
 				kind: MessageKind::Error,
+				span: None,
 			}],
 		};
 
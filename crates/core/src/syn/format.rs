@@ -0,0 +1,182 @@
+//! Module implementing a formatter for SurrealQL queries.
+//!
+//! The formatter builds on the pretty printing [`Display`](std::fmt::Display) implementations of
+//! the AST. The pretty printed output of every statement is lexed and then reassembled with the
+//! requested indentation, keyword casing and clause breaking applied. As the formatter only ever
+//! changes whitespace between tokens and the case of keywords, the output parses back into the
+//! same AST. Each formatted statement is checked against this guarantee, falling back to only
+//! changing whitespace if changing the keyword case would change the meaning of the statement.
+
+use super::{
+	lexer::keywords::KEYWORDS,
+	parse,
+	token::{t, TokenKind},
+	tokenize,
+};
+use crate::sql::{Query, Statement, Statements};
+use unicase::UniCase;
+
+/// The casing applied to keywords by [`format`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum KeywordCase {
+	/// Write keywords in upper case, `SELECT * FROM foo`.
+	#[default]
+	Upper,
+	/// Write keywords in lower case, `select * from foo`.
+	Lower,
+}
+
+/// Options controlling the output of [`format`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct FormatOptions {
+	/// The amount of spaces used for a single level of indentation.
+	pub indent: usize,
+	/// The casing applied to keywords.
+	pub keyword_case: KeywordCase,
+	/// Whether to place each clause of a `SELECT` statement, like `FROM`, `WHERE` or `FETCH`, on
+	/// its own line when the statement does not fit within [`FormatOptions::max_width`].
+	pub break_clauses: bool,
+	/// The width after which a statement is considered too long to fit on a single line.
+	pub max_width: usize,
+}
+
+impl Default for FormatOptions {
+	fn default() -> Self {
+		FormatOptions {
+			indent: 4,
+			keyword_case: KeywordCase::Upper,
+			break_clauses: true,
+			max_width: 80,
+		}
+	}
+}
+
+impl FormatOptions {
+	pub fn with_indent(mut self, indent: usize) -> Self {
+		self.indent = indent;
+		self
+	}
+
+	pub fn with_keyword_case(mut self, keyword_case: KeywordCase) -> Self {
+		self.keyword_case = keyword_case;
+		self
+	}
+
+	pub fn with_break_clauses(mut self, break_clauses: bool) -> Self {
+		self.break_clauses = break_clauses;
+		self
+	}
+
+	pub fn with_max_width(mut self, max_width: usize) -> Self {
+		self.max_width = max_width;
+		self
+	}
+}
+
+/// Keywords starting a clause of a `SELECT` statement.
+const CLAUSE_KEYWORDS: &[&str] = &[
+	"OMIT", "FROM", "WITH", "WHERE", "SPLIT", "GROUP", "ORDER", "LIMIT", "START", "FETCH",
+	"VERSION", "TIMEOUT", "PARALLEL", "EXPLAIN",
+];
+
+/// Formats a [`Query`] according to the given options.
+///
+/// Every statement is placed on its own line(s), terminated by a `;`.
+pub fn format(query: &Query, opts: FormatOptions) -> String {
+	let mut res = String::new();
+	for (i, stmt) in query.0 .0.iter().enumerate() {
+		if i > 0 {
+			res.push('\n');
+		}
+		res.push_str(&format_statement(stmt, &opts));
+		res.push(';');
+	}
+	res
+}
+
+fn format_statement(stmt: &Statement, opts: &FormatOptions) -> String {
+	let source = format!("{stmt:#}");
+	let expected = Query(Statements(vec![stmt.clone()]));
+	if let Some(x) = reformat(&source, opts, true) {
+		if parse(&x).is_ok_and(|x| x == expected) {
+			return x;
+		}
+	}
+	// Changing keyword case changed the meaning of the statement, only change whitespace.
+	if let Some(x) = reformat(&source, opts, false) {
+		if parse(&x).is_ok_and(|x| x == expected) {
+			return x;
+		}
+	}
+	source
+}
+
+/// Reassembles the source from its tokens, returns none if the source could not be lexed.
+fn reformat(source: &str, opts: &FormatOptions, change_case: bool) -> Option<String> {
+	let tokens = tokenize(source).ok()?;
+	let indent = " ".repeat(opts.indent);
+	let is_select = source.trim_start().starts_with("SELECT");
+	let break_clauses = opts.break_clauses
+		&& is_select
+		&& source.lines().any(|x| x.chars().count() > opts.max_width);
+
+	let mut res = String::with_capacity(source.len());
+	let mut last = 0;
+	let mut depth = 0usize;
+	for (i, token) in tokens.iter().enumerate() {
+		let start = token.span.offset as usize;
+		let end = start + token.span.len as usize;
+		let text = &source[start..end];
+		let gap = &source[last..start];
+		last = end;
+
+		let is_keyword = !matches!(token.kind, TokenKind::Identifier)
+			&& KEYWORDS.contains_key(&UniCase::ascii(text));
+		// Keywords can also be used as function names, object keys, or fields, in which case
+		// their case is significant.
+		let is_name = i
+			.checked_sub(1)
+			.and_then(|x| tokens.get(x))
+			.is_some_and(|x| matches!(x.kind, t!("::") | t!(".")))
+			|| tokens.get(i + 1).is_some_and(|x| matches!(x.kind, t!("::") | t!(":") | t!(".")));
+
+		if break_clauses
+			&& depth == 0
+			&& is_keyword
+			&& !gap.is_empty()
+			&& CLAUSE_KEYWORDS.iter().any(|x| x.eq_ignore_ascii_case(text))
+		{
+			res.push('\n');
+			res.push_str(&indent);
+		} else if is_select && depth == 0 && !gap.is_empty() {
+			// The clauses of a select statement are written on a single line unless broken up.
+			res.push(' ');
+		} else {
+			for c in gap.chars() {
+				if c == '\t' {
+					res.push_str(&indent);
+				} else {
+					res.push(c);
+				}
+			}
+		}
+
+		if change_case && is_keyword && !is_name {
+			match opts.keyword_case {
+				KeywordCase::Upper => res.push_str(&text.to_ascii_uppercase()),
+				KeywordCase::Lower => res.push_str(&text.to_ascii_lowercase()),
+			}
+		} else {
+			res.push_str(text);
+		}
+
+		match token.kind {
+			TokenKind::OpenDelim(_) => depth += 1,
+			TokenKind::CloseDelim(_) => depth = depth.saturating_sub(1),
+			_ => {}
+		}
+	}
+	res.push_str(&source[last..].replace('\t', &indent));
+	Some(res)
+}
@@ -2,7 +2,7 @@ use crate::syn::{
 	error::{bail, syntax_error, SyntaxError},
 	lexer::{
 		unicode::{byte, chars},
-		Lexer,
+		CommentKind, Lexer,
 	},
 	token::{t, Token, TokenKind},
 };
@@ -10,17 +10,18 @@ use crate::syn::{
 impl Lexer<'_> {
 	/// Eats a single line comment.
 	pub(super) fn eat_single_line_comment(&mut self) {
-		loop {
+		let end = loop {
+			let end = self.reader.offset();
 			let Some(byte) = self.reader.next() else {
-				break;
+				break end;
 			};
 			match byte {
 				byte::CR => {
 					self.eat(byte::LF);
-					break;
+					break end;
 				}
 				byte::LF => {
-					break;
+					break end;
 				}
 				x if !x.is_ascii() => {
 					// -1 because we already ate the byte.
@@ -30,18 +31,19 @@ impl Lexer<'_> {
 						Err(_) => {
 							// let the next token handle the error.
 							self.reader.backup(backup);
-							break;
+							break end;
 						}
 					};
 
 					match char {
-						chars::LS | chars::PS | chars::NEL => break,
+						chars::LS | chars::PS | chars::NEL => break end,
 						_ => {}
 					}
 				}
 				_ => {}
 			}
-		}
+		};
+		self.push_comment(CommentKind::Line, end);
 	}
 
 	/// Eats a multi line comment and returns an error if `*/` would be missing.
@@ -57,6 +59,7 @@ impl Lexer<'_> {
 				};
 				if b'/' == byte {
 					self.reader.next();
+					self.push_comment(CommentKind::Block, self.reader.offset());
 					return Ok(());
 				}
 			}
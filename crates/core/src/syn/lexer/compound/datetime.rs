@@ -79,14 +79,13 @@ pub fn datetime_inner(lexer: &mut Lexer) -> Result<DateTime<Utc>, SyntaxError> {
 				break;
 			}
 
-			if count == 9 {
-				bail!("Invalid datetime nanoseconds, expected no more then 9 digits", @lexer.span_since(nanos_start))
-			}
-
 			lexer.reader.next();
-			number *= 10;
-			number += (d - b'0') as u32;
-			count += 1;
+			// Precision beyond nanoseconds is truncated.
+			if count < 9 {
+				number *= 10;
+				number += (d - b'0') as u32;
+				count += 1;
+			}
 		}
 
 		if count == 0 {
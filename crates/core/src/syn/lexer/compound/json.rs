@@ -0,0 +1,247 @@
+use std::collections::BTreeMap;
+
+use crate::{
+	sql::{Array, Number, Object, Strand, Value},
+	syn::{
+		error::{bail, syntax_error, SyntaxError},
+		lexer::{unicode::chars, Lexer},
+		token::Span,
+	},
+};
+
+use super::strand::lex_unicode_sequence;
+
+/// Lexes a complete value which must be valid RFC 8259 JSON.
+///
+/// Unlike JSON parsed with the SurrealQL parser this does not allow comments, trailing commas,
+/// unquoted object keys, single quoted strings, or any SurrealQL values like record ids or
+/// durations. `depth` limits how deeply arrays and objects may be nested.
+pub fn json_strict(lexer: &mut Lexer, depth: usize) -> Result<Value, SyntaxError> {
+	let value = lex_json_value(lexer, depth)?;
+	eat_json_whitespace(lexer);
+	if lexer.reader.peek().is_some() {
+		let offset = lexer.reader.offset();
+		let char = next_char(lexer)?;
+		bail!("Unexpected character `{char}` after JSON value", @lexer.span_since(offset));
+	}
+	Ok(value)
+}
+
+fn lex_json_value(lexer: &mut Lexer, depth: usize) -> Result<Value, SyntaxError> {
+	eat_json_whitespace(lexer);
+	let offset = lexer.reader.offset();
+	match lexer.reader.peek() {
+		None => {
+			let err = syntax_error!("Unexpected end of file, expected a JSON value", @lexer.span_since(offset));
+			Err(err.with_data_pending())
+		}
+		Some(b'{') => {
+			lexer.reader.next();
+			lex_json_object(lexer, offset, depth).map(Value::Object)
+		}
+		Some(b'[') => {
+			lexer.reader.next();
+			lex_json_array(lexer, offset, depth).map(Value::Array)
+		}
+		Some(b'"') => {
+			lexer.reader.next();
+			lex_json_string(lexer).map(|x| Value::Strand(Strand(x)))
+		}
+		Some(b'-' | b'0'..=b'9') => lex_json_number(lexer).map(Value::Number),
+		Some(b't') => lex_json_literal(lexer, "true", Value::Bool(true)),
+		Some(b'f') => lex_json_literal(lexer, "false", Value::Bool(false)),
+		Some(b'n') => lex_json_literal(lexer, "null", Value::Null),
+		Some(_) => {
+			let char = next_char(lexer)?;
+			bail!("Unexpected character `{char}`, expected a JSON value", @lexer.span_since(offset))
+		}
+	}
+}
+
+fn lex_json_object(lexer: &mut Lexer, start: usize, depth: usize) -> Result<Object, SyntaxError> {
+	let Some(depth) = depth.checked_sub(1) else {
+		bail!("Exceeded the maximum nesting depth of JSON values", @lexer.span_since(start));
+	};
+	let mut obj = BTreeMap::new();
+	eat_json_whitespace(lexer);
+	if lexer.eat(b'}') {
+		return Ok(Object(obj));
+	}
+	loop {
+		eat_json_whitespace(lexer);
+		let offset = lexer.reader.offset();
+		match lexer.reader.peek() {
+			Some(b'"') => {
+				lexer.reader.next();
+			}
+			Some(b'}') => {
+				lexer.reader.next();
+				bail!("Trailing commas are not allowed in JSON objects", @lexer.span_since(offset));
+			}
+			_ => {
+				next_char(lexer)?;
+				bail!("Unexpected character, expected a double quoted JSON object key", @lexer.span_since(offset));
+			}
+		}
+		let key = lex_json_string(lexer)?;
+		eat_json_whitespace(lexer);
+		lexer.expect(':')?;
+		let value = lex_json_value(lexer, depth)?;
+		obj.insert(key, value);
+		eat_json_whitespace(lexer);
+		if !lexer.eat(b',') {
+			expect_closing_delimiter(lexer, '}', start)?;
+			return Ok(Object(obj));
+		}
+	}
+}
+
+fn lex_json_array(lexer: &mut Lexer, start: usize, depth: usize) -> Result<Array, SyntaxError> {
+	let Some(depth) = depth.checked_sub(1) else {
+		bail!("Exceeded the maximum nesting depth of JSON values", @lexer.span_since(start));
+	};
+	let mut array = Vec::new();
+	eat_json_whitespace(lexer);
+	if lexer.eat(b']') {
+		return Ok(Array(array));
+	}
+	loop {
+		eat_json_whitespace(lexer);
+		let offset = lexer.reader.offset();
+		if lexer.eat(b']') {
+			bail!("Trailing commas are not allowed in JSON arrays", @lexer.span_since(offset));
+		}
+		array.push(lex_json_value(lexer, depth)?);
+		eat_json_whitespace(lexer);
+		if !lexer.eat(b',') {
+			expect_closing_delimiter(lexer, ']', start)?;
+			return Ok(Array(array));
+		}
+	}
+}
+
+/// Lexes a JSON string, expects the opening `"` to already be consumed.
+fn lex_json_string(lexer: &mut Lexer) -> Result<String, SyntaxError> {
+	let start = lexer.reader.offset() - 1;
+	let mut res = String::new();
+	loop {
+		let offset = lexer.reader.offset();
+		let Some(x) = lexer.reader.next() else {
+			let err = syntax_error!("Unexpected end of file, expected string to end", @lexer.span_since(start));
+			return Err(err.with_data_pending());
+		};
+		match x {
+			b'"' => return Ok(res),
+			b'\\' => match lexer.reader.next() {
+				Some(b'"') => res.push('"'),
+				Some(b'\\') => res.push('\\'),
+				Some(b'/') => res.push('/'),
+				Some(b'b') => res.push(chars::BS),
+				Some(b'f') => res.push(chars::FF),
+				Some(b'n') => res.push(chars::LF),
+				Some(b'r') => res.push(chars::CR),
+				Some(b't') => res.push(chars::TAB),
+				Some(b'u') if lexer.reader.peek() != Some(b'{') => {
+					res.push(lex_unicode_sequence(lexer)?);
+				}
+				_ => {
+					bail!("Invalid escape sequence, valid characters are `\"`, `\\`, `/`, `b`, `f`, `n`, `r`, `t`, or `u`", @lexer.span_since(offset))
+				}
+			},
+			0x00..=0x1f => {
+				bail!("Control characters must be escaped in JSON strings", @lexer.span_since(offset))
+			}
+			x if x.is_ascii() => res.push(x as char),
+			x => res.push(lexer.reader.complete_char(x)?),
+		}
+	}
+}
+
+fn lex_json_number(lexer: &mut Lexer) -> Result<Number, SyntaxError> {
+	let start = lexer.reader.offset();
+	lexer.eat(b'-');
+	// Integer part, without leading zeros
+	match lexer.reader.next() {
+		Some(b'0') => {}
+		Some(b'1'..=b'9') => eat_json_digits(lexer),
+		_ => bail!("Invalid JSON number, expected a digit", @lexer.span_since(start)),
+	}
+	let mut float = false;
+	// Optional fraction
+	if lexer.eat(b'.') {
+		float = true;
+		if !lexer.eat_when(|x| x.is_ascii_digit()) {
+			bail!("Invalid JSON number, expected a digit after the decimal point", @lexer.span_since(start));
+		}
+		eat_json_digits(lexer);
+	}
+	// Optional exponent
+	if lexer.eat_when(|x| x == b'e' || x == b'E') {
+		float = true;
+		lexer.eat_when(|x| x == b'+' || x == b'-');
+		if !lexer.eat_when(|x| x.is_ascii_digit()) {
+			bail!("Invalid JSON number, expected a digit in the exponent", @lexer.span_since(start));
+		}
+		eat_json_digits(lexer);
+	}
+	let span = lexer.span_since(start);
+	let text = lexer.span_str(span);
+	if !float {
+		if let Ok(x) = text.parse::<i64>() {
+			return Ok(Number::Int(x));
+		}
+	}
+	text.parse::<f64>().map(Number::Float).map_err(|_| syntax_error!("Invalid JSON number", @span))
+}
+
+fn lex_json_literal(lexer: &mut Lexer, literal: &str, value: Value) -> Result<Value, SyntaxError> {
+	let start = lexer.reader.offset();
+	if lexer.reader.remaining().starts_with(literal.as_bytes()) {
+		for _ in 0..literal.len() {
+			lexer.reader.next();
+		}
+		return Ok(value);
+	}
+	while lexer.eat_when(|x| x.is_ascii_alphanumeric() || x == b'_') {}
+	let span = lexer.span_since(start);
+	bail!("Unexpected token `{}`, expected a JSON value", lexer.span_str(span), @span)
+}
+
+fn expect_closing_delimiter(lexer: &mut Lexer, c: char, start: usize) -> Result<(), SyntaxError> {
+	let offset = lexer.reader.offset();
+	match lexer.reader.peek() {
+		Some(x) if x == c as u8 => {
+			lexer.reader.next();
+			Ok(())
+		}
+		Some(_) => {
+			let char = next_char(lexer)?;
+			bail!("Unexpected character `{char}` expected `,` or `{c}`",
+				@lexer.span_since(offset),
+				@Span { offset: start as u32, len: 1 } => "expected this delimiter to close"
+			)
+		}
+		None => {
+			let err = syntax_error!("Unexpected end of file, expected `,` or `{c}`",
+				@lexer.span_since(offset),
+				@Span { offset: start as u32, len: 1 } => "expected this delimiter to close"
+			);
+			Err(err.with_data_pending())
+		}
+	}
+}
+
+fn eat_json_digits(lexer: &mut Lexer) {
+	while lexer.eat_when(|x| x.is_ascii_digit()) {}
+}
+
+fn eat_json_whitespace(lexer: &mut Lexer) {
+	while lexer.eat_when(|x| matches!(x, b' ' | b'\t' | b'\n' | b'\r')) {}
+}
+
+fn next_char(lexer: &mut Lexer) -> Result<char, SyntaxError> {
+	let Some(x) = lexer.reader.next() else {
+		bail!("Unexpected end of file", @lexer.current_span());
+	};
+	Ok(lexer.reader.convert_to_char(x)?)
+}
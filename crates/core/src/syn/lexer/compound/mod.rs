@@ -9,6 +9,7 @@ mod datetime;
 mod file;
 mod ident;
 mod js;
+mod json;
 mod number;
 mod regex;
 mod strand;
@@ -19,6 +20,7 @@ pub use datetime::{datetime, datetime_inner};
 pub use file::file;
 pub use ident::flexible_ident;
 pub use js::javascript;
+pub use json::json_strict;
 pub use number::{
 	duration, float, integer, number, numeric, numeric_kind, NumberKind, Numeric, NumericKind,
 };
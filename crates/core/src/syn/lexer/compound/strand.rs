@@ -106,7 +106,7 @@ pub fn strand(lexer: &mut Lexer, start: Token) -> Result<String, SyntaxError> {
 const LEADING_SURROGATES: RangeInclusive<u16> = 0xD800..=0xDBFF;
 const TRAILING_SURROGATES: RangeInclusive<u16> = 0xDC00..=0xDFFF;
 
-fn lex_unicode_sequence(lexer: &mut Lexer) -> Result<char, SyntaxError> {
+pub(super) fn lex_unicode_sequence(lexer: &mut Lexer) -> Result<char, SyntaxError> {
 	if let Some(b'{') = lexer.reader.peek() {
 		lexer.reader.next();
 		return lex_bracket_unicode_sequence(lexer);
@@ -3,6 +3,7 @@ use crate::{
 	syn::token::{DistanceKind, Keyword, TokenKind, VectorTypeKind},
 };
 use phf::{phf_map, phf_set};
+use std::sync::LazyLock;
 use unicase::UniCase;
 
 /// A set of keywords which might in some contexts are dissallowed as an identifier.
@@ -50,10 +51,23 @@ pub static RESERVED_KEYWORD: phf::Set<UniCase<&'static str>> = phf_set! {
 	UniCase::ascii("TABLE"),
 };
 
+/// A sorted list of the keywords within [`RESERVED_KEYWORD`].
+static RESERVED_KEYWORD_LIST: LazyLock<Vec<&'static str>> = LazyLock::new(|| {
+	let mut res: Vec<_> = RESERVED_KEYWORD.iter().map(|x| x.into_inner()).collect();
+	res.sort_unstable();
+	res
+});
+
+/// Returns if the string matches a reserved keyword, ignoring ascii case.
 pub fn could_be_reserved(s: &str) -> bool {
 	RESERVED_KEYWORD.contains(&UniCase::ascii(s))
 }
 
+/// Returns the list of reserved keywords in upper case, sorted alphabetically.
+pub fn reserved() -> &'static [&'static str] {
+	&RESERVED_KEYWORD_LIST
+}
+
 /// A map for mapping keyword strings to a tokenkind,
 pub(crate) static KEYWORDS: phf::Map<UniCase<&'static str>, TokenKind> = phf_map! {
 	// Keywords
@@ -105,6 +119,7 @@ pub(crate) static KEYWORDS: phf::Map<UniCase<&'static str>, TokenKind> = phf_map
 	UniCase::ascii("DELETE") => TokenKind::Keyword(Keyword::Delete),
 	UniCase::ascii("DESCENDING") => TokenKind::Keyword(Keyword::Descending),
 	UniCase::ascii("DESC") => TokenKind::Keyword(Keyword::Descending),
+	UniCase::ascii("DETAIL") => TokenKind::Keyword(Keyword::Detail),
 	UniCase::ascii("DIFF") => TokenKind::Keyword(Keyword::Diff),
 	UniCase::ascii("DIMENSION") => TokenKind::Keyword(Keyword::Dimension),
 	UniCase::ascii("DISTANCE") => TokenKind::Keyword(Keyword::Distance),
@@ -114,6 +129,7 @@ pub(crate) static KEYWORDS: phf::Map<UniCase<&'static str>, TokenKind> = phf_map
 	UniCase::ascii("DOC_LENGTHS_CACHE") => TokenKind::Keyword(Keyword::DocLengthsCache),
 	UniCase::ascii("DOC_LENGTHS_ORDER") => TokenKind::Keyword(Keyword::DocLengthsOrder),
 	UniCase::ascii("DROP") => TokenKind::Keyword(Keyword::Drop),
+	UniCase::ascii("DRY") => TokenKind::Keyword(Keyword::Dry),
 	UniCase::ascii("DUPLICATE") => TokenKind::Keyword(Keyword::Duplicate),
 	UniCase::ascii("EDGENGRAM") => TokenKind::Keyword(Keyword::Edgengram),
 	UniCase::ascii("EFC") => TokenKind::Keyword(Keyword::Efc),
@@ -216,6 +232,7 @@ pub(crate) static KEYWORDS: phf::Map<UniCase<&'static str>, TokenKind> = phf_map
 	UniCase::ascii("ROLES") => TokenKind::Keyword(Keyword::Roles),
 	UniCase::ascii("ROOT") => TokenKind::Keyword(Keyword::Root),
 	UniCase::ascii("KV") => TokenKind::Keyword(Keyword::Root),
+	UniCase::ascii("SAVEPOINT") => TokenKind::Keyword(Keyword::Savepoint),
 	UniCase::ascii("SCHEMAFULL") => TokenKind::Keyword(Keyword::Schemafull),
 	UniCase::ascii("SCHEMAFUL") => TokenKind::Keyword(Keyword::Schemafull),
 	UniCase::ascii("SCHEMALESS") => TokenKind::Keyword(Keyword::Schemaless),
@@ -53,6 +53,26 @@ pub struct Lexer<'a> {
 	// actual number value to when the parser can decide on a format.
 	pub(super) string: Option<String>,
 	pub(super) error: Option<SyntaxError>,
+	/// The comments lexed so far, only collected if comments are retained.
+	comments: Option<Vec<Comment>>,
+}
+
+/// A comment in the source passed to the lexer.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub struct Comment {
+	pub kind: CommentKind,
+	/// The location of the comment, including its delimiters but excluding the new-line ending a
+	/// line comment.
+	pub span: Span,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum CommentKind {
+	/// A comment until the end of the line, starting with `--`, `//` or `#`.
+	Line,
+	/// A comment delimited by `/*` and `*/`, which can span multiple lines.
+	Block,
 }
 
 impl<'a> Lexer<'a> {
@@ -68,6 +88,40 @@ impl<'a> Lexer<'a> {
 			scratch: String::new(),
 			string: None,
 			error: None,
+			comments: None,
+		}
+	}
+
+	/// Set whether the comments in the source are retained, to be taken with
+	/// [`Lexer::take_comments`]. Comments are discarded by default.
+	pub fn retain_comments(&mut self, retain: bool) {
+		self.comments = retain.then(Vec::new);
+	}
+
+	/// Takes the comments lexed so far, in the order they appear in the source.
+	pub fn take_comments(&mut self) -> Vec<Comment> {
+		self.comments.as_mut().map(std::mem::take).unwrap_or_default()
+	}
+
+	/// Records a comment starting at the start of the current token and ending at the given
+	/// offset, if comments are retained.
+	fn push_comment(&mut self, kind: CommentKind, end: usize) {
+		let span = Span {
+			offset: self.last_offset,
+			len: end as u32 - self.last_offset,
+		};
+		if let Some(comments) = self.comments.as_mut() {
+			comments.push(Comment {
+				kind,
+				span,
+			});
+		}
+	}
+
+	/// Forgets the comments at or after the given offset, which will be lexed again.
+	fn truncate_comments(&mut self, offset: u32) {
+		if let Some(comments) = self.comments.as_mut() {
+			comments.retain(|c| c.span.offset < offset);
 		}
 	}
 
@@ -96,6 +150,7 @@ impl<'a> Lexer<'a> {
 			scratch: self.scratch,
 			string: self.string,
 			error: self.error,
+			comments: self.comments.map(|_| Vec::new()),
 		}
 	}
 
@@ -177,6 +232,7 @@ impl<'a> Lexer<'a> {
 	pub(crate) fn backup_before(&mut self, span: Span) {
 		self.reader.backup(span.offset as usize);
 		self.last_offset = span.offset;
+		self.truncate_comments(span.offset);
 	}
 
 	/// Moves the lexer state to after the give span.
@@ -188,6 +244,7 @@ impl<'a> Lexer<'a> {
 		let offset = span.offset + span.len;
 		self.reader.backup(offset as usize);
 		self.last_offset = offset;
+		self.truncate_comments(offset);
 	}
 
 	/// Checks if the next byte is the given byte, if it is it consumes the byte and returns true.
@@ -4,10 +4,14 @@ use crate::{
 	cnf::{MAX_OBJECT_PARSING_DEPTH, MAX_QUERY_PARSING_DEPTH},
 	dbs::{capabilities::ExperimentalTarget, Capabilities},
 	err::Error,
-	sql::{Block, Datetime, Duration, Idiom, Kind, Query, Range, Subquery, Thing, Value},
+	sql::{
+		statements::FunctionSignature, Block, Cond, Datetime, Duration, Fields, Geometry, Idiom,
+		Kind, Param, Permissions, Query, Range, Statement, Statements, Subquery, Thing, Value,
+	},
 };
 
 pub mod error;
+mod format;
 pub mod lexer;
 pub mod parser;
 pub mod token;
@@ -20,18 +24,206 @@ pub trait Parse<T> {
 #[cfg(test)]
 mod test;
 
-use lexer::{compound, Lexer};
-use parser::{Parser, ParserSettings};
+pub use format::{format, FormatOptions, KeywordCase};
+
+use bytes::BytesMut;
+use lexer::{compound, Comment, Lexer};
+use parser::{Parser, ParserSettings, StatementStream};
 use reblessive::Stack;
-use token::t;
+use std::collections::BTreeMap;
+use std::io::{self, Read};
+use std::ops;
+use token::{t, Glued, QouteKind, TokenKind};
 
 const TARGET: &str = "surrealdb::core::syn";
 
 /// Takes a string and returns if it could be a reserved keyword in certain contexts.
+///
+/// Keywords are matched the same way the lexer matches them, ignoring the case of ascii
+/// characters only. So `select` and `SeLeCt` both match but a string which only lowers to a
+/// keyword under unicode case folding does not. See [`could_be_reserved_keyword_ci`] for that.
 pub fn could_be_reserved_keyword(s: &str) -> bool {
 	lexer::keywords::could_be_reserved(s)
 }
 
+/// Takes a string and returns if it could be a reserved keyword in certain contexts, after
+/// converting it to lowercase with full unicode case rules.
+///
+/// This is stricter than [`could_be_reserved_keyword`]: it also reports strings like `\u{212A}ILL`
+/// (starting with a Kelvin sign) which the lexer would not treat as a keyword but which look
+/// like one once lowercased. Useful when validating identifiers which might later be normalized.
+pub fn could_be_reserved_keyword_ci(s: &str) -> bool {
+	lexer::keywords::could_be_reserved(&s.to_lowercase())
+}
+
+/// Returns the list of all keywords which could be reserved in certain contexts, in upper case
+/// and sorted alphabetically.
+pub fn reserved_keywords() -> &'static [&'static str] {
+	lexer::keywords::reserved()
+}
+
+/// Lexes a string into a list of SurrealQL [`token::Token`]s.
+///
+/// Whitespace and comments are skipped. String-like literals, for example `"foo"` or `d"2024-01-01"`,
+/// are lexed in full and returned as a single [`token::TokenKind::Glued`] token spanning the
+/// literal including its quotes. Keywords are classified case-insensitively, identical to
+/// [`could_be_reserved_keyword`]. Note that the returned tokens are raw: compound tokens like
+/// numbers or durations are not combined, as doing so requires knowledge of the grammar.
+#[instrument(level = "trace", target = "surrealdb::core::syn", fields(length = input.len()))]
+pub fn tokenize(input: &str) -> Result<Vec<token::Token>, Error> {
+	trace!(target: TARGET, "Tokenizing SurrealQL");
+
+	let mut res = Vec::new();
+	for_each_token(input, |token| res.push(token))?;
+	Ok(res)
+}
+
+/// Lexes the input, calling `f` for every token in the same form as returned by [`tokenize`].
+fn for_each_token(input: &str, mut f: impl FnMut(token::Token)) -> Result<(), Error> {
+	if input.len() > u32::MAX as usize {
+		return Err(Error::QueryTooLarge);
+	}
+
+	let mut lexer = Lexer::new(input.as_bytes());
+	loop {
+		let token = lexer.next_token();
+		let token = match token.kind {
+			TokenKind::Eof => break,
+			TokenKind::WhiteSpace => continue,
+			TokenKind::Invalid => {
+				let error = lexer.error.take().expect("invalid token without an error");
+				return Err(Error::InvalidQuery(error.render_on(input)));
+			}
+			TokenKind::Qoute(kind) => {
+				let span = match kind {
+					QouteKind::Plain | QouteKind::PlainDouble => {
+						lexer.lex_compound(token, compound::strand).map(|x| (x.span, Glued::Strand))
+					}
+					QouteKind::RecordId | QouteKind::RecordIdDouble => {
+						// Record-id strings are lexed as a plain strand, the contents are only
+						// validated when parsing.
+						let start = token::Token {
+							kind: if kind == QouteKind::RecordId {
+								t!("'")
+							} else {
+								t!("\"")
+							},
+							..token
+						};
+						lexer.lex_compound(start, compound::strand).map(|x| (x.span, Glued::Strand))
+					}
+					QouteKind::Uuid | QouteKind::UuidDouble => {
+						lexer.lex_compound(token, compound::uuid).map(|x| (x.span, Glued::Uuid))
+					}
+					QouteKind::DateTime | QouteKind::DateTimeDouble => lexer
+						.lex_compound(token, compound::datetime)
+						.map(|x| (x.span, Glued::Datetime)),
+					QouteKind::Bytes | QouteKind::BytesDouble => {
+						lexer.lex_compound(token, compound::bytes).map(|x| (x.span, Glued::Bytes))
+					}
+					QouteKind::File | QouteKind::FileDouble => {
+						lexer.lex_compound(token, compound::file).map(|x| (x.span, Glued::File))
+					}
+				};
+				let (span, glued) =
+					span.map_err(|e| e.render_on(input)).map_err(Error::InvalidQuery)?;
+				token::Token {
+					kind: TokenKind::Glued(glued),
+					span,
+				}
+			}
+			_ => token,
+		};
+		f(token);
+	}
+	Ok(())
+}
+
+/// Rough measures of the complexity of a query, as returned by [`estimate_complexity`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct QueryStats {
+	/// The number of top-level statements.
+	pub statements: usize,
+	/// The number of statements nested within parentheses, like `(SELECT * FROM foo)`.
+	pub subqueries: usize,
+	/// The number of function and method calls, like `string::len($a)` or `$a.len()`.
+	pub functions: usize,
+	/// The deepest nesting of parentheses, brackets and braces.
+	pub depth: usize,
+}
+
+/// Estimates the complexity of a SurrealQL query without parsing it.
+///
+/// The input is only lexed, which is a lot cheaper than parsing, so this can be used to reject
+/// overly complex queries before handing them to the parser. The counts are based on the tokens
+/// alone and are therefore an estimate: an invalid query will still produce stats, and only lexer
+/// errors, like an unterminated string, are returned as an error.
+#[instrument(level = "trace", target = "surrealdb::core::syn", fields(length = input.len()))]
+pub fn estimate_complexity(input: &str) -> Result<QueryStats, Error> {
+	trace!(target: TARGET, "Estimating SurrealQL query complexity");
+
+	let mut stats = QueryStats::default();
+	let mut depth = 0usize;
+	let mut in_statement = false;
+	// The two previously seen tokens.
+	let mut prev = [None, None];
+	for_each_token(input, |token| {
+		match token.kind {
+			t!(";") if depth == 0 => in_statement = false,
+			_ if !in_statement && depth == 0 => {
+				in_statement = true;
+				stats.statements += 1;
+			}
+			_ => {}
+		}
+		match token.kind {
+			t!("(") | t!("[") | t!("{") => {
+				depth += 1;
+				stats.depth = stats.depth.max(depth);
+			}
+			t!(")") | t!("]") | t!("}") => depth = depth.saturating_sub(1),
+			t!("SELECT")
+			| t!("CREATE")
+			| t!("UPDATE")
+			| t!("UPSERT")
+			| t!("DELETE")
+			| t!("RELATE")
+			| t!("INSERT")
+			| t!("DEFINE")
+			| t!("REMOVE")
+			| t!("RETURN")
+			| t!("IF")
+				if prev[0] == Some(t!("(")) =>
+			{
+				stats.subqueries += 1;
+			}
+			_ => {}
+		}
+		if token.kind == t!("(")
+			&& (prev[0] == Some(TokenKind::Identifier) || prev[1] == Some(t!("::")))
+		{
+			stats.functions += 1;
+		}
+		prev = [Some(token.kind), prev[0]];
+	})?;
+	Ok(stats)
+}
+
+/// Returns the settings used by the parsing functions: the default recursion limits, with the
+/// experimental features which the given capabilities allow enabled.
+fn default_settings(capabilities: &Capabilities) -> ParserSettings {
+	ParserSettings {
+		object_recursion_limit: *MAX_OBJECT_PARSING_DEPTH as usize,
+		query_recursion_limit: *MAX_QUERY_PARSING_DEPTH as usize,
+		references_enabled: capabilities.allows_experimental(&ExperimentalTarget::RecordReferences),
+		bearer_access_enabled: capabilities.allows_experimental(&ExperimentalTarget::BearerAccess),
+		define_api_enabled: capabilities.allows_experimental(&ExperimentalTarget::DefineApi),
+		files_enabled: capabilities.allows_experimental(&ExperimentalTarget::Files),
+		..Default::default()
+	}
+}
+
 /// Parses a SurrealQL [`Query`]
 ///
 /// During query parsing, the total depth of calls to parse values (including arrays, expressions,
@@ -60,32 +252,284 @@ pub fn parse(input: &str) -> Result<Query, Error> {
 /// please [open an issue](https://github.com/surrealdb/surrealdb/issues)!
 #[instrument(level = "trace", target = "surrealdb::core::syn", fields(length = input.len()))]
 pub fn parse_with_capabilities(input: &str, capabilities: &Capabilities) -> Result<Query, Error> {
+	parse_with_limits(
+		input,
+		capabilities,
+		*MAX_OBJECT_PARSING_DEPTH as usize,
+		*MAX_QUERY_PARSING_DEPTH as usize,
+	)
+}
+
+/// Parses a SurrealQL [`Query`] with custom recursion limits.
+///
+/// `object_limit` bounds how deeply objects and arrays may be nested, while `query_limit` bounds
+/// how deeply statements may be nested within subqueries and blocks. Both limits must be non-zero.
+/// Exceeding either limit during parsing results in a parse error instead of overflowing the call
+/// stack.
+#[instrument(level = "trace", target = "surrealdb::core::syn", fields(length = input.len()))]
+pub fn parse_with_limits(
+	input: &str,
+	capabilities: &Capabilities,
+	object_limit: usize,
+	query_limit: usize,
+) -> Result<Query, Error> {
+	parse_with_settings(
+		input,
+		ParserSettings {
+			object_recursion_limit: object_limit,
+			query_recursion_limit: query_limit,
+			..default_settings(capabilities)
+		},
+	)
+}
+
+/// Parses a SurrealQL [`Query`] with the given [`ParserSettings`].
+///
+/// This gives full control over the parser, which is mostly useful for tooling. For example,
+/// setting [`ParserSettings::legacy_strands`] parses strings the way SurrealDB 1.x did: a string
+/// literal which looks like a record id, like `'person:tobie'`, is parsed as that value instead of
+/// as a string. This allows importing old exports which relied on that
+/// behaviour, but changes the meaning of any string which happens to look like such a value, so it
+/// should not be used for new queries.
+///
+/// Experimental features are enabled through the settings, not through [`Capabilities`]. Both
+/// recursion limits must be non-zero.
+#[instrument(level = "trace", target = "surrealdb::core::syn", fields(length = input.len()))]
+pub fn parse_with_settings(input: &str, settings: ParserSettings) -> Result<Query, Error> {
 	trace!(target: TARGET, "Parsing SurrealQL query");
 
+	parse_with_settings_inner(input, settings).map(|(query, _)| query)
+}
+
+/// Parses a SurrealQL [`Query`] with the given [`ParserSettings`] like [`parse_with_settings`],
+/// returning it together with the comments in the input, which are otherwise discarded.
+///
+/// Comments are returned in the order they appear in the input, regardless of
+/// [`ParserSettings::retain_comments`]. This allows tooling like formatters to keep comments which
+/// are not part of the parsed query.
+#[instrument(level = "trace", target = "surrealdb::core::syn", fields(length = input.len()))]
+pub fn parse_with_comments(
+	input: &str,
+	settings: ParserSettings,
+) -> Result<(Query, Vec<Comment>), Error> {
+	trace!(target: TARGET, "Parsing SurrealQL query with comments");
+
+	let settings = ParserSettings {
+		retain_comments: true,
+		..settings
+	};
+	parse_with_settings_inner(input, settings)
+}
+
+fn parse_with_settings_inner(
+	input: &str,
+	settings: ParserSettings,
+) -> Result<(Query, Vec<Comment>), Error> {
 	if input.len() > u32::MAX as usize {
 		return Err(Error::QueryTooLarge);
 	}
 
-	let mut parser = Parser::new_with_settings(
-		input.as_bytes(),
-		ParserSettings {
-			object_recursion_limit: *MAX_OBJECT_PARSING_DEPTH as usize,
-			query_recursion_limit: *MAX_QUERY_PARSING_DEPTH as usize,
-			references_enabled: capabilities
-				.allows_experimental(&ExperimentalTarget::RecordReferences),
-			bearer_access_enabled: capabilities
-				.allows_experimental(&ExperimentalTarget::BearerAccess),
-			define_api_enabled: capabilities.allows_experimental(&ExperimentalTarget::DefineApi),
-			files_enabled: capabilities.allows_experimental(&ExperimentalTarget::Files),
-			..Default::default()
-		},
-	);
+	if settings.object_recursion_limit == 0 {
+		return Err(Error::InvalidQuery(
+			error::SyntaxError::new("Invalid object recursion limit, expected a non-zero value")
+				.render_on(input),
+		));
+	}
+
+	if settings.query_recursion_limit == 0 {
+		return Err(Error::InvalidQuery(
+			error::SyntaxError::new("Invalid query recursion limit, expected a non-zero value")
+				.render_on(input),
+		));
+	}
+
+	let mut parser = Parser::new_with_settings(input.as_bytes(), settings);
 	let mut stack = Stack::new();
-	stack
+	let query = stack
 		.enter(|stk| parser.parse_query(stk))
 		.finish()
 		.map_err(|e| e.render_on(input))
-		.map_err(Error::InvalidQuery)
+		.map_err(Error::InvalidQuery)?;
+	Ok((query, parser.take_comments()))
+}
+
+/// Parses a SurrealQL query into its individual [`Statement`]s.
+///
+/// Every statement is returned together with the byte range it occupies in the input. The range
+/// starts at the first token of the statement and ends after its last token, excluding any
+/// leading whitespace or comments and the terminating semicolon.
+#[instrument(level = "trace", target = "surrealdb::core::syn", fields(length = input.len()))]
+pub fn parse_statements(input: &str) -> Result<Vec<(Statement, ops::Range<usize>)>, Error> {
+	trace!(target: TARGET, "Parsing SurrealQL statements");
+
+	if input.len() > u32::MAX as usize {
+		return Err(Error::QueryTooLarge);
+	}
+
+	let mut parser =
+		Parser::new_with_settings(input.as_bytes(), default_settings(&Capabilities::all()));
+	let mut stack = Stack::new();
+	let statements = stack
+		.enter(|stk| parser.parse_spanned_query(stk))
+		.finish()
+		.map_err(|e| e.render_on(input))
+		.map_err(Error::InvalidQuery)?;
+
+	Ok(statements
+		.into_iter()
+		.map(|(stmt, span)| {
+			let start = span.offset as usize;
+			(stmt, start..start + span.len as usize)
+		})
+		.collect())
+}
+
+/// Parses the first SurrealQL [`Statement`] of the input, returning it together with the
+/// remaining unparsed input.
+///
+/// Leading empty statements are skipped. The statement must be followed by either a `;`, which
+/// is consumed, or the end of the input in which case the returned remainder is empty. The
+/// remainder can be passed to this function again to parse the next statement. Errors are
+/// reported relative to the given input.
+#[instrument(level = "trace", target = "surrealdb::core::syn", fields(length = input.len()))]
+pub fn parse_one(input: &str) -> Result<(Statement, &str), Error> {
+	trace!(target: TARGET, "Parsing a single SurrealQL statement");
+
+	if input.len() > u32::MAX as usize {
+		return Err(Error::QueryTooLarge);
+	}
+
+	let mut parser =
+		Parser::new_with_settings(input.as_bytes(), default_settings(&Capabilities::all()));
+	// eat empty statements.
+	while parser.eat(t!(";")) {}
+
+	let mut stack = Stack::new();
+	let statement = stack
+		.enter(|stk| parser.parse_statement(stk))
+		.finish()
+		.map_err(|e| e.render_on(input))
+		.map_err(Error::InvalidQuery)?;
+
+	if !parser.eat(t!(";")) {
+		let peek = parser.peek();
+		if !peek.is_eof() {
+			let error = error::syntax_error!("Unexpected token `{}` expected the statement to end",peek.kind.as_str(),
+				@peek.span => "maybe forgot a semicolon after the previous statement?");
+			return Err(Error::InvalidQuery(error.render_on(input)));
+		}
+		// Only whitespace or comments remain, nothing left to parse.
+		return Ok((statement, &input[input.len()..]));
+	}
+
+	let remaining = parser.remaining_input().len();
+	Ok((statement, &input[input.len() - remaining..]))
+}
+
+/// Parses a SurrealQL [`Query`], recovering from syntax errors.
+///
+/// Unlike [`parse`] this function does not stop at the first error. Whenever a statement fails to
+/// parse, the error is recorded and parsing resumes after the next `;`. The returned query
+/// contains every statement which parsed without errors and is `None` only if errors were
+/// encountered and no statement could be recovered. Errors can be rendered with
+/// [`error::SyntaxError::render_on`].
+#[instrument(level = "trace", target = "surrealdb::core::syn", fields(length = input.len()))]
+pub fn parse_recover(input: &str) -> (Option<Query>, Vec<error::SyntaxError>) {
+	trace!(target: TARGET, "Parsing SurrealQL query with error recovery");
+
+	if input.len() > u32::MAX as usize {
+		let error = error::SyntaxError::new(
+			"Size of query script exceeded maximum supported size of 4,294,967,295 bytes.",
+		);
+		return (None, vec![error]);
+	}
+
+	let mut parser =
+		Parser::new_with_settings(input.as_bytes(), default_settings(&Capabilities::all()));
+	let mut stack = Stack::new();
+	let (statements, errors) = stack.enter(|stk| parser.parse_query_recover(stk)).finish();
+	if statements.is_empty() && !errors.is_empty() {
+		return (None, errors);
+	}
+	(Some(Query(Statements(statements))), errors)
+}
+
+/// Parses SurrealQL [`Statement`]s from a reader, one statement at a time.
+///
+/// Unlike [`parse`] the total size of the input is not limited to 4GB, only individual statements
+/// are. The reader is consumed lazily, only reading as much data as is required to parse the next
+/// statement. Locations in the returned errors are relative to the start of the whole input.
+///
+/// The iterator stops after the first error is returned.
+pub fn parse_reader<R: Read>(mut reader: R) -> impl Iterator<Item = Result<Statement, Error>> {
+	let mut stream = StatementStream::new_with_settings(default_settings(&Capabilities::all()));
+	let mut buffer = BytesMut::new();
+	let mut chunk = vec![0u8; 4096];
+	let mut parse_size = 4096;
+	let mut complete = false;
+	let mut filling = true;
+	let mut finished = false;
+
+	std::iter::from_fn(move || loop {
+		if finished {
+			return None;
+		}
+
+		// fill the buffer to at least parse_size when filling is required.
+		while filling {
+			match reader.read(&mut chunk) {
+				Ok(0) => {
+					complete = true;
+					filling = false;
+				}
+				Ok(read) => {
+					buffer.extend_from_slice(&chunk[..read]);
+					filling = buffer.len() < parse_size;
+				}
+				Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+				Err(e) => {
+					finished = true;
+					return Some(Err(Error::Io(e)));
+				}
+			}
+		}
+
+		// if we finished reading we can parse with complete so that the parser can be sure
+		// of it's results.
+		if complete {
+			return match stream.parse_complete(&mut buffer) {
+				Err(e) => {
+					finished = true;
+					Some(Err(Error::InvalidQuery(e)))
+				}
+				Ok(None) => {
+					finished = true;
+					None
+				}
+				Ok(Some(x)) => Some(Ok(x)),
+			};
+		}
+
+		// otherwise try to parse a single statement.
+		match stream.parse_partial(&mut buffer) {
+			Err(e) => {
+				finished = true;
+				return Some(Err(Error::InvalidQuery(e)));
+			}
+			Ok(Some(x)) => return Some(Ok(x)),
+			Ok(None) => {
+				// Couldn't parse a statement for sure.
+				if buffer.len() >= parse_size && parse_size < u32::MAX as usize {
+					// the buffer already contained more or equal to parse_size bytes
+					// this means we are trying to parse a statement of more then buffer size.
+					// so we need to increase the buffer size.
+					parse_size = (parse_size + 1).next_power_of_two();
+				}
+				// start filling the buffer again.
+				filling = true;
+			}
+		}
+	})
 }
 
 /// Parses a SurrealQL [`Value`].
@@ -104,19 +548,92 @@ pub fn value_with_capabilities(input: &str, capabilities: &Capabilities) -> Resu
 		return Err(Error::QueryTooLarge);
 	}
 
+	let mut parser = Parser::new_with_settings(input.as_bytes(), default_settings(capabilities));
+	let mut stack = Stack::new();
+	stack
+		.enter(|stk| parser.parse_value_field(stk))
+		.finish()
+		.and_then(|e| parser.assert_finished().map(|_| e))
+		.map_err(|e| e.render_on(input))
+		.map_err(Error::InvalidQuery)
+}
+
+/// Returns the experimental features used by a SurrealQL [`Value`] which are not allowed by the
+/// given capabilities.
+///
+/// An empty list means the value can be parsed with these capabilities, for example by
+/// [`value_with_capabilities`]. Only features which affect the syntax are detected, so
+/// [`ExperimentalTarget::GraphQL`] is never returned. Returns an error if the value fails to parse
+/// even with all experimental features enabled.
+#[instrument(level = "trace", target = "surrealdb::core::syn", fields(length = input.len()))]
+pub fn value_uses_experimental(
+	input: &str,
+	capabilities: &Capabilities,
+) -> Result<Vec<ExperimentalTarget>, Error> {
+	trace!(target: TARGET, "Checking SurrealQL value for experimental features");
+
+	if input.len() > u32::MAX as usize {
+		return Err(Error::QueryTooLarge);
+	}
+
+	let parse = |disabled: Option<&ExperimentalTarget>| {
+		let enabled = |target: ExperimentalTarget| disabled != Some(&target);
+		let mut parser = Parser::new_with_settings(
+			input.as_bytes(),
+			ParserSettings {
+				object_recursion_limit: *MAX_OBJECT_PARSING_DEPTH as usize,
+				query_recursion_limit: *MAX_QUERY_PARSING_DEPTH as usize,
+				references_enabled: enabled(ExperimentalTarget::RecordReferences),
+				bearer_access_enabled: enabled(ExperimentalTarget::BearerAccess),
+				define_api_enabled: enabled(ExperimentalTarget::DefineApi),
+				files_enabled: enabled(ExperimentalTarget::Files),
+				..Default::default()
+			},
+		);
+		let mut stack = Stack::new();
+		stack
+			.enter(|stk| parser.parse_value_field(stk))
+			.finish()
+			.and_then(|e| parser.assert_finished().map(|_| e))
+	};
+
+	// Make sure the value is valid at all before checking the individual features.
+	parse(None).map_err(|e| e.render_on(input)).map_err(Error::InvalidQuery)?;
+
+	Ok([
+		ExperimentalTarget::RecordReferences,
+		ExperimentalTarget::BearerAccess,
+		ExperimentalTarget::DefineApi,
+		ExperimentalTarget::Files,
+	]
+	.into_iter()
+	.filter(|target| !capabilities.allows_experimental(target))
+	.filter(|target| parse(Some(target)).is_err())
+	.collect())
+}
+
+/// Parses a SurrealQL [`Value`], substituting the given values for parameters.
+///
+/// Parameters defined within the value itself, like closure arguments or variables introduced
+/// with `LET` in a block, shadow the given values and are left in place. Referencing any other
+/// parameter which has no value results in an error.
+#[instrument(level = "trace", target = "surrealdb::core::syn", skip(params), fields(length = input.len()))]
+pub fn value_with_params(input: &str, params: &BTreeMap<String, Value>) -> Result<Value, Error> {
+	trace!(target: TARGET, "Parsing SurrealQL value, with parameters");
+
+	if input.len() > u32::MAX as usize {
+		return Err(Error::QueryTooLarge);
+	}
+
 	let mut parser = Parser::new_with_settings(
 		input.as_bytes(),
 		ParserSettings {
 			object_recursion_limit: *MAX_OBJECT_PARSING_DEPTH as usize,
 			query_recursion_limit: *MAX_QUERY_PARSING_DEPTH as usize,
-			references_enabled: capabilities
-				.allows_experimental(&ExperimentalTarget::RecordReferences),
-			bearer_access_enabled: capabilities
-				.allows_experimental(&ExperimentalTarget::BearerAccess),
-			files_enabled: capabilities.allows_experimental(&ExperimentalTarget::Files),
 			..Default::default()
 		},
-	);
+	)
+	.with_params(params);
 	let mut stack = Stack::new();
 	stack
 		.enter(|stk| parser.parse_value_field(stk))
@@ -126,6 +643,28 @@ pub fn value_with_capabilities(input: &str, capabilities: &Capabilities) -> Resu
 		.map_err(Error::InvalidQuery)
 }
 
+/// Parses a list of comma separated SurrealQL [`Value`]s, like `1, 'a, b', [2, 3]`.
+///
+/// The whole input must consist of values, so trailing commas are rejected.
+#[instrument(level = "trace", target = "surrealdb::core::syn", fields(length = input.len()))]
+pub fn value_list(input: &str) -> Result<Vec<Value>, Error> {
+	trace!(target: TARGET, "Parsing SurrealQL value list");
+
+	if input.len() > u32::MAX as usize {
+		return Err(Error::QueryTooLarge);
+	}
+
+	let mut parser =
+		Parser::new_with_settings(input.as_bytes(), default_settings(&Capabilities::all()));
+	let mut stack = Stack::new();
+	stack
+		.enter(|stk| parser.parse_value_list(stk))
+		.finish()
+		.and_then(|e| parser.assert_finished().map(|_| e))
+		.map_err(|e| e.render_on(input))
+		.map_err(Error::InvalidQuery)
+}
+
 /// Parses JSON into an inert SurrealQL [`Value`]
 #[instrument(level = "trace", target = "surrealdb::core::syn", fields(length = input.len()))]
 pub fn json(input: &str) -> Result<Value, Error> {
@@ -152,6 +691,24 @@ pub fn json(input: &str) -> Result<Value, Error> {
 		.map_err(Error::InvalidQuery)
 }
 
+/// Parses strictly valid RFC 8259 JSON into an inert SurrealQL [`Value`]
+///
+/// Unlike [`json`] this rejects any input which is not standard JSON, like comments, trailing
+/// commas, unquoted object keys, single quoted strings, or SurrealQL values like record ids.
+#[instrument(level = "trace", target = "surrealdb::core::syn", fields(length = input.len()))]
+pub fn json_strict(input: &str) -> Result<Value, Error> {
+	trace!(target: TARGET, "Parsing strict JSON value");
+
+	if input.len() > u32::MAX as usize {
+		return Err(Error::QueryTooLarge);
+	}
+
+	let mut lexer = Lexer::new(input.as_bytes());
+	compound::json_strict(&mut lexer, *MAX_OBJECT_PARSING_DEPTH as usize)
+		.map_err(|e| e.render_on(input))
+		.map_err(Error::InvalidQuery)
+}
+
 /// Parses a SurrealQL Subquery [`Subquery`]
 #[instrument(level = "trace", target = "surrealdb::core::syn", fields(length = input.len()))]
 pub fn subquery(input: &str) -> Result<Subquery, Error> {
@@ -205,6 +762,140 @@ pub fn idiom(input: &str) -> Result<Idiom, Error> {
 		.map_err(Error::InvalidQuery)
 }
 
+/// Parses a SurrealQL [`Param`] reference, like `$name`.
+///
+/// Parameter names are not restricted by reserved keywords, so `$select` is a valid parameter.
+#[instrument(level = "trace", target = "surrealdb::core::syn", fields(length = input.len()))]
+pub fn param(input: &str) -> Result<Param, Error> {
+	trace!(target: TARGET, "Parsing SurrealQL param");
+
+	if input.len() > u32::MAX as usize {
+		return Err(Error::QueryTooLarge);
+	}
+
+	let mut parser = Parser::new(input.as_bytes());
+	parser
+		.next_token_value::<Param>()
+		.and_then(|e| parser.assert_finished().map(|_| e))
+		.map_err(|e| e.render_on(input))
+		.map_err(Error::InvalidQuery)
+}
+
+/// Parses a SurrealQL [`Fields`] projection list, like `foo, bar AS baz` or `VALUE foo`.
+#[instrument(level = "trace", target = "surrealdb::core::syn", fields(length = input.len()))]
+pub fn fields(input: &str) -> Result<Fields, Error> {
+	trace!(target: TARGET, "Parsing SurrealQL fields");
+
+	if input.len() > u32::MAX as usize {
+		return Err(Error::QueryTooLarge);
+	}
+
+	let mut parser = Parser::new_with_settings(
+		input.as_bytes(),
+		ParserSettings {
+			object_recursion_limit: *MAX_OBJECT_PARSING_DEPTH as usize,
+			query_recursion_limit: *MAX_QUERY_PARSING_DEPTH as usize,
+			..Default::default()
+		},
+	);
+	let mut stack = Stack::new();
+	stack
+		.enter(|stk| parser.parse_fields(stk))
+		.finish()
+		.and_then(|e| parser.assert_finished().map(|_| e))
+		.map_err(|e| e.render_on(input))
+		.map_err(Error::InvalidQuery)
+}
+
+/// Parses a SurrealQL [`Cond`], the expression following `WHERE`, without the `WHERE` keyword.
+#[instrument(level = "trace", target = "surrealdb::core::syn", fields(length = input.len()))]
+pub fn cond(input: &str) -> Result<Cond, Error> {
+	trace!(target: TARGET, "Parsing SurrealQL condition");
+
+	if input.len() > u32::MAX as usize {
+		return Err(Error::QueryTooLarge);
+	}
+
+	let mut parser = Parser::new_with_settings(
+		input.as_bytes(),
+		ParserSettings {
+			object_recursion_limit: *MAX_OBJECT_PARSING_DEPTH as usize,
+			query_recursion_limit: *MAX_QUERY_PARSING_DEPTH as usize,
+			..Default::default()
+		},
+	);
+	let mut stack = Stack::new();
+	stack
+		.enter(|stk| parser.parse_value_field(stk))
+		.finish()
+		.and_then(|e| parser.assert_finished().map(|_| Cond(e)))
+		.map_err(|e| e.render_on(input))
+		.map_err(Error::InvalidQuery)
+}
+
+/// Parses a SurrealQL [`Permissions`] clause like `FOR select WHERE published = true, FOR create NONE`.
+///
+/// The leading `PERMISSIONS` keyword is optional. Actions which are not mentioned in a `FOR`
+/// clause default to `NONE`, the same as in a `DEFINE TABLE` statement.
+#[instrument(level = "trace", target = "surrealdb::core::syn", fields(length = input.len()))]
+pub fn permissions(input: &str) -> Result<Permissions, Error> {
+	trace!(target: TARGET, "Parsing SurrealQL permissions");
+
+	if input.len() > u32::MAX as usize {
+		return Err(Error::QueryTooLarge);
+	}
+
+	let mut parser = Parser::new_with_settings(
+		input.as_bytes(),
+		ParserSettings {
+			object_recursion_limit: *MAX_OBJECT_PARSING_DEPTH as usize,
+			query_recursion_limit: *MAX_QUERY_PARSING_DEPTH as usize,
+			..Default::default()
+		},
+	);
+	parser.eat(t!("PERMISSIONS"));
+	let mut stack = Stack::new();
+	stack
+		.enter(|stk| parser.parse_permission(stk, false))
+		.finish()
+		.and_then(|e| parser.assert_finished().map(|_| e))
+		.map_err(|e| e.render_on(input))
+		.map_err(Error::InvalidQuery)
+}
+
+/// Parses a SurrealQL [`Geometry`], either a point like `(-0.118092, 51.509865)` or a GeoJSON-style
+/// object like `{ type: "Polygon", coordinates: [...] }`.
+#[instrument(level = "trace", target = "surrealdb::core::syn", fields(length = input.len()))]
+pub fn geometry(input: &str) -> Result<Geometry, Error> {
+	trace!(target: TARGET, "Parsing SurrealQL geometry");
+
+	if input.len() > u32::MAX as usize {
+		return Err(Error::QueryTooLarge);
+	}
+
+	let mut parser = Parser::new_with_settings(
+		input.as_bytes(),
+		ParserSettings {
+			object_recursion_limit: *MAX_OBJECT_PARSING_DEPTH as usize,
+			query_recursion_limit: *MAX_QUERY_PARSING_DEPTH as usize,
+			..Default::default()
+		},
+	);
+	let mut stack = Stack::new();
+	let start = parser.peek().span;
+	stack
+		.enter(|stk| parser.parse_value_field(stk))
+		.finish()
+		.and_then(|e| parser.assert_finished().map(|_| e))
+		.and_then(|e| match e {
+			Value::Geometry(x) => Ok(x),
+			_ => Err(error::SyntaxError::new("Expected a geometry")
+				.with_span(start.covers(parser.last_span()), error::MessageKind::Error)),
+		})
+		.map_err(|e| e.render_on(input))
+		.map_err(Error::InvalidQuery)
+}
+
 /// Parse a datetime without enclosing delimiters from a string.
 #[instrument(level = "trace", target = "surrealdb::core::syn", fields(length = input.len()))]
 pub fn datetime(input: &str) -> Result<Datetime, Error> {
@@ -404,13 +1095,21 @@ pub fn json_legacy_strand(input: &str) -> Result<Value, Error> {
 /// Parse a kind from a string.
 #[instrument(level = "trace", target = "surrealdb::core::syn", fields(length = input.len()))]
 pub fn kind(input: &str) -> Result<Kind, Error> {
-	trace!(target: TARGET, "Parsing SurrealQL duration");
+	let capabilities = Capabilities::all();
+	kind_with_capabilities(input, &capabilities)
+}
+
+/// Parse a kind from a string, only allowing the experimental kinds, like `references`, which the
+/// given capabilities allow.
+#[instrument(level = "trace", target = "surrealdb::core::syn", fields(length = input.len()))]
+pub fn kind_with_capabilities(input: &str, capabilities: &Capabilities) -> Result<Kind, Error> {
+	trace!(target: TARGET, "Parsing SurrealQL kind");
 
 	if input.len() > u32::MAX as usize {
 		return Err(Error::QueryTooLarge);
 	}
 
-	let mut parser = Parser::new(input.as_bytes());
+	let mut parser = Parser::new_with_settings(input.as_bytes(), default_settings(capabilities));
 	let mut stack = Stack::new();
 	stack
 		.enter(|stk| parser.parse_inner_kind(stk))
@@ -419,3 +1118,22 @@ pub fn kind(input: &str) -> Result<Kind, Error> {
 		.map_err(|e| e.render_on(input))
 		.map_err(Error::InvalidQuery)
 }
+
+/// Parse a custom function signature, like `fn::add($a: int, $b: int) -> int`, from a string.
+#[instrument(level = "trace", target = "surrealdb::core::syn", fields(length = input.len()))]
+pub fn function_signature(input: &str) -> Result<FunctionSignature, Error> {
+	trace!(target: TARGET, "Parsing SurrealQL function signature");
+
+	if input.len() > u32::MAX as usize {
+		return Err(Error::QueryTooLarge);
+	}
+
+	let mut parser = Parser::new(input.as_bytes());
+	let mut stack = Stack::new();
+	stack
+		.enter(|stk| parser.parse_function_signature(stk))
+		.finish()
+		.and_then(|e| parser.assert_finished().map(|_| e))
+		.map_err(|e| e.render_on(input))
+		.map_err(Error::InvalidQuery)
+}
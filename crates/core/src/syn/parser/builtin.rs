@@ -351,31 +351,45 @@ pub(crate) static PATHS: phf::Map<UniCase<&'static str>, PathKind> = phf_map! {
 		UniCase::ascii("type::array") => PathKind::Function,
 		UniCase::ascii("type::bool") => PathKind::Function,
 		UniCase::ascii("type::bytes") => PathKind::Function,
+		UniCase::ascii("type::bytes_from_string") => PathKind::Function,
+		UniCase::ascii("type::cast") => PathKind::Function,
+		UniCase::ascii("type::coerce") => PathKind::Function,
 		UniCase::ascii("type::datetime") => PathKind::Function,
+		UniCase::ascii("type::datetime_from_unix") => PathKind::Function,
 		UniCase::ascii("type::decimal") => PathKind::Function,
 		UniCase::ascii("type::duration") => PathKind::Function,
+		UniCase::ascii("type::duration_from") => PathKind::Function,
 		UniCase::ascii("type::field") => PathKind::Function,
 		UniCase::ascii("type::fields") => PathKind::Function,
 		UniCase::ascii("type::file") => PathKind::Function,
+		UniCase::ascii("type::file_from_url") => PathKind::Function,
 		UniCase::ascii("type::float") => PathKind::Function,
 		UniCase::ascii("type::geometry") => PathKind::Function,
 		UniCase::ascii("type::int") => PathKind::Function,
+		UniCase::ascii("type::kind") => PathKind::Function,
 		UniCase::ascii("type::number") => PathKind::Function,
 		UniCase::ascii("type::point") => PathKind::Function,
 		UniCase::ascii("type::range") => PathKind::Function,
 		UniCase::ascii("type::record") => PathKind::Function,
+		UniCase::ascii("type::set") => PathKind::Function,
 		UniCase::ascii("type::string") => PathKind::Function,
 		UniCase::ascii("type::string_lossy") => PathKind::Function,
 		UniCase::ascii("type::table") => PathKind::Function,
 		UniCase::ascii("type::thing") => PathKind::Function,
+		UniCase::ascii("type::thing_range") => PathKind::Function,
 		UniCase::ascii("type::uuid") => PathKind::Function,
+		UniCase::ascii("type::geometry::collection") => PathKind::Function,
+		UniCase::ascii("type::geometry::line") => PathKind::Function,
+		UniCase::ascii("type::geometry::polygon") => PathKind::Function,
 		UniCase::ascii("type::is::array") => PathKind::Function,
 		UniCase::ascii("type::is::bool") => PathKind::Function,
 		UniCase::ascii("type::is::bytes") => PathKind::Function,
+		UniCase::ascii("type::is::closure") => PathKind::Function,
 		UniCase::ascii("type::is::collection") => PathKind::Function,
 		UniCase::ascii("type::is::datetime") => PathKind::Function,
 		UniCase::ascii("type::is::decimal") => PathKind::Function,
 		UniCase::ascii("type::is::duration") => PathKind::Function,
+		UniCase::ascii("type::is::file") => PathKind::Function,
 		UniCase::ascii("type::is::float") => PathKind::Function,
 		UniCase::ascii("type::is::geometry") => PathKind::Function,
 		UniCase::ascii("type::is::int") => PathKind::Function,
@@ -393,6 +407,8 @@ pub(crate) static PATHS: phf::Map<UniCase<&'static str>, PathKind> = phf_map! {
 		UniCase::ascii("type::is::record") => PathKind::Function,
 		UniCase::ascii("type::is::string") => PathKind::Function,
 		UniCase::ascii("type::is::uuid") => PathKind::Function,
+		UniCase::ascii("type::record::id") => PathKind::Function,
+		UniCase::ascii("type::record::table") => PathKind::Function,
 		//
 		UniCase::ascii("value::diff") => PathKind::Function,
 		UniCase::ascii("value::patch") => PathKind::Function,
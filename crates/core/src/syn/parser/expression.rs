@@ -61,6 +61,15 @@ impl Parser<'_> {
 		res
 	}
 
+	/// Parses a list of values separated by commas, like `1, 'a', [2, 3]`.
+	pub(crate) async fn parse_value_list(&mut self, ctx: &mut Stk) -> ParseResult<Vec<Value>> {
+		let mut res = vec![ctx.run(|ctx| self.parse_value_field(ctx)).await?];
+		while self.eat(t!(",")) {
+			res.push(ctx.run(|ctx| self.parse_value_field(ctx)).await?);
+		}
+		Ok(res)
+	}
+
 	/// Parsers a generic value.
 	///
 	/// Inherits how loose identifiers are parsed from it's caller.
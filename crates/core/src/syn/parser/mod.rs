@@ -55,15 +55,19 @@
 
 use self::token_buffer::TokenBuffer;
 use crate::{
-	sql::{self, Bytes, Datetime, Duration, File, Strand, Uuid},
+	sql::{self, Bytes, Datetime, Duration, File, Strand, Uuid, Value},
 	syn::{
 		error::{bail, SyntaxError},
-		lexer::{compound::NumberKind, Lexer},
-		token::{t, Span, Token, TokenKind},
+		lexer::{
+			compound::{self, NumberKind},
+			Comment, Lexer,
+		},
+		token::{t, QouteKind, Span, Token, TokenKind},
 	},
 };
 use bytes::BytesMut;
 use reblessive::{Stack, Stk};
+use std::collections::BTreeMap;
 
 mod basic;
 mod builtin;
@@ -147,6 +151,9 @@ pub struct ParserSettings {
 	pub define_api_enabled: bool,
 	/// Whether the files feature is enabled
 	pub files_enabled: bool,
+	/// Whether to retain the comments in the source, which can be taken with
+	/// [`Parser::take_comments`] after parsing.
+	pub retain_comments: bool,
 }
 
 impl Default for ParserSettings {
@@ -160,6 +167,7 @@ impl Default for ParserSettings {
 			bearer_access_enabled: false,
 			define_api_enabled: false,
 			files_enabled: false,
+			retain_comments: false,
 		}
 	}
 }
@@ -172,6 +180,10 @@ pub struct Parser<'a> {
 	glued_value: GluedValue,
 	pub(crate) table_as_field: bool,
 	settings: ParserSettings,
+	/// Values which are substituted for parameters while parsing, if any.
+	params: Option<&'a BTreeMap<String, Value>>,
+	/// Parameters defined within the source, which shadow the substituted parameters.
+	shadowed: Vec<String>,
 }
 
 impl<'a> Parser<'a> {
@@ -182,21 +194,41 @@ impl<'a> Parser<'a> {
 
 	/// Create a new parser from a give source.
 	pub fn new_with_settings(source: &'a [u8], settings: ParserSettings) -> Self {
+		let mut lexer = Lexer::new(source);
+		lexer.retain_comments(settings.retain_comments);
 		Parser {
-			lexer: Lexer::new(source),
+			lexer,
 			last_span: Span::empty(),
 			token_buffer: TokenBuffer::new(),
 			glued_value: GluedValue::None,
 			table_as_field: true,
 			settings,
+			params: None,
+			shadowed: Vec::new(),
 		}
 	}
 
 	pub fn with_settings(mut self, settings: ParserSettings) -> Self {
+		self.lexer.retain_comments(settings.retain_comments);
 		self.settings = settings;
 		self
 	}
 
+	/// Takes the comments parsed so far, in the order they appear in the source. Comments are only
+	/// retained if enabled with [`ParserSettings::retain_comments`].
+	pub fn take_comments(&mut self) -> Vec<Comment> {
+		self.lexer.take_comments()
+	}
+
+	/// Substitute the given values for parameters while parsing.
+	///
+	/// Referencing a parameter which is neither given nor defined within the source, for example
+	/// by a closure argument or a `LET` statement, results in an error.
+	pub fn with_params(mut self, params: &'a BTreeMap<String, Value>) -> Self {
+		self.params = Some(params);
+		self
+	}
+
 	/// Returns the next token and advance the parser one token forward.
 	#[allow(clippy::should_implement_trait)]
 	pub fn next(&mut self) -> Token {
@@ -317,6 +349,19 @@ impl<'a> Parser<'a> {
 		self.last_span
 	}
 
+	/// Returns the part of the source after the last consumed token.
+	///
+	/// Tokens which were only peeked are not considered consumed and are part of the returned
+	/// slice, as is any whitespace following the last consumed token.
+	pub fn remaining_input(&self) -> &'a [u8] {
+		let offset = self.last_span.after_offset();
+		let end = self.lexer.reader.offset() + self.lexer.reader.len();
+		self.lexer.reader.span(Span {
+			offset,
+			len: end as u32 - offset,
+		})
+	}
+
 	pub fn assert_finished(&mut self) -> ParseResult<()> {
 		let p = self.peek();
 		if p.kind != TokenKind::Eof {
@@ -380,6 +425,47 @@ impl<'a> Parser<'a> {
 		self.lexer.backup_after(span);
 	}
 
+	/// Skip ahead to just after the next `;` which isn't nested within delimiters.
+	///
+	/// Used to resynchronize the parser after an error in the statement starting at `start`,
+	/// continues from after the last consumed token.
+	fn recover_statement_end(&mut self, start: Span) {
+		if self.last_span.offset >= start.offset && self.lexer.reader.span(self.last_span) == b";" {
+			// The error was raised on the `;` ending the statement, nothing left to skip.
+			return;
+		}
+		let mut depth = 0usize;
+		loop {
+			let token = self.next_whitespace();
+			match token.kind {
+				t!("eof") => return,
+				t!(";") if depth == 0 => return,
+				t!("{") | t!("(") | t!("[") => depth += 1,
+				t!("}") | t!(")") | t!("]") => depth = depth.saturating_sub(1),
+				TokenKind::Qoute(x) if self.token_buffer.is_empty() => {
+					// Skip over the strand so that delimiters within it are not counted.
+					let kind = match x {
+						QouteKind::Plain
+						| QouteKind::RecordId
+						| QouteKind::Uuid
+						| QouteKind::DateTime
+						| QouteKind::Bytes
+						| QouteKind::File => t!("'"),
+						_ => t!("\""),
+					};
+					let start = Token {
+						kind,
+						span: token.span,
+					};
+					if let Ok(x) = self.lexer.lex_compound(start, compound::strand) {
+						self.last_span = x.span;
+					}
+				}
+				_ => {}
+			}
+		}
+	}
+
 	/// Parse a full query.
 	///
 	/// This is the primary entry point of the parser.
@@ -388,6 +474,24 @@ impl<'a> Parser<'a> {
 		Ok(sql::Query(statements))
 	}
 
+	/// Parse a full query, returning every statement together with its span in the source.
+	pub async fn parse_spanned_query(
+		&mut self,
+		ctx: &mut Stk,
+	) -> ParseResult<Vec<(sql::Statement, Span)>> {
+		self.parse_spanned_stmt_list(ctx).await
+	}
+
+	/// Parse a full query, recovering from syntax errors at statement boundaries.
+	///
+	/// Returns the statements which parsed without errors together with every error encountered.
+	pub async fn parse_query_recover(
+		&mut self,
+		ctx: &mut Stk,
+	) -> (Vec<sql::Statement>, Vec<SyntaxError>) {
+		self.parse_stmt_list_recover(ctx).await
+	}
+
 	/// Parse a single statement.
 	pub async fn parse_statement(&mut self, ctx: &mut Stk) -> ParseResult<sql::Statement> {
 		self.parse_stmt(ctx).await
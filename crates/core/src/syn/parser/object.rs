@@ -571,6 +571,8 @@ impl Parser<'_> {
 	/// Expects the starting `{` to have already been eaten and its span to be handed to this
 	/// functions as the `start` parameter.
 	pub async fn parse_block(&mut self, ctx: &mut Stk, start: Span) -> ParseResult<Block> {
+		// Parameters defined within the block go out of scope at its end
+		let shadowed = self.shadowed.len();
 		let mut statements = Vec::new();
 		loop {
 			while self.eat(t!(";")) {}
@@ -585,6 +587,7 @@ impl Parser<'_> {
 				break;
 			}
 		}
+		self.shadowed.truncate(shadowed);
 		Ok(Block(statements))
 	}
 
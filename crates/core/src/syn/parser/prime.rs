@@ -50,7 +50,7 @@ impl Parser<'_> {
 				Ok(Value::Bytes(self.next_token_value()?))
 			}
 			t!("$param") => {
-				let value = Value::Param(self.next_token_value()?);
+				let value = self.parse_param_value()?;
 				let value = self.try_parse_inline(ctx, &value).await?.unwrap_or(value);
 				Ok(value)
 			}
@@ -274,7 +274,7 @@ impl Parser<'_> {
 				Value::Number(Number::Float(f64::NAN))
 			}
 			t!("$param") => {
-				let value = Value::Param(self.next_token_value()?);
+				let value = self.parse_param_value()?;
 				self.try_parse_inline(ctx, &value).await?.unwrap_or(value)
 			}
 			t!("FUNCTION") => {
@@ -420,6 +420,23 @@ impl Parser<'_> {
 		}
 	}
 
+	/// Parses a parameter, substituting its value if the parser was given parameters.
+	pub(super) fn parse_param_value(&mut self) -> ParseResult<Value> {
+		let param = self.next_token_value::<Param>()?;
+		let Some(params) = self.params else {
+			return Ok(Value::Param(param));
+		};
+		if self.shadowed.contains(&param.0 .0) {
+			return Ok(Value::Param(param));
+		}
+		match params.get(param.as_str()) {
+			Some(v) => Ok(v.clone()),
+			None => {
+				bail!("Parameter `{param}` is not bound", @self.last_span() => "this parameter has no value")
+			}
+		}
+	}
+
 	pub(super) async fn parse_closure_or_mock(
 		&mut self,
 		ctx: &mut Stk,
@@ -466,6 +483,9 @@ impl Parser<'_> {
 		ctx: &mut Stk,
 		args: Vec<(Ident, Kind)>,
 	) -> ParseResult<Value> {
+		// The closure arguments shadow any substituted parameters
+		let shadowed = self.shadowed.len();
+		self.shadowed.extend(args.iter().map(|(name, _)| name.0.clone()));
 		let (returns, body) = if self.eat(t!("->")) {
 			let returns = Some(ctx.run(|ctx| self.parse_inner_kind(ctx)).await?);
 			let start = expected!(self, t!("{")).span;
@@ -475,6 +495,7 @@ impl Parser<'_> {
 			let body = ctx.run(|ctx| self.parse_value_inherit(ctx)).await?;
 			(None, body)
 		};
+		self.shadowed.truncate(shadowed);
 
 		Ok(Value::Closure(Box::new(Closure {
 			args,
@@ -23,6 +23,7 @@ use crate::{
             DefineDatabaseStatement, DefineEventStatement, DefineFieldStatement,
             DefineFunctionStatement, DefineIndexStatement, DefineNamespaceStatement,
             DefineParamStatement, DefineStatement, DefineTableStatement, DefineUserStatement,
+            FunctionSignature,
         },
         table_type,
         tokenizer::Tokenizer,
@@ -145,30 +146,11 @@ impl Parser<'_> {
         } else {
             (false, false)
         };
-        let name = self.parse_custom_function_name()?;
-        let token = expected!(self, t!("(")).span;
-        let mut args = Vec::new();
-        loop {
-            if self.eat(t!(")")) {
-                break;
-            }
-
-            let param = self.next_token_value::<Param>()?.0;
-            expected!(self, t!(":"));
-            let kind = ctx.run(|ctx| self.parse_inner_kind(ctx)).await?;
-
-            args.push((param, kind));
-
-            if !self.eat(t!(",")) {
-                self.expect_closing_delimiter(t!(")"), token)?;
-                break;
-            }
-        }
-        let returns = if self.eat(t!("->")) {
-            Some(ctx.run(|ctx| self.parse_inner_kind(ctx)).await?)
-        } else {
-            None
-        };
+        let FunctionSignature {
+            name,
+            args,
+            returns,
+        } = self.parse_function_signature(ctx).await?;
 
         let next = expected!(self, t!("{")).span;
         let block = self.parse_block(ctx, next).await?;
@@ -200,6 +182,43 @@ impl Parser<'_> {
         Ok(res)
     }
 
+    /// Parses a custom function signature, like `fn::add($a: int, $b: int) -> int`.
+    pub async fn parse_function_signature(
+        &mut self,
+        ctx: &mut Stk,
+    ) -> ParseResult<FunctionSignature> {
+        let name = self.parse_custom_function_name()?;
+        let token = expected!(self, t!("(")).span;
+        let mut args = Vec::new();
+        loop {
+            if self.eat(t!(")")) {
+                break;
+            }
+
+            let param = self.next_token_value::<Param>()?.0;
+            expected!(self, t!(":"));
+            let kind = ctx.run(|ctx| self.parse_inner_kind(ctx)).await?;
+
+            args.push((param, kind));
+
+            if !self.eat(t!(",")) {
+                self.expect_closing_delimiter(t!(")"), token)?;
+                break;
+            }
+        }
+        let returns = if self.eat(t!("->")) {
+            Some(ctx.run(|ctx| self.parse_inner_kind(ctx)).await?)
+        } else {
+            None
+        };
+
+        Ok(FunctionSignature {
+            name,
+            args,
+            returns,
+        })
+    }
+
     pub fn parse_define_user(&mut self) -> ParseResult<DefineUserStatement> {
         let (if_not_exists, overwrite) = if self.eat(t!("IF")) {
             expected!(self, t!("NOT"));
@@ -1547,12 +1566,17 @@ impl Parser<'_> {
     }
 
     fn parse_graphql_config(&mut self) -> ParseResult<GraphQLConfig> {
-        use graphql::{FunctionsConfig, TablesConfig};
+        use graphql::{FunctionsConfig, GqlNaming, TablesConfig};
         let mut tmp_tables = Option::<TablesConfig>::None;
         let mut tmp_fncs = Option::<FunctionsConfig>::None;
         let mut tmp_cursor = false;
+        let mut tmp_naming = GqlNaming::default();
+        let mut tmp_wrap_lists = false;
+        let mut tmp_depth_limit = None;
+        let mut tmp_complexity_limit = None;
         loop {
-            match self.peek_kind() {
+            let peek = self.peek();
+            match peek.kind {
                 t!("NONE") => {
                     self.pop_peek();
                     tmp_tables = Some(TablesConfig::None);
@@ -1605,6 +1629,30 @@ impl Parser<'_> {
                     self.pop_peek();
                     tmp_cursor = true;
                 }
+                kind if Self::kind_is_identifier(kind)
+                    && self.lexer.span_str(peek.span).eq_ignore_ascii_case("NAMING") =>
+                {
+                    self.pop_peek();
+                    tmp_naming = self.parse_graphql_naming()?;
+                }
+                kind if Self::kind_is_identifier(kind)
+                    && self.lexer.span_str(peek.span).eq_ignore_ascii_case("LISTS") =>
+                {
+                    self.pop_peek();
+                    tmp_wrap_lists = self.parse_graphql_lists()?;
+                }
+                kind if Self::kind_is_identifier(kind)
+                    && self.lexer.span_str(peek.span).eq_ignore_ascii_case("DEPTH") =>
+                {
+                    self.pop_peek();
+                    tmp_depth_limit = Some(self.next_token_value::<u32>()?);
+                }
+                kind if Self::kind_is_identifier(kind)
+                    && self.lexer.span_str(peek.span).eq_ignore_ascii_case("COMPLEXITY") =>
+                {
+                    self.pop_peek();
+                    tmp_complexity_limit = Some(self.next_token_value::<u32>()?);
+                }
                 _ => break,
             }
         }
@@ -1613,9 +1661,70 @@ impl Parser<'_> {
             tables: tmp_tables.unwrap_or_default(),
             functions: tmp_fncs.unwrap_or_default(),
             cursor: tmp_cursor,
+            naming: tmp_naming,
+            wrap_lists: tmp_wrap_lists,
+            depth_limit: tmp_depth_limit,
+            complexity_limit: tmp_complexity_limit,
         })
     }
 
+    /// Parses the naming strategy after `NAMING`, one of `PASCAL`, `VERBATIM` or
+    /// `TYPES <case> FIELDS <case>`. The words are not keywords, so they are matched by their text.
+    fn parse_graphql_naming(&mut self) -> ParseResult<graphql::GqlNaming> {
+        use graphql::GqlNaming;
+        let next = self.next();
+        let word = match Self::kind_is_identifier(next.kind) {
+            true => self.lexer.span_str(next.span).to_ascii_uppercase(),
+            false => unexpected!(self, next, "`PASCAL`, `VERBATIM` or `TYPES`"),
+        };
+        let naming = match word.as_str() {
+            "PASCAL" => GqlNaming::Pascal,
+            "VERBATIM" => GqlNaming::Verbatim,
+            "TYPES" => {
+                let types = self.parse_graphql_name_case()?;
+                expected!(self, t!("FIELDS"));
+                let fields = self.parse_graphql_name_case()?;
+                GqlNaming::Custom {
+                    types,
+                    fields,
+                }
+            }
+            _ => unexpected!(self, next, "`PASCAL`, `VERBATIM` or `TYPES`"),
+        };
+        Ok(naming)
+    }
+
+    /// Parses whether lists are wrapped after `LISTS`, either `WRAPPED` or `BARE`.
+    fn parse_graphql_lists(&mut self) -> ParseResult<bool> {
+        let next = self.next();
+        let word = match Self::kind_is_identifier(next.kind) {
+            true => self.lexer.span_str(next.span).to_ascii_uppercase(),
+            false => unexpected!(self, next, "`WRAPPED` or `BARE`"),
+        };
+        match word.as_str() {
+            "WRAPPED" => Ok(true),
+            "BARE" => Ok(false),
+            _ => unexpected!(self, next, "`WRAPPED` or `BARE`"),
+        }
+    }
+
+    fn parse_graphql_name_case(&mut self) -> ParseResult<graphql::NameCase> {
+        use graphql::NameCase;
+        let next = self.next();
+        let word = match Self::kind_is_identifier(next.kind) {
+            true => self.lexer.span_str(next.span).to_ascii_uppercase(),
+            false => unexpected!(self, next, "`VERBATIM`, `PASCAL`, `CAMEL` or `SNAKE`"),
+        };
+        let case = match word.as_str() {
+            "VERBATIM" => NameCase::Verbatim,
+            "PASCAL" => NameCase::Pascal,
+            "CAMEL" => NameCase::Camel,
+            "SNAKE" => NameCase::Snake,
+            _ => unexpected!(self, next, "`VERBATIM`, `PASCAL`, `CAMEL` or `SNAKE`"),
+        };
+        Ok(case)
+    }
+
     fn parse_graphql_table_configs(&mut self) -> ParseResult<Vec<graphql::TableConfig>> {
         let mut acc = vec![];
         loop {
@@ -11,23 +11,24 @@ use crate::sql::statements::{
 	},
 	KillStatement, LiveStatement, OptionStatement, SetStatement, ThrowStatement,
 };
-use crate::sql::{Duration, Fields, Ident, Param};
+use crate::sql::{Duration, Fields, Ident, Param, Strand};
+use crate::syn::error::{bail, syntax_error};
 use crate::syn::lexer::compound;
 use crate::syn::parser::enter_query_recursion;
-use crate::syn::token::{t, Glued, TokenKind};
+use crate::syn::token::{t, Glued, Span, TokenKind};
 use crate::{
 	sql::{
 		statements::{
 			analyze::AnalyzeStatement, BeginStatement, BreakStatement, CancelStatement,
-			CommitStatement, ContinueStatement, ForeachStatement, InfoStatement, OutputStatement,
-			UseStatement,
+			CommitStatement, ContinueStatement, ForeachStatement, InfoDbCategory, InfoStatement,
+			OutputStatement, SavepointStatement, UseStatement,
 		},
 		Expression, Operator, Statement, Statements, Value,
 	},
 	syn::parser::mac::unexpected,
 };
 
-use super::{mac::expected, ParseResult, Parser};
+use super::{mac::expected, ParseResult, Parser, SyntaxError};
 
 mod alter;
 mod create;
@@ -44,6 +45,18 @@ mod upsert;
 
 impl Parser<'_> {
 	pub(super) async fn parse_stmt_list(&mut self, ctx: &mut Stk) -> ParseResult<Statements> {
+		let res = self.parse_spanned_stmt_list(ctx).await?;
+		Ok(Statements(res.into_iter().map(|(stmt, _)| stmt).collect()))
+	}
+
+	/// Parses a list of statements, returning each statement together with the span it covers.
+	///
+	/// The span of a statement starts at its first token and ends after its last token, so it
+	/// never includes surrounding whitespace, comments, or the terminating semicolon.
+	pub(super) async fn parse_spanned_stmt_list(
+		&mut self,
+		ctx: &mut Stk,
+	) -> ParseResult<Vec<(Statement, Span)>> {
 		let mut res = Vec::new();
 		loop {
 			match self.peek_kind() {
@@ -54,8 +67,9 @@ impl Parser<'_> {
 				}
 				t!("eof") => break,
 				_ => {
+					let start = self.peek().span;
 					let stmt = ctx.run(|ctx| self.parse_stmt(ctx)).await?;
-					res.push(stmt);
+					res.push((stmt, start.covers(self.last_span())));
 					if !self.eat(t!(";")) {
 						if self.eat(t!("eof")) {
 							break;
@@ -74,7 +88,58 @@ impl Parser<'_> {
 				}
 			}
 		}
-		Ok(Statements(res))
+		Ok(res)
+	}
+
+	/// Parses a list of statements, recovering from any syntax errors encountered.
+	///
+	/// When a statement fails to parse the error is recorded and the parser skips ahead to the end
+	/// of the statement, after which parsing resumes with the next statement. Returns all the
+	/// statements which parsed without errors together with every error encountered.
+	pub(super) async fn parse_stmt_list_recover(
+		&mut self,
+		ctx: &mut Stk,
+	) -> (Vec<Statement>, Vec<SyntaxError>) {
+		let mut res = Vec::new();
+		let mut errors = Vec::new();
+		loop {
+			match self.peek_kind() {
+				// consume any possible empty statements.
+				t!(";") => {
+					self.pop_peek();
+					continue;
+				}
+				t!("eof") => break,
+				_ => {
+					let start = self.peek().span;
+					match ctx.run(|ctx| self.parse_stmt(ctx)).await {
+						Ok(stmt) => {
+							if self.eat(t!(";")) || self.peek_kind() == t!("eof") {
+								res.push(stmt);
+								continue;
+							}
+
+							let token = self.peek();
+							errors.push(
+								syntax_error!("Unexpected token `{}` expected the query to end",token.kind,
+							@token.span => "maybe forgot a semicolon after the previous statement?"),
+							);
+							if Self::kind_starts_statement(token.kind) {
+								// The next statement can be parsed as is, so keep this one.
+								res.push(stmt);
+								continue;
+							}
+							self.recover_statement_end(start);
+						}
+						Err(e) => {
+							errors.push(e);
+							self.recover_statement_end(start);
+						}
+					}
+				}
+			}
+		}
+		(res, errors)
 	}
 
 	pub(super) async fn parse_stmt(&mut self, ctx: &mut Stk) -> ParseResult<Statement> {
@@ -182,6 +247,10 @@ impl Parser<'_> {
 				self.pop_peek();
 				ctx.run(|ctx| self.parse_remove_stmt(ctx)).await.map(Statement::Remove)
 			}
+			t!("SAVEPOINT") => {
+				self.pop_peek();
+				self.parse_savepoint().map(Statement::Savepoint)
+			}
 			t!("SELECT") => {
 				self.pop_peek();
 				ctx.run(|ctx| self.parse_select_stmt(ctx)).await.map(Statement::Select)
@@ -517,7 +586,31 @@ impl Parser<'_> {
 	/// Expects `CANCEL` to already be consumed.
 	fn parse_cancel(&mut self) -> ParseResult<CancelStatement> {
 		self.eat(t!("TRANSACTION"));
-		Ok(CancelStatement)
+		let savepoint = if self.eat(t!("TO")) {
+			expected!(self, t!("SAVEPOINT"));
+			Some(self.next_token_value()?)
+		} else {
+			None
+		};
+		let reason = match self.peek_kind() {
+			t!("\"") | t!("'") | TokenKind::Glued(Glued::Strand) => Some(self.next_token_value()?),
+			_ => None,
+		};
+		Ok(CancelStatement {
+			reason,
+			savepoint,
+		})
+	}
+
+	/// Parsers a savepoint statement.
+	///
+	/// # Parser State
+	/// Expects `SAVEPOINT` to already be consumed.
+	fn parse_savepoint(&mut self) -> ParseResult<SavepointStatement> {
+		let name = self.next_token_value()?;
+		Ok(SavepointStatement {
+			name,
+		})
 	}
 
 	/// Parsers a commit statement.
@@ -565,12 +658,14 @@ impl Parser<'_> {
 	/// # Parser State
 	/// Expects `FOR` to already be consumed.
 	pub(super) async fn parse_for_stmt(&mut self, stk: &mut Stk) -> ParseResult<ForeachStatement> {
-		let param = self.next_token_value()?;
+		let param: Param = self.next_token_value()?;
 		expected!(self, t!("IN"));
 		let range = stk.run(|stk| self.parse_value_inherit(stk)).await?;
 
 		let span = expected!(self, t!("{")).span;
+		self.shadowed.push(param.0 .0.clone());
 		let block = self.parse_block(stk, span).await?;
+		self.shadowed.pop();
 		Ok(ForeachStatement {
 			param,
 			range,
@@ -588,10 +683,12 @@ impl Parser<'_> {
 		let mut stmt = match next.kind {
 			t!("ROOT") => InfoStatement::Root(false),
 			t!("NAMESPACE") => InfoStatement::Ns(false),
-			t!("DATABASE") => InfoStatement::Db(false, None),
+			t!("DATABASE") => {
+				InfoStatement::Db(false, None, self.parse_info_db_category(), None, None)
+			}
 			t!("TABLE") => {
 				let ident = self.next_token_value()?;
-				InfoStatement::Tb(ident, false, None)
+				InfoStatement::Tb(ident, false, None, false)
 			}
 			t!("USER") => {
 				let ident = self.next_token_value()?;
@@ -605,6 +702,32 @@ impl Parser<'_> {
 				let table = self.next_token_value()?;
 				InfoStatement::Index(index, table, false)
 			}
+			t!("API") => {
+				if !self.settings.define_api_enabled {
+					bail!("Cannot show an API, as the experimental define api capability is not enabled", @next.span);
+				}
+				// The path of an API can be given as a string or an identifier
+				let path = match self.peek_kind() {
+					t!("\"") | t!("'") | TokenKind::Glued(Glued::Strand) => {
+						Ident(self.next_token_value::<Strand>()?.0)
+					}
+					_ => self.next_token_value()?,
+				};
+				InfoStatement::Api(path)
+			}
+			t!("BUCKET") => {
+				if !self.settings.files_enabled {
+					unexpected!(self, next, "the experimental files feature to be enabled");
+				}
+				let ident = self.next_token_value()?;
+				let peek = self.peek();
+				let files = Self::kind_is_identifier(peek.kind)
+					&& self.lexer.span_str(peek.span).eq_ignore_ascii_case("FILES");
+				if files {
+					self.pop_peek();
+				}
+				InfoStatement::Bucket(ident, files, None, None)
+			}
 			_ => unexpected!(self, next, "an info target"),
 		};
 
@@ -612,6 +735,29 @@ impl Parser<'_> {
 			stmt = stmt.versionize(version);
 		}
 
+		if let InfoStatement::Tb(..) = stmt {
+			let peek = self.peek();
+			if Self::kind_is_identifier(peek.kind)
+				&& self.lexer.span_str(peek.span).eq_ignore_ascii_case("STATS")
+			{
+				self.pop_peek();
+				stmt = stmt.with_stats();
+			}
+		}
+
+		if let InfoStatement::Db(..) | InfoStatement::Bucket(_, true, ..) = stmt {
+			let (limit, start) = if let t!("START") = self.peek_kind() {
+				let start = self.try_parse_start(stk).await?;
+				let limit = self.try_parse_limit(stk).await?;
+				(limit, start)
+			} else {
+				let limit = self.try_parse_limit(stk).await?;
+				let start = self.try_parse_start(stk).await?;
+				(limit, start)
+			};
+			stmt = stmt.paginate(limit, start);
+		}
+
 		if self.peek_kind() == t!("STRUCTURE") {
 			self.pop_peek();
 			stmt = stmt.structurize();
@@ -619,6 +765,29 @@ impl Parser<'_> {
 		Ok(stmt)
 	}
 
+	/// Parses the optional category after `INFO FOR DATABASE`, like `TABLES` or `FUNCTIONS`.
+	fn parse_info_db_category(&mut self) -> Option<InfoDbCategory> {
+		let peek = self.peek();
+		if !Self::kind_is_identifier(peek.kind) {
+			return None;
+		}
+		let category = match self.lexer.span_str(peek.span).to_ascii_uppercase().as_str() {
+			"ACCESSES" => InfoDbCategory::Accesses,
+			"APIS" => InfoDbCategory::Apis,
+			"ANALYZERS" => InfoDbCategory::Analyzers,
+			"BUCKETS" => InfoDbCategory::Buckets,
+			"FUNCTIONS" => InfoDbCategory::Functions,
+			"MODELS" => InfoDbCategory::Models,
+			"PARAMS" => InfoDbCategory::Params,
+			"TABLES" => InfoDbCategory::Tables,
+			"USERS" => InfoDbCategory::Users,
+			"CONFIGS" => InfoDbCategory::Configs,
+			_ => return None,
+		};
+		self.pop_peek();
+		Some(category)
+	}
+
 	/// Parsers a KILL statement.
 	///
 	/// # Parser State
@@ -744,6 +913,7 @@ impl Parser<'_> {
 		};
 		expected!(self, t!("="));
 		let what = self.parse_value_inherit(ctx).await?;
+		self.shadowed.push(name.clone());
 		Ok(SetStatement {
 			name,
 			what,
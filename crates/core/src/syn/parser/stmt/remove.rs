@@ -39,11 +39,24 @@ impl Parser<'_> {
 				};
 
 				let name = self.next_token_value()?;
+				let detail = self.eat(t!("DETAIL"));
+				let peek = self.peek();
+				let report = Self::kind_is_identifier(peek.kind)
+					&& self.lexer.span_str(peek.span).eq_ignore_ascii_case("REPORT");
+				if report {
+					self.pop_peek();
+				}
+				let dry_run = self.eat(t!("DRY"));
+				let concurrently = self.eat(t!("CONCURRENTLY"));
 
 				RemoveStatement::Namespace(RemoveNamespaceStatement {
 					name,
 					if_exists,
 					expunge,
+					detail,
+					dry_run,
+					concurrently,
+					report,
 				})
 			}
 			t!("DATABASE") => {
@@ -4,11 +4,11 @@ use crate::{
 	sql::{
 		order::{OrderList, Ordering},
 		statements::SelectStatement,
-		Field, Fields, Idioms, Limit, Order, Split, Splits, Start, Values, Version,
+		Field, Fields, Idioms, Limit, Number, Order, Split, Splits, Start, Value, Values, Version,
 	},
 	syn::{
 		parser::{mac::expected, ParseResult, Parser},
-		token::{t, Span},
+		token::{t, Glued, Span, TokenKind},
 	},
 };
 
@@ -200,8 +200,29 @@ impl Parser<'_> {
 			return Ok(None);
 		}
 		self.eat(t!("AT"));
+		// A number directly followed by a `%` which ends the clause is a percentage of the result
+		// set, while `START 10%3` remains a modulo expression
+		if matches!(self.peek_kind(), TokenKind::Digits | TokenKind::Glued(Glued::Number)) {
+			let next = self.glue_and_peek1()?;
+			let after = self.peek_token_at(2);
+			let ends_clause = after.is_eof()
+				|| matches!(
+					after.kind,
+					t!(";")
+						| t!(")") | t!("]")
+						| t!("}") | t!("LIMIT")
+						| t!("FETCH") | t!("VERSION")
+						| t!("TIMEOUT") | t!("PARALLEL")
+						| t!("TEMPFILES") | t!("EXPLAIN")
+				);
+			if next.kind == t!("%") && self.peek().span.is_followed_by(&next.span) && ends_clause {
+				let value = self.next_token_value::<Number>()?;
+				self.pop_peek();
+				return Ok(Some(Start(Value::Number(value), true)));
+			}
+		}
 		let value = ctx.run(|ctx| self.parse_value_field(ctx)).await?;
-		Ok(Some(Start(value)))
+		Ok(Some(Start(value, false)))
 	}
 
 	pub(crate) async fn try_parse_version(
@@ -17,6 +17,10 @@ use crate::{
 				AccessStatementShow,
 			},
 			analyze::AnalyzeStatement,
+			define::config::{
+				graphql::{FunctionsConfig, GqlNaming, GraphQLConfig, NameCase, TablesConfig},
+				ConfigInner, DefineConfigStatement,
+			},
 			show::{ShowSince, ShowStatement},
 			sleep::SleepStatement,
 			AccessStatement, BeginStatement, BreakStatement, CancelStatement, CommitStatement,
@@ -24,13 +28,13 @@ use crate::{
 			DefineDatabaseStatement, DefineEventStatement, DefineFieldStatement,
 			DefineFunctionStatement, DefineIndexStatement, DefineNamespaceStatement,
 			DefineParamStatement, DefineStatement, DefineTableStatement, DeleteStatement,
-			ForeachStatement, IfelseStatement, InfoStatement, InsertStatement, KillStatement,
-			OptionStatement, OutputStatement, RelateStatement, RemoveAccessStatement,
-			RemoveAnalyzerStatement, RemoveDatabaseStatement, RemoveEventStatement,
-			RemoveFieldStatement, RemoveFunctionStatement, RemoveIndexStatement,
-			RemoveNamespaceStatement, RemoveParamStatement, RemoveStatement, RemoveTableStatement,
-			RemoveUserStatement, SelectStatement, SetStatement, ThrowStatement, UpdateStatement,
-			UpsertStatement, UseStatement,
+			ForeachStatement, IfelseStatement, InfoDbCategory, InfoStatement, InsertStatement,
+			KillStatement, OptionStatement, OutputStatement, RelateStatement,
+			RemoveAccessStatement, RemoveAnalyzerStatement, RemoveDatabaseStatement,
+			RemoveEventStatement, RemoveFieldStatement, RemoveFunctionStatement,
+			RemoveIndexStatement, RemoveNamespaceStatement, RemoveParamStatement, RemoveStatement,
+			RemoveTableStatement, RemoveUserStatement, SavepointStatement, SelectStatement,
+			SetStatement, ThrowStatement, UpdateStatement, UpsertStatement, UseStatement,
 		},
 		tokenizer::Tokenizer,
 		user::UserDuration,
@@ -77,9 +81,43 @@ pub fn parse_break() {
 #[test]
 pub fn parse_cancel() {
 	let res = test_parse!(parse_stmt, r#"CANCEL"#).unwrap();
-	assert_eq!(res, Statement::Cancel(CancelStatement));
+	assert_eq!(res, Statement::Cancel(CancelStatement::default()));
 	let res = test_parse!(parse_stmt, r#"CANCEL TRANSACTION"#).unwrap();
-	assert_eq!(res, Statement::Cancel(CancelStatement));
+	assert_eq!(res, Statement::Cancel(CancelStatement::default()));
+	let res = test_parse!(parse_stmt, r#"CANCEL TRANSACTION "invalid balance""#).unwrap();
+	assert_eq!(
+		res,
+		Statement::Cancel(CancelStatement {
+			reason: Some(Strand("invalid balance".to_owned())),
+			savepoint: None,
+		})
+	);
+	assert_eq!(res.to_string(), "CANCEL TRANSACTION 'invalid balance'");
+	let res = test_parse!(parse_stmt, r#"CANCEL 'invalid balance'"#).unwrap();
+	assert_eq!(res.to_string(), "CANCEL TRANSACTION 'invalid balance'");
+	let res = test_parse!(parse_stmt, r#"CANCEL TO SAVEPOINT s1"#).unwrap();
+	assert_eq!(
+		res,
+		Statement::Cancel(CancelStatement {
+			reason: None,
+			savepoint: Some(Ident("s1".to_owned())),
+		})
+	);
+	assert_eq!(res.to_string(), "CANCEL TO SAVEPOINT s1");
+	let res = test_parse!(parse_stmt, r#"CANCEL TRANSACTION TO SAVEPOINT s1 'retry'"#).unwrap();
+	assert_eq!(res.to_string(), "CANCEL TO SAVEPOINT s1 'retry'");
+}
+
+#[test]
+pub fn parse_savepoint() {
+	let res = test_parse!(parse_stmt, r#"SAVEPOINT s1"#).unwrap();
+	assert_eq!(
+		res,
+		Statement::Savepoint(SavepointStatement {
+			name: Ident("s1".to_owned()),
+		})
+	);
+	assert_eq!(res.to_string(), "SAVEPOINT s1");
 }
 
 #[test]
@@ -392,20 +430,99 @@ fn parse_define_user() {
 // TODO(Psmouz): add more
 #[test]
 fn parse_define_config_graphql() {
-    let res = test_parse!(parse_stmt, "DEFINE CONFIG GRAPHQL AUTO CURSOR").unwrap();
-    assert_eq!(
-        res,
-        Statement::Define(DefineStatement::Config(DefineConfigStatement {
-            inner: ConfigInner::GraphQL(GraphQLConfig {
-                tables: TablesConfig::Auto,
-                functions: FunctionsConfig::Auto,
-                cursor: true,
-                ..Default::default()
-            }),
-            if_not_exists: false,
-            overwrite: false,
-        }))
-    );
+	let res = test_parse!(parse_stmt, "DEFINE CONFIG GRAPHQL AUTO CURSOR").unwrap();
+	assert_eq!(
+		res,
+		Statement::Define(DefineStatement::Config(DefineConfigStatement {
+			inner: ConfigInner::GraphQL(GraphQLConfig {
+				tables: TablesConfig::Auto,
+				functions: FunctionsConfig::Auto,
+				cursor: true,
+				..Default::default()
+			}),
+			if_not_exists: false,
+			overwrite: false,
+		}))
+	);
+}
+
+#[test]
+fn parse_define_config_graphql_naming() {
+	let res = test_parse!(parse_stmt, "DEFINE CONFIG GRAPHQL AUTO NAMING VERBATIM").unwrap();
+	let Statement::Define(DefineStatement::Config(DefineConfigStatement {
+		inner: ConfigInner::GraphQL(config),
+		..
+	})) = res
+	else {
+		panic!("expected a GraphQL config, found: {res:?}")
+	};
+	assert_eq!(config.naming, GqlNaming::Verbatim);
+
+	let res = test_parse!(parse_stmt, "DEFINE CONFIG GRAPHQL AUTO NAMING TYPES SNAKE FIELDS CAMEL")
+		.unwrap();
+	let Statement::Define(DefineStatement::Config(DefineConfigStatement {
+		inner: ConfigInner::GraphQL(config),
+		..
+	})) = res
+	else {
+		panic!("expected a GraphQL config, found: {res:?}")
+	};
+	assert_eq!(
+		config.naming,
+		GqlNaming::Custom {
+			types: NameCase::Snake,
+			fields: NameCase::Camel,
+		}
+	);
+	assert_eq!(
+		config.to_string(),
+		" GRAPHQL TABLES AUTO FUNCTIONS AUTO NAMING TYPES SNAKE FIELDS CAMEL"
+	);
+
+	test_parse!(parse_stmt, "DEFINE CONFIG GRAPHQL AUTO NAMING KEBAB").unwrap_err();
+}
+
+#[test]
+fn parse_define_config_graphql_lists() {
+	let res = test_parse!(parse_stmt, "DEFINE CONFIG GRAPHQL AUTO LISTS WRAPPED").unwrap();
+	let Statement::Define(DefineStatement::Config(DefineConfigStatement {
+		inner: ConfigInner::GraphQL(config),
+		..
+	})) = res
+	else {
+		panic!("expected a GraphQL config, found: {res:?}")
+	};
+	assert!(config.wrap_lists);
+	assert_eq!(config.to_string(), " GRAPHQL TABLES AUTO FUNCTIONS AUTO LISTS WRAPPED");
+
+	let res = test_parse!(parse_stmt, "DEFINE CONFIG GRAPHQL AUTO LISTS BARE").unwrap();
+	let Statement::Define(DefineStatement::Config(DefineConfigStatement {
+		inner: ConfigInner::GraphQL(config),
+		..
+	})) = res
+	else {
+		panic!("expected a GraphQL config, found: {res:?}")
+	};
+	assert!(!config.wrap_lists);
+
+	test_parse!(parse_stmt, "DEFINE CONFIG GRAPHQL AUTO LISTS PAGED").unwrap_err();
+}
+
+#[test]
+fn parse_define_config_graphql_limits() {
+	let res = test_parse!(parse_stmt, "DEFINE CONFIG GRAPHQL AUTO DEPTH 8 COMPLEXITY 200").unwrap();
+	let Statement::Define(DefineStatement::Config(DefineConfigStatement {
+		inner: ConfigInner::GraphQL(config),
+		..
+	})) = res
+	else {
+		panic!("expected a GraphQL config, found: {res:?}")
+	};
+	assert_eq!(config.depth_limit, Some(8));
+	assert_eq!(config.complexity_limit, Some(200));
+	assert_eq!(config.to_string(), " GRAPHQL TABLES AUTO FUNCTIONS AUTO DEPTH 8 COMPLEXITY 200");
+
+	test_parse!(parse_stmt, "DEFINE CONFIG GRAPHQL AUTO DEPTH deep").unwrap_err();
 }
 
 // TODO(gguillemas): This test is kept in 2.0.0 for backward compatibility. Drop in 3.0.0.
@@ -2277,8 +2394,62 @@ fn parse_info() {
 	let res = test_parse!(parse_stmt, "INFO FOR NS").unwrap();
 	assert_eq!(res, Statement::Info(InfoStatement::Ns(false)));
 
+	let res = test_parse!(parse_stmt, "INFO FOR DATABASE").unwrap();
+	assert_eq!(res, Statement::Info(InfoStatement::Db(false, None, None, None, None)));
+
+	let res = test_parse!(parse_stmt, "INFO FOR DB TABLES STRUCTURE").unwrap();
+	assert_eq!(
+		res,
+		Statement::Info(InfoStatement::Db(true, None, Some(InfoDbCategory::Tables), None, None))
+	);
+
+	let res = test_parse!(parse_stmt, "INFO FOR DATABASE functions").unwrap();
+	assert_eq!(
+		res,
+		Statement::Info(InfoStatement::Db(
+			false,
+			None,
+			Some(InfoDbCategory::Functions),
+			None,
+			None
+		))
+	);
+
+	let res = test_parse!(parse_stmt, "INFO FOR DB TABLES LIMIT 10 START 20 STRUCTURE").unwrap();
+	assert_eq!(
+		res,
+		Statement::Info(InfoStatement::Db(
+			true,
+			None,
+			Some(InfoDbCategory::Tables),
+			Some(Limit(Value::Number(Number::Int(10)))),
+			Some(Start(Value::Number(Number::Int(20)), false))
+		))
+	);
+
+	let res = test_parse!(parse_stmt, "INFO FOR DB START 5").unwrap();
+	assert_eq!(
+		res,
+		Statement::Info(InfoStatement::Db(
+			false,
+			None,
+			None,
+			None,
+			Some(Start(Value::Number(Number::Int(5)), false))
+		))
+	);
+
 	let res = test_parse!(parse_stmt, "INFO FOR TABLE table").unwrap();
-	assert_eq!(res, Statement::Info(InfoStatement::Tb(Ident("table".to_owned()), false, None)));
+	assert_eq!(
+		res,
+		Statement::Info(InfoStatement::Tb(Ident("table".to_owned()), false, None, false))
+	);
+
+	let res = test_parse!(parse_stmt, "INFO FOR TABLE table STATS STRUCTURE").unwrap();
+	assert_eq!(
+		res,
+		Statement::Info(InfoStatement::Tb(Ident("table".to_owned()), true, None, true))
+	);
 
 	let res = test_parse!(parse_stmt, "INFO FOR USER user").unwrap();
 	assert_eq!(res, Statement::Info(InfoStatement::User(Ident("user".to_owned()), None, false)));
@@ -2290,6 +2461,52 @@ fn parse_info() {
 	);
 }
 
+#[test]
+fn parse_info_api() {
+	let settings = ParserSettings {
+		define_api_enabled: true,
+		..Default::default()
+	};
+	let res =
+		test_parse_with_settings!(parse_stmt, r#"INFO FOR API "/users/:id""#, settings.clone())
+			.unwrap();
+	assert_eq!(res, Statement::Info(InfoStatement::Api(Ident("/users/:id".to_owned()))));
+
+	let res =
+		test_parse_with_settings!(parse_stmt, "INFO FOR API ⟨/test⟩ STRUCTURE", settings).unwrap();
+	assert_eq!(res, Statement::Info(InfoStatement::Api(Ident("/test".to_owned()))));
+
+	test_parse!(parse_stmt, r#"INFO FOR API "/test""#).unwrap_err();
+}
+
+#[test]
+fn parse_info_bucket() {
+	let settings = ParserSettings {
+		files_enabled: true,
+		..Default::default()
+	};
+	let res =
+		test_parse_with_settings!(parse_stmt, "INFO FOR BUCKET test", settings.clone()).unwrap();
+	assert_eq!(
+		res,
+		Statement::Info(InfoStatement::Bucket(Ident("test".to_owned()), false, None, None))
+	);
+
+	let res = test_parse_with_settings!(parse_stmt, "INFO FOR BUCKET test FILES LIMIT 2", settings)
+		.unwrap();
+	assert_eq!(
+		res,
+		Statement::Info(InfoStatement::Bucket(
+			Ident("test".to_owned()),
+			true,
+			Some(Limit(Value::Number(Number::Int(2)))),
+			None
+		))
+	);
+
+	test_parse!(parse_stmt, "INFO FOR BUCKET test").unwrap_err();
+}
+
 #[test]
 fn parse_select() {
 	let res = test_parse!(
@@ -2368,9 +2585,10 @@ SELECT bar as foo,[1,2],bar OMIT bar FROM ONLY a,1
 				tb: "a".to_owned(),
 				id: Id::from("b"),
 			}))),
-			start: Some(Start(Value::Object(Object(
-				[("a".to_owned(), Value::Bool(true))].into_iter().collect()
-			)))),
+			start: Some(Start(
+				Value::Object(Object([("a".to_owned(), Value::Bool(true))].into_iter().collect())),
+				false,
+			)),
 			fetch: Some(Fetchs(vec![Fetch(Value::Idiom(Idiom(vec![Part::Field(Ident(
 				"foo".to_owned()
 			))])))])),
@@ -2757,6 +2975,34 @@ fn parse_relate() {
 	)
 }
 
+#[test]
+fn parse_select_start_percentage() {
+	let res = test_parse!(parse_stmt, r#"SELECT * FROM a START 10%"#).unwrap();
+	let Statement::Select(stmt) = res else {
+		panic!("expected a select statement")
+	};
+	assert_eq!(stmt.start, Some(Start(Value::Number(Number::Int(10)), true)));
+	assert_eq!(stmt.to_string(), "SELECT * FROM a START 10%");
+
+	let res = test_parse!(parse_stmt, r#"SELECT * FROM a START 10 % 3"#).unwrap();
+	let Statement::Select(stmt) = res else {
+		panic!("expected a select statement")
+	};
+	assert!(!stmt.start.unwrap().1);
+
+	let res = test_parse!(parse_stmt, r#"SELECT * FROM a START 10%3"#).unwrap();
+	let Statement::Select(stmt) = res else {
+		panic!("expected a select statement")
+	};
+	assert!(!stmt.start.unwrap().1);
+
+	let res = test_parse!(parse_stmt, r#"SELECT * FROM a LIMIT 5 START 10% FETCH b"#).unwrap();
+	let Statement::Select(stmt) = res else {
+		panic!("expected a select statement")
+	};
+	assert!(stmt.start.unwrap().1);
+}
+
 #[test]
 fn parse_remove() {
 	let res = test_parse!(parse_stmt, r#"REMOVE NAMESPACE ns"#).unwrap();
@@ -2766,6 +3012,66 @@ fn parse_remove() {
 			name: Ident("ns".to_owned()),
 			if_exists: false,
 			expunge: false,
+			detail: false,
+			dry_run: false,
+			concurrently: false,
+			report: false,
+		}))
+	);
+
+	let res = test_parse!(parse_stmt, r#"REMOVE NAMESPACE IF EXISTS ns DETAIL"#).unwrap();
+	assert_eq!(
+		res,
+		Statement::Remove(RemoveStatement::Namespace(RemoveNamespaceStatement {
+			name: Ident("ns".to_owned()),
+			if_exists: true,
+			expunge: false,
+			detail: true,
+			dry_run: false,
+			concurrently: false,
+			report: false,
+		}))
+	);
+
+	let res = test_parse!(parse_stmt, r#"REMOVE NAMESPACE ns DRY"#).unwrap();
+	assert_eq!(
+		res,
+		Statement::Remove(RemoveStatement::Namespace(RemoveNamespaceStatement {
+			name: Ident("ns".to_owned()),
+			if_exists: false,
+			expunge: false,
+			detail: false,
+			dry_run: true,
+			concurrently: false,
+			report: false,
+		}))
+	);
+
+	let res = test_parse!(parse_stmt, r#"REMOVE NAMESPACE IF EXISTS ns DETAIL REPORT"#).unwrap();
+	assert_eq!(
+		res,
+		Statement::Remove(RemoveStatement::Namespace(RemoveNamespaceStatement {
+			name: Ident("ns".to_owned()),
+			if_exists: true,
+			expunge: false,
+			detail: true,
+			dry_run: false,
+			concurrently: false,
+			report: true,
+		}))
+	);
+
+	let res = test_parse!(parse_stmt, r#"REMOVE NAMESPACE AND EXPUNGE ns CONCURRENTLY"#).unwrap();
+	assert_eq!(
+		res,
+		Statement::Remove(RemoveStatement::Namespace(RemoveNamespaceStatement {
+			name: Ident("ns".to_owned()),
+			if_exists: false,
+			expunge: true,
+			detail: false,
+			dry_run: false,
+			concurrently: true,
+			report: false,
 		}))
 	);
 
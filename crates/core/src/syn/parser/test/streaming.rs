@@ -134,8 +134,8 @@ fn statements() -> Vec<Statement> {
 		Statement::Begin(BeginStatement),
 		Statement::Begin(BeginStatement),
 		Statement::Break(BreakStatement),
-		Statement::Cancel(CancelStatement),
-		Statement::Cancel(CancelStatement),
+		Statement::Cancel(CancelStatement::default()),
+		Statement::Cancel(CancelStatement::default()),
 		Statement::Commit(CommitStatement),
 		Statement::Commit(CommitStatement),
 		Statement::Continue(ContinueStatement),
@@ -529,9 +529,10 @@ fn statements() -> Vec<Statement> {
 				tb: "a".to_owned(),
 				id: Id::from("b"),
 			}))),
-			start: Some(Start(Value::Object(Object(
-				[("a".to_owned(), Value::Bool(true))].into_iter().collect(),
-			)))),
+			start: Some(Start(
+				Value::Object(Object([("a".to_owned(), Value::Bool(true))].into_iter().collect())),
+				false,
+			)),
 			fetch: Some(Fetchs(vec![Fetch(Value::Idiom(Idiom(vec![Part::Field(Ident(
 				"foo".to_owned(),
 			))])))])),
@@ -26,6 +26,7 @@ impl Parser<'_> {
 				| t!("RETURN")
 				| t!("RELATE")
 				| t!("REMOVE")
+				| t!("SAVEPOINT")
 				| t!("SELECT")
 				| t!("LET") | t!("SHOW")
 				| t!("SLEEP")
@@ -128,6 +129,7 @@ impl Parser<'_> {
 				| t!("FOR") | t!("INFO")
 				| t!("KILL") | t!("LIVE")
 				| t!("OPTION")
+				| t!("SAVEPOINT")
 				| t!("LET") | t!("SHOW")
 				| t!("SLEEP")
 				| t!("THROW")
@@ -1,12 +1,19 @@
 use reblessive::Stack;
+use std::collections::BTreeMap;
 
-use super::lexer::Lexer;
+use super::error::MessageKind;
+use super::lexer::{CommentKind, Lexer};
 use super::parse;
-use super::parser::Parser;
+use super::parser::{Parser, ParserSettings};
 use super::Parse;
+use crate::dbs::capabilities::Targets;
+use crate::dbs::Capabilities;
 use crate::err::Error;
-use crate::sql::{Array, Expression, Ident, Idiom, Param, Script, Thing, Value};
-use crate::syn::token::{t, TokenKind};
+use crate::sql::{
+	kind::Literal, Array, Expression, Geometry, Ident, Idiom, Kind, Param, Permission, Permissions,
+	Script, Statement, Thing, Value,
+};
+use crate::syn::token::{t, Glued, TokenKind};
 
 impl Parse<Self> for Value {
 	fn parse(val: &str) -> Self {
@@ -139,3 +146,833 @@ fn empty_subquery() {
 fn empty_json() {
 	super::json("").unwrap_err();
 }
+
+#[test]
+fn parse_with_zero_limits() {
+	let capabilities = Capabilities::all();
+	super::parse_with_limits("RETURN 1", &capabilities, 0, 10).unwrap_err();
+	super::parse_with_limits("RETURN 1", &capabilities, 10, 0).unwrap_err();
+}
+
+#[test]
+fn parse_with_custom_limits() {
+	let capabilities = Capabilities::all();
+	let q = "RETURN [[[1]]]";
+	super::parse_with_limits(q, &capabilities, 2, 10).unwrap_err();
+	let res = super::parse_with_limits(q, &capabilities, 10, 10).unwrap();
+	assert_eq!(res, parse(q).unwrap());
+}
+
+#[test]
+fn estimate_complexity_counts() {
+	let stats = super::estimate_complexity("").unwrap();
+	assert_eq!(stats, super::QueryStats::default());
+
+	let q = r#"
+		LET $a = "a; (SELECT * FROM b)";;
+		SELECT *, string::len(name), (SELECT * FROM ->likes) AS likes FROM person
+			WHERE count(tags) > 1 AND $a.len() > 0;
+		CREATE foo CONTENT { bar: [{ baz: type::string(1) }] };
+	"#;
+	let stats = super::estimate_complexity(q).unwrap();
+	assert_eq!(stats.statements, 3);
+	assert_eq!(stats.subqueries, 1);
+	assert_eq!(stats.functions, 4);
+	assert_eq!(stats.depth, 4);
+
+	let q = "IF true { RETURN (SELECT * FROM (SELECT * FROM foo)); }";
+	let stats = super::estimate_complexity(q).unwrap();
+	assert_eq!(stats.statements, 1);
+	assert_eq!(stats.subqueries, 2);
+	assert_eq!(stats.functions, 0);
+	assert_eq!(stats.depth, 3);
+}
+
+#[test]
+fn estimate_complexity_lexer_error() {
+	super::estimate_complexity("RETURN 'unterminated").unwrap_err();
+}
+
+#[test]
+fn parse_with_legacy_strands() {
+	let q = "CREATE foo SET a = 'person:tobie', b = ['person:jaime'], c = 'hello'";
+	let settings = ParserSettings {
+		legacy_strands: true,
+		..Default::default()
+	};
+	let res = super::parse_with_settings(q, settings).unwrap();
+	let expected =
+		parse("CREATE foo SET a = person:tobie, b = [person:jaime], c = 'hello'").unwrap();
+	assert_eq!(res, expected);
+	// Without legacy strands the strings are left alone.
+	let res = super::parse_with_settings(q, ParserSettings::default()).unwrap();
+	assert_eq!(res, parse(q).unwrap());
+	assert_ne!(res, expected);
+}
+
+#[test]
+fn parse_with_settings_zero_limits() {
+	let settings = ParserSettings {
+		object_recursion_limit: 0,
+		..Default::default()
+	};
+	super::parse_with_settings("RETURN 1", settings).unwrap_err();
+}
+
+#[test]
+fn parse_with_comments() {
+	let q = "-- leading\nSELECT * FROM foo; // trailing\n/* block\n comment */ RETURN 1 # last";
+	let (res, comments) = super::parse_with_comments(q, ParserSettings::default()).unwrap();
+	assert_eq!(res, parse(q).unwrap());
+	let found: Vec<_> = comments
+		.iter()
+		.map(|c| (c.kind, &q[c.span.offset as usize..][..c.span.len as usize]))
+		.collect();
+	assert_eq!(
+		found,
+		[
+			(CommentKind::Line, "-- leading"),
+			(CommentKind::Line, "// trailing"),
+			(CommentKind::Block, "/* block\n comment */"),
+			(CommentKind::Line, "# last"),
+		]
+	);
+	// Comments are only collected when asked for.
+	let mut parser = Parser::new(q.as_bytes());
+	let mut stack = Stack::new();
+	stack.enter(|stk| parser.parse_query(stk)).finish().unwrap();
+	assert!(parser.take_comments().is_empty());
+}
+
+#[test]
+fn parse_statements_spans() {
+	let q = "  SELECT * FROM foo;\n\tRETURN 1 ;;CREATE bar  ";
+	let res = super::parse_statements(q).unwrap();
+	assert_eq!(res.len(), 3);
+	let spans: Vec<_> = res.iter().map(|(_, span)| &q[span.clone()]).collect();
+	assert_eq!(spans, ["SELECT * FROM foo", "RETURN 1", "CREATE bar"]);
+	let statements: Vec<_> = res.into_iter().map(|(stmt, _)| stmt).collect();
+	assert_eq!(statements, parse(q).unwrap().0 .0);
+}
+
+#[test]
+fn parse_statements_trailing_semicolons() {
+	let q = "RETURN 1;RETURN 2;;;";
+	let res = super::parse_statements(q).unwrap();
+	assert_eq!(res.len(), 2);
+	assert_eq!(res[0].1, 0..8);
+	assert_eq!(res[1].1, 9..17);
+}
+
+#[test]
+fn parse_statements_comments() {
+	let q = "-- first\nRETURN 1; /* second */ RETURN 2 # trailing\n;";
+	let res = super::parse_statements(q).unwrap();
+	let spans: Vec<_> = res.iter().map(|(_, span)| &q[span.clone()]).collect();
+	assert_eq!(spans, ["RETURN 1", "RETURN 2"]);
+}
+
+#[test]
+fn parse_one_remainder() {
+	let q = ";; RETURN 1; SELECT * FROM foo;\nCREATE bar -- done\n";
+	let (stmt, rest) = super::parse_one(q).unwrap();
+	assert_eq!(stmt, parse("RETURN 1").unwrap().0 .0[0]);
+	assert_eq!(rest, " SELECT * FROM foo;\nCREATE bar -- done\n");
+	let (stmt, rest) = super::parse_one(rest).unwrap();
+	assert_eq!(stmt, parse("SELECT * FROM foo").unwrap().0 .0[0]);
+	assert_eq!(rest, "\nCREATE bar -- done\n");
+	let (stmt, rest) = super::parse_one(rest).unwrap();
+	assert_eq!(stmt, parse("CREATE bar").unwrap().0 .0[0]);
+	assert_eq!(rest, "");
+}
+
+#[test]
+fn parse_one_invalid() {
+	super::parse_one("").unwrap_err();
+	super::parse_one(" ;; ").unwrap_err();
+	super::parse_one("RETURN 1 RETURN 2").unwrap_err();
+	super::parse_one("SELECT FROM; RETURN 1").unwrap_err();
+}
+
+#[test]
+fn parse_recover_collects_errors() {
+	let q = "RETURN 1; SELECT FROM; RETURN [1, ';' ; CREATE foo; RETURN 2";
+	let (query, errors) = super::parse_recover(q);
+	let query = query.unwrap();
+	assert_eq!(query, parse("RETURN 1; CREATE foo; RETURN 2").unwrap());
+	assert_eq!(errors.len(), 2);
+	for e in errors {
+		assert!(!e.render_on(q).snippets.is_empty());
+	}
+}
+
+#[test]
+fn invalid_query_spans() {
+	let q = "RETURN 1;\nRETURN [1, 2 3];";
+	let Err(Error::InvalidQuery(e)) = parse(q) else {
+		panic!("query should fail to parse");
+	};
+	let snippet = &e.snippets[0];
+	let span = snippet.span().unwrap();
+	assert_eq!(&q[span.offset as usize..(span.offset + span.len) as usize], "3");
+	assert_eq!(snippet.kind(), MessageKind::Error);
+	assert_eq!(snippet.location().line, 2);
+	assert_eq!(snippet.location().column, 14);
+	// The rendered message is still available.
+	assert!(e.to_string().contains("--> [2:14]"));
+}
+
+#[test]
+fn parse_recover_missing_semicolon() {
+	let q = "RETURN 1 RETURN 2; RETURN 3 4; RETURN 5";
+	let (query, errors) = super::parse_recover(q);
+	assert_eq!(query.unwrap(), parse("RETURN 1; RETURN 2; RETURN 5").unwrap());
+	assert_eq!(errors.len(), 2);
+}
+
+#[test]
+fn parse_recover_only_errors() {
+	let (query, errors) = super::parse_recover("SELECT FROM; RETURN )");
+	assert!(query.is_none());
+	assert_eq!(errors.len(), 2);
+
+	let (query, errors) = super::parse_recover(") ; ]; RETURN 1");
+	assert_eq!(query.unwrap(), parse("RETURN 1").unwrap());
+	assert_eq!(errors.len(), 2);
+
+	let (query, errors) = super::parse_recover("");
+	assert_eq!(query.unwrap(), parse("").unwrap());
+	assert!(errors.is_empty());
+}
+
+#[test]
+fn empty_param() {
+	super::param("").unwrap_err();
+}
+
+#[test]
+fn param_names() {
+	assert_eq!(super::param("$foo").unwrap(), Param::from("foo"));
+	assert_eq!(super::param("  $foo_bar2 ").unwrap(), Param::from("foo_bar2"));
+	assert_eq!(super::param("$`foo bar`").unwrap(), Param::from("foo bar"));
+	super::param("foo").unwrap_err();
+	super::param("$").unwrap_err();
+	super::param("$foo.bar").unwrap_err();
+	super::param("$foo $bar").unwrap_err();
+}
+
+#[test]
+fn param_reserved_keywords() {
+	assert_eq!(super::param("$select").unwrap(), Param::from("select"));
+	assert_eq!(super::param("$VALUE").unwrap(), Param::from("VALUE"));
+	super::param("SELECT").unwrap_err();
+}
+
+#[test]
+fn empty_function_signature() {
+	super::function_signature("").unwrap_err();
+}
+
+#[test]
+fn function_signature_no_args() {
+	let res = super::function_signature("fn::foo::bar()").unwrap();
+	assert_eq!(res.name, Ident::from("foo::bar"));
+	assert!(res.args.is_empty());
+	assert_eq!(res.returns, None);
+	assert_eq!(res.to_string(), "fn::foo::bar()");
+}
+
+#[test]
+fn function_signature_args() {
+	let res = super::function_signature("fn::add($a: int, $b: option<int>) -> int").unwrap();
+	assert_eq!(res.name, Ident::from("add"));
+	let b = Kind::Option(Box::new(Kind::Int));
+	assert_eq!(res.args, vec![(Ident::from("a"), Kind::Int), (Ident::from("b"), b)]);
+	assert_eq!(res.returns, Some(Kind::Int));
+	assert_eq!(res.to_string(), "fn::add($a: int, $b: option<int>) -> int");
+}
+
+#[test]
+fn function_signature_invalid() {
+	super::function_signature("add($a: int)").unwrap_err();
+	super::function_signature("fn::add($a)").unwrap_err();
+	super::function_signature("fn::add($a: int").unwrap_err();
+	super::function_signature("fn::add(a: int)").unwrap_err();
+	super::function_signature("fn::add() ->").unwrap_err();
+	let err = super::function_signature("fn::add($a: int) { RETURN $a; }").unwrap_err();
+	assert!(err.to_string().contains("1:18"), "{err}");
+}
+
+#[test]
+fn value_with_bound_params() {
+	let params = BTreeMap::from([
+		("name".to_owned(), Value::from("Tobie")),
+		("age".to_owned(), Value::from(30)),
+	]);
+	let res = super::value_with_params("{ name: $name, tags: [$age, 1] }", &params).unwrap();
+	assert_eq!(res, super::value("{ name: 'Tobie', tags: [30, 1] }").unwrap());
+	let res = super::value_with_params("$age + 1", &params).unwrap();
+	assert_eq!(res, super::value("30 + 1").unwrap());
+}
+
+#[test]
+fn value_with_unbound_params() {
+	let params = BTreeMap::from([("name".to_owned(), Value::from("Tobie"))]);
+	let err = super::value_with_params("[$name, $age]", &params).unwrap_err();
+	assert!(err.to_string().contains("Parameter `$age` is not bound"), "{err}");
+	super::value_with_params("|$a| $a + $b", &params).unwrap_err();
+	super::value_with_params("{ LET $a = 1; $b }", &params).unwrap_err();
+}
+
+#[test]
+fn value_with_shadowed_params() {
+	let params = BTreeMap::from([("a".to_owned(), Value::from(10))]);
+	let res = super::value_with_params("[$a, |$a| $a + 1]", &params).unwrap();
+	assert_eq!(res, super::value("[10, |$a| $a + 1]").unwrap());
+	let res = super::value_with_params("{ LET $a = $a + 1; RETURN $a; }", &params).unwrap();
+	assert_eq!(res, super::value("{ LET $a = 10 + 1; RETURN $a; }").unwrap());
+	let res = super::value_with_params("[{ LET $a = 1; $a }, $a]", &params).unwrap();
+	assert_eq!(res, super::value("[{ LET $a = 1; $a }, 10]").unwrap());
+}
+
+#[test]
+fn value_uses_experimental() {
+	use crate::dbs::capabilities::{ExperimentalTarget, Targets};
+
+	let none = Capabilities::default();
+	let all = Capabilities::default().with_experimental(Targets::All);
+	let files = Capabilities::default()
+		.with_experimental(Targets::Some([ExperimentalTarget::Files].into()));
+
+	let q = "[f\"bucket:/key\", <references<foo>> []]";
+	assert_eq!(
+		super::value_uses_experimental(q, &none).unwrap(),
+		[ExperimentalTarget::RecordReferences, ExperimentalTarget::Files]
+	);
+	assert_eq!(
+		super::value_uses_experimental(q, &files).unwrap(),
+		[ExperimentalTarget::RecordReferences]
+	);
+	assert!(super::value_uses_experimental(q, &all).unwrap().is_empty());
+
+	assert!(super::value_uses_experimental("{ a: 1, b: 'f\"x:/y\"' }", &none).unwrap().is_empty());
+	super::value_uses_experimental("[f\"bucket:/key\"", &all).unwrap_err();
+}
+
+#[test]
+fn value_list_mixed() {
+	let res = super::value_list(
+		r#"1, 'a, b', "c", [2, 'd, e'], { f: [3, 4], g: 'h, i' }, person:tobie, NONE"#,
+	)
+	.unwrap();
+	assert_eq!(
+		res,
+		vec![
+			Value::from(1),
+			Value::from("a, b"),
+			Value::from("c"),
+			super::value("[2, 'd, e']").unwrap(),
+			super::value("{ f: [3, 4], g: 'h, i' }").unwrap(),
+			Value::from(Thing::from(("person", "tobie"))),
+			Value::None,
+		]
+	);
+	assert_eq!(super::value_list(" 1 ").unwrap(), vec![Value::from(1)]);
+}
+
+#[test]
+fn value_list_invalid() {
+	super::value_list("").unwrap_err();
+	super::value_list("1, 2,").unwrap_err();
+	super::value_list("1,, 2").unwrap_err();
+	super::value_list(", 1").unwrap_err();
+	super::value_list("1 2").unwrap_err();
+	super::value_list("[1, 2").unwrap_err();
+}
+
+#[test]
+fn permissions_full_none() {
+	assert_eq!(super::permissions("FULL").unwrap(), Permissions::full());
+	assert_eq!(super::permissions("NONE").unwrap(), Permissions::none());
+	assert_eq!(super::permissions("PERMISSIONS FULL").unwrap(), Permissions::full());
+}
+
+#[test]
+fn permissions_specific() {
+	let res =
+		super::permissions("FOR select WHERE published = true, FOR create NONE, FOR update FULL")
+			.unwrap();
+	assert_eq!(
+		res,
+		Permissions {
+			select: Permission::Specific(super::value("published = true").unwrap()),
+			create: Permission::None,
+			update: Permission::Full,
+			delete: Permission::None,
+		}
+	);
+	let res = super::permissions("PERMISSIONS FOR select, delete WHERE user = $auth.id").unwrap();
+	let cond = Permission::Specific(super::value("user = $auth.id").unwrap());
+	assert_eq!(
+		res,
+		Permissions {
+			select: cond.clone(),
+			create: Permission::None,
+			update: Permission::None,
+			delete: cond,
+		}
+	);
+}
+
+#[test]
+fn permissions_invalid() {
+	super::permissions("").unwrap_err();
+	super::permissions("PERMISSIONS").unwrap_err();
+	super::permissions("FOR select").unwrap_err();
+	super::permissions("FOR relate FULL").unwrap_err();
+	let Err(Error::InvalidQuery(e)) = super::permissions("FULL FOR select NONE") else {
+		panic!("trailing tokens should be rejected");
+	};
+	assert!(!e.snippets.is_empty());
+}
+
+#[test]
+fn empty_json_strict() {
+	super::json_strict("").unwrap_err();
+	super::json_strict("  ").unwrap_err();
+}
+
+#[test]
+fn json_strict_valid() {
+	let input = r#" {
+		"name": "Tobie \"T\" \u00e9\ud83d\ude00",
+		"age": -30,
+		"score": 1.5e2,
+		"tags": [true, false, null, [], {}],
+		"id": "person:tobie"
+	} "#;
+	let res = super::json_strict(input).unwrap();
+	assert_eq!(
+		res,
+		super::value(
+			r#"{ age: -30, id: "person:tobie", name: "Tobie \"T\" é😀", score: 150f, tags: [true, false, NULL, [], {}] }"#
+		)
+		.unwrap()
+	);
+	assert_eq!(super::json_strict("0").unwrap(), Value::from(0));
+	assert_eq!(super::json_strict("-0.5").unwrap(), Value::from(-0.5));
+	assert_eq!(super::json_strict("\"a/b\"").unwrap(), Value::from("a/b"));
+}
+
+#[test]
+fn json_strict_rejects_extensions() {
+	let invalid = [
+		"[1, 2,]",
+		"{ \"a\": 1, }",
+		"{ a: 1 }",
+		"'foo'",
+		"NULL",
+		"True",
+		"person:tobie",
+		"1d",
+		"1f",
+		"+1",
+		"01",
+		"1.",
+		".5",
+		"NaN",
+		"[1] /* comment */",
+		"// comment\n1",
+		"\"tab\there\"",
+		"\"\\u{61}\"",
+		"\"\\'\"",
+		"[1] [2]",
+		"{ \"a\" 1 }",
+	];
+	for input in invalid {
+		assert!(super::json_strict(input).is_err(), "`{input}` should be rejected");
+	}
+}
+
+#[test]
+fn json_strict_error_span() {
+	let err = super::json_strict("[1, 2,\n]").unwrap_err();
+	let err = err.to_string();
+	assert!(err.contains("Trailing commas are not allowed"), "{err}");
+	assert!(err.contains("2:1"), "{err}");
+}
+
+#[test]
+fn empty_fields() {
+	super::fields("").unwrap_err();
+}
+
+#[test]
+fn fields_round_trip() {
+	for q in [
+		"*",
+		"name, age AS years",
+		"->likes->post[*]",
+		"VALUE name",
+		"VALUE <-wrote<-person.name AS authors",
+		"*, math::sum(scores) AS total",
+	] {
+		let fields = super::fields(q).unwrap();
+		assert_eq!(fields.to_string(), q);
+		assert_eq!(super::fields(&fields.to_string()).unwrap(), fields);
+	}
+	assert_eq!(
+		super::fields("->likes->post.*").unwrap(),
+		super::fields("->likes->post[*]").unwrap()
+	);
+}
+
+#[test]
+fn fields_trailing_tokens() {
+	super::fields("name FROM person").unwrap_err();
+	super::fields("name,").unwrap_err();
+}
+
+#[test]
+fn empty_cond() {
+	super::cond("").unwrap_err();
+}
+
+#[test]
+fn cond_matches_where_clause() {
+	for c in [
+		"age > 18",
+		"name = 'Tobie' AND (age >= 18 OR admin = true)",
+		"(a < 1 OR b != 2) AND c <= 3",
+		"tags CONTAINS 'rust'",
+	] {
+		let Statement::Select(select) =
+			parse(&format!("SELECT * FROM t WHERE {c}")).unwrap().0 .0.remove(0)
+		else {
+			panic!("expected a select statement");
+		};
+		assert_eq!(super::cond(c).unwrap(), select.cond.unwrap());
+	}
+}
+
+#[test]
+fn cond_trailing_tokens() {
+	super::cond("age > 18 ORDER BY age").unwrap_err();
+	super::cond("age > 18)").unwrap_err();
+}
+
+#[test]
+fn tokenize_query() {
+	let q = r#"SELECT name, math::max(age) FROM person:tobie WHERE bio = "a \"quoted\" ; bio" AND age >= 1.5 -- comment"#;
+	let tokens = super::tokenize(q).unwrap();
+	let texts: Vec<_> = tokens
+		.iter()
+		.map(|t| &q[t.span.offset as usize..(t.span.offset + t.span.len) as usize])
+		.collect();
+	assert_eq!(
+		texts,
+		[
+			"SELECT",
+			"name",
+			",",
+			"math",
+			"::",
+			"max",
+			"(",
+			"age",
+			")",
+			"FROM",
+			"person",
+			":",
+			"tobie",
+			"WHERE",
+			"bio",
+			"=",
+			r#""a \"quoted\" ; bio""#,
+			"AND",
+			"age",
+			">=",
+			"1",
+			".",
+			"5"
+		]
+	);
+	assert_eq!(tokens[0].kind, t!("SELECT"));
+	assert!(super::could_be_reserved_keyword(texts[0]));
+	assert_eq!(tokens[1].kind, TokenKind::Identifier);
+	assert_eq!(tokens[16].kind, TokenKind::Glued(Glued::Strand));
+	assert_eq!(tokens[19].kind, t!(">="));
+	assert_eq!(tokens[20].kind, TokenKind::Digits);
+
+	let tokens = super::tokenize("select d'2024-01-01T00:00:00Z'").unwrap();
+	assert_eq!(tokens[0].kind, t!("SELECT"));
+	assert_eq!(tokens[1].kind, TokenKind::Glued(Glued::Datetime));
+}
+
+#[test]
+fn tokenize_errors() {
+	super::tokenize(r#"SELECT "unterminated"#).unwrap_err();
+	super::tokenize(r#"RETURN "invalid \q escape""#).unwrap_err();
+	super::tokenize("RETURN a & b").unwrap_err();
+	assert!(super::tokenize("  -- only a comment").unwrap().is_empty());
+}
+
+/// A reader which returns its data a couple of bytes at a time.
+struct ChunkedReader<'a>(&'a [u8]);
+
+impl std::io::Read for ChunkedReader<'_> {
+	fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+		let len = self.0.len().min(buf.len()).min(3);
+		buf[..len].copy_from_slice(&self.0[..len]);
+		self.0 = &self.0[len..];
+		Ok(len)
+	}
+}
+
+#[test]
+fn parse_reader_statements() {
+	let mut q = String::from("CREATE foo:1 SET a = 'a;b';\n\n;SELECT * FROM foo; ");
+	// A statement exceeding the initial buffer size.
+	q.push_str("RETURN [");
+	for i in 0..2000 {
+		q.push_str(&format!("{i},"));
+	}
+	q.push_str("];RETURN 1");
+
+	let expected = parse(&q).unwrap().0 .0;
+	let res = super::parse_reader(q.as_bytes()).collect::<Result<Vec<_>, _>>().unwrap();
+	assert_eq!(res, expected);
+	let res =
+		super::parse_reader(ChunkedReader(q.as_bytes())).collect::<Result<Vec<_>, _>>().unwrap();
+	assert_eq!(res, expected);
+}
+
+#[test]
+fn parse_reader_error() {
+	let q = "RETURN 1; RETURN (; RETURN 2;";
+	let mut iter = super::parse_reader(ChunkedReader(q.as_bytes()));
+	assert_eq!(iter.next().unwrap().unwrap(), parse("RETURN 1").unwrap().0 .0[0]);
+	iter.next().unwrap().unwrap_err();
+	assert!(iter.next().is_none());
+	assert!(super::parse_reader("".as_bytes()).next().is_none());
+}
+
+#[test]
+fn empty_geometry() {
+	super::geometry("").unwrap_err();
+}
+
+#[test]
+fn geometry_variants() {
+	use geo::{line_string, point, polygon, LineString, MultiPoint, Point};
+
+	let res = super::geometry("(-0.118092, 51.509865)").unwrap();
+	assert_eq!(res, Geometry::Point(point!(x: -0.118092, y: 51.509865)));
+
+	let res = super::geometry(r#"{ type: "Point", coordinates: [1.0, 2.0] }"#).unwrap();
+	assert_eq!(res, Geometry::Point(point!(x: 1.0, y: 2.0)));
+
+	let res = super::geometry(r#"{ type: "LineString", coordinates: [[1, 2], [3, 4]] }"#).unwrap();
+	assert_eq!(res, Geometry::Line(line_string![(x: 1.0, y: 2.0), (x: 3.0, y: 4.0)]));
+
+	let res =
+		super::geometry(r#"{ type: "Polygon", coordinates: [[[0, 0], [1, 0], [1, 1], [0, 0]]] }"#)
+			.unwrap();
+	assert_eq!(
+		res,
+		Geometry::Polygon(polygon![(x: 0.0, y: 0.0), (x: 1.0, y: 0.0), (x: 1.0, y: 1.0)])
+	);
+
+	let res = super::geometry(r#"{ type: "MultiPoint", coordinates: [[1, 2], [3, 4]] }"#).unwrap();
+	assert_eq!(
+		res,
+		Geometry::MultiPoint(MultiPoint(vec![Point::new(1.0, 2.0), Point::new(3.0, 4.0)]))
+	);
+
+	let res = super::geometry(
+		r#"{ type: "MultiLineString", coordinates: [[[1, 2], [3, 4]], [[5, 6], [7, 8]]] }"#,
+	)
+	.unwrap();
+	let Geometry::MultiLine(x) = res else {
+		panic!("expected a multiline")
+	};
+	assert_eq!(x.0.len(), 2);
+	assert_eq!(x.0[1], LineString::from(vec![(5.0, 6.0), (7.0, 8.0)]));
+
+	let res = super::geometry(
+		r#"{ type: "MultiPolygon", coordinates: [[[[0, 0], [1, 0], [1, 1], [0, 0]]]] }"#,
+	)
+	.unwrap();
+	let Geometry::MultiPolygon(x) = res else {
+		panic!("expected a multipolygon")
+	};
+	assert_eq!(x.0.len(), 1);
+
+	let res = super::geometry(
+		r#"{ type: "GeometryCollection", geometries: [{ type: "Point", coordinates: [1, 2] }] }"#,
+	)
+	.unwrap();
+	assert_eq!(res, Geometry::Collection(vec![Geometry::Point(point!(x: 1.0, y: 2.0))]));
+}
+
+#[test]
+fn geometry_invalid() {
+	super::geometry(r#"{ type: "Point", coordinates: [1.0] }"#).unwrap_err();
+	super::geometry(r#"{ type: "Point", coordinates: ["a", "b"] }"#).unwrap_err();
+	super::geometry(r#"{ type: "Polygon", coordinates: [1, 2] }"#).unwrap_err();
+	super::geometry("(1.0, 2.0) (3.0, 4.0)").unwrap_err();
+	super::geometry("[1.0, 2.0]").unwrap_err();
+}
+
+#[test]
+fn reserved_keyword_case() {
+	assert!(super::could_be_reserved_keyword("select"));
+	assert!(super::could_be_reserved_keyword("SeLeCt"));
+	assert!(!super::could_be_reserved_keyword("\u{212A}ILL"));
+	assert!(super::could_be_reserved_keyword_ci("SeLeCt"));
+	assert!(super::could_be_reserved_keyword_ci("\u{212A}ILL"));
+	assert!(!super::could_be_reserved_keyword_ci("person"));
+}
+
+#[test]
+fn reserved_keyword_list() {
+	let list = super::reserved_keywords();
+	assert!(list.contains(&"SELECT"));
+	assert!(list.windows(2).all(|x| x[0] < x[1]));
+	for k in list {
+		assert!(super::could_be_reserved_keyword(k));
+		assert!(super::could_be_reserved_keyword(&k.to_lowercase()));
+	}
+}
+
+#[test]
+fn format_round_trip() {
+	let queries = [
+		"SELECT * FROM person WHERE age > 18 AND name CONTAINS 'a' ORDER BY age DESC LIMIT 10 START 5 FETCH friends",
+		"select name, ->likes->post.* as liked from person:tobie where (select count() from post where author = $parent.id) > 2 group by name fetch liked",
+		"CREATE person CONTENT { name: 'Tobie', type: 'admin', nested: { values: [1, 2, { select: true }] } }",
+		"DEFINE TABLE test SCHEMAFULL PERMISSIONS FOR create, update, delete NONE FOR select WHERE public = true",
+		"IF $a { RETURN type::is::string($a) } ELSE { RETURN 'tab\tin string' }",
+		"LET $type = 1; RETURN { type: $type, value: count() }",
+	];
+	for q in queries {
+		let query = parse(q).unwrap();
+		for opts in [
+			super::FormatOptions::default(),
+			super::FormatOptions::default()
+				.with_indent(2)
+				.with_keyword_case(super::KeywordCase::Lower)
+				.with_max_width(20),
+			super::FormatOptions::default().with_break_clauses(false),
+		] {
+			let formatted = super::format(&query, opts);
+			assert_eq!(parse(&formatted).unwrap(), query, "{formatted}");
+		}
+	}
+}
+
+#[test]
+fn format_output() {
+	let query =
+		parse("select * from person where age > 18 and admin = true order by age fetch friends")
+			.unwrap();
+	let opts =
+		super::FormatOptions::default().with_indent(2).with_keyword_case(super::KeywordCase::Lower);
+	assert_eq!(
+		super::format(&query, opts.clone().with_max_width(120)),
+		"select * from person where age > 18 and admin = true order by age fetch friends;"
+	);
+	assert_eq!(
+		super::format(&query, opts.with_max_width(40)),
+		"select *\n  from person\n  where age > 18 and admin = true\n  order by age\n  fetch friends;"
+	);
+
+	let query = parse("CREATE foo CONTENT { a: [1, 2] }; RETURN 1").unwrap();
+	assert_eq!(
+		super::format(&query, super::FormatOptions::default()),
+		"CREATE foo CONTENT {\n    a: [\n        1,\n        2\n    ]\n};\nRETURN 1;"
+	);
+}
+
+#[test]
+fn datetime_fractional_precision() {
+	let micros = super::datetime("2024-01-01T12:30:45.123456Z").unwrap();
+	assert_eq!(micros.timestamp_subsec_nanos(), 123_456_000);
+
+	let nanos = super::datetime("2024-01-01T12:30:45.123456789Z").unwrap();
+	assert_eq!(nanos.timestamp_subsec_nanos(), 123_456_789);
+
+	// Digits beyond nanosecond precision are truncated.
+	let picos = super::datetime("2024-01-01T12:30:45.123456789999Z").unwrap();
+	assert_eq!(picos, nanos);
+
+	let query = parse("RETURN d'2024-01-01T12:30:45.123456789999+02:00'").unwrap();
+	assert_eq!(query.to_string(), "RETURN d'2024-01-01T10:30:45.123456789Z';");
+
+	super::datetime("2024-01-01T12:30:45.Z").unwrap_err();
+}
+
+#[test]
+fn kind_round_trip() {
+	let kinds = [
+		"any",
+		"null",
+		"bool",
+		"bytes",
+		"datetime",
+		"decimal",
+		"duration",
+		"float",
+		"int",
+		"number",
+		"object",
+		"point",
+		"string",
+		"uuid",
+		"regex",
+		"function",
+		"range",
+		"option<int>",
+		"option<int | string>",
+		"record",
+		"record<user>",
+		"record<user | post>",
+		"geometry",
+		"geometry<point | polygon>",
+		"set",
+		"set<int>",
+		"set<int, 5>",
+		"array",
+		"array<record<user>>",
+		"array<string, 10>",
+		"int | string",
+		"'a' | 'b'",
+		"1 | 2.5f | 1h | true",
+		"[int, string]",
+		"{ a: int, b: option<string> }",
+		"{ kind: 'a', x: int } | { kind: 'b', y: string }",
+		"references",
+		"references<user>",
+		"references<user, friends>",
+		"file",
+		"file<avatars>",
+		"file<avatars | documents>",
+	];
+	let capabilities = Capabilities::all().with_experimental(Targets::All);
+	for input in kinds {
+		let kind = super::kind_with_capabilities(input, &capabilities).unwrap();
+		assert_eq!(kind.to_string(), input);
+		assert_eq!(
+			super::kind_with_capabilities(&kind.to_string(), &capabilities).unwrap(),
+			kind,
+			"{input}"
+		);
+	}
+
+	let kind = super::kind("{ kind: 'a', x: int } | { kind: 'b', y: string }").unwrap();
+	assert!(matches!(kind, Kind::Literal(Literal::DiscriminatedObject(..))), "{kind:?}");
+
+	// Experimental kinds are only parsed when the capabilities allow them
+	super::kind("references<user>").unwrap_err();
+	super::kind_with_capabilities("references<user>", &Capabilities::default()).unwrap_err();
+}
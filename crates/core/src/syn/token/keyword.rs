@@ -70,6 +70,7 @@ keyword! {
 	Define => "DEFINE",
 	Delete => "DELETE",
 	Descending => "DESCENDING",
+	Detail => "DETAIL",
 	Diff => "DIFF",
 	Dimension => "DIMENSION",
 	Distance => "DISTANCE",
@@ -78,6 +79,7 @@ keyword! {
 	DocLengthsCache => "DOC_LENGTHS_CACHE",
 	DocLengthsOrder => "DOC_LENGTHS_ORDER",
 	Drop => "DROP",
+	Dry => "DRY",
 	Duplicate => "DUPLICATE",
 	Efc => "EFC",
 	Edgengram => "EDGENGRAM",
@@ -174,6 +176,7 @@ keyword! {
 	Revoked => "REVOKED",
 	Roles => "ROLES",
 	Root => "ROOT",
+	Savepoint => "SAVEPOINT",
 	Schemafull => "SCHEMAFULL",
 	Schemaless => "SCHEMALESS",
 	Scope => "SCOPE",
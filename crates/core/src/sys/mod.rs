@@ -1,5 +1,7 @@
 use futures::lock::Mutex;
+use std::path::PathBuf;
 use std::sync::LazyLock;
+use sysinfo::Disks;
 use sysinfo::Pid;
 use sysinfo::System;
 
@@ -26,6 +28,21 @@ pub async fn refresh() {
 	information.load_average = environment.load_average();
 	information.physical_cores = environment.physical_cores();
 	information.available_parallelism = environment.available_parallelism();
+	(information.disk_total, information.disk_available) = match environment.disk_usage() {
+		Some((total, available)) => (Some(total), Some(available)),
+		None => (None, None),
+	};
+	information.disk_used =
+		information.disk_total.zip(information.disk_available).map(|(t, a)| t.saturating_sub(a));
+}
+
+/// Sets the data directory of the local storage
+/// engine, which is used to compute disk usage.
+#[cfg(any(feature = "kv-rocksdb", feature = "kv-surrealkv"))]
+pub async fn set_data_path(path: impl AsRef<std::path::Path>) {
+	let path = path.as_ref();
+	let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+	ENVIRONMENT.lock().await.path = Some(path);
 }
 
 /// Cached system utilisation metrics information
@@ -38,12 +55,17 @@ pub struct Information {
 	pub threads: usize,
 	pub memory_usage: u64,
 	pub physical_cores: usize,
+	pub disk_total: Option<u64>,
+	pub disk_available: Option<u64>,
+	pub disk_used: Option<u64>,
 }
 
 /// An environment for fetching system utilisation
 pub struct Environment {
 	sys: System,
 	pid: Pid,
+	disks: Disks,
+	path: Option<PathBuf>,
 }
 
 impl Default for Environment {
@@ -54,6 +76,8 @@ impl Default for Environment {
 			pid: 0.into(),
 			#[cfg(not(target_family = "wasm"))]
 			pid: Pid::from(std::process::id() as usize),
+			disks: Disks::new(),
+			path: None,
 		}
 	}
 }
@@ -108,6 +132,20 @@ impl Environment {
 		}
 	}
 
+	/// Returns the total and available space (in
+	/// bytes) of the disk holding the data directory.
+	/// This is not available for in-memory or remote
+	/// storage engines, where no path has been set.
+	pub fn disk_usage(&self) -> Option<(u64, u64)> {
+		let path = self.path.as_ref()?;
+		self.disks
+			.list()
+			.iter()
+			.filter(|d| path.starts_with(d.mount_point()))
+			.max_by_key(|d| d.mount_point().as_os_str().len())
+			.map(|d| (d.total_space(), d.available_space()))
+	}
+
 	/// Refreshes the current process information
 	/// with memory and cpu usage details. This
 	/// ensures that we only fetch data we need.
@@ -117,5 +155,8 @@ impl Environment {
 			true,
 			sysinfo::ProcessRefreshKind::nothing().with_memory().with_cpu(),
 		);
+		if self.path.is_some() {
+			self.disks.refresh_specifics(true, sysinfo::DiskRefreshKind::nothing().with_storage());
+		}
 	}
 }
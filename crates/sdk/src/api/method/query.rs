@@ -128,12 +128,13 @@ where
 					let query_indicies = if register_live_queries {
 						query_statements
 							.iter()
-							// BEGIN, COMMIT, and CANCEL don't return a result.
+							// BEGIN, COMMIT, CANCEL, and SAVEPOINT don't return a result.
 							.filter(|x| {
 								!matches!(
 									x,
 									Statement::Begin(_)
 										| Statement::Commit(_) | Statement::Cancel(_)
+										| Statement::Savepoint(_)
 								)
 							})
 							.enumerate()
@@ -448,6 +448,8 @@ pub enum Action {
 	Create,
 	Update,
 	Delete,
+	/// The namespace which the live query was defined within has been removed
+	Remove,
 }
 
 impl Action {
@@ -457,6 +459,7 @@ impl Action {
 			CoreAction::Create => Self::Create,
 			CoreAction::Update => Self::Update,
 			CoreAction::Delete => Self::Delete,
+			CoreAction::Remove => Self::Remove,
 			_ => panic!("unimplemented variant of action"),
 		}
 	}
@@ -2,8 +2,10 @@ mod parse;
 use parse::Parse;
 mod helpers;
 use helpers::new_ds;
+use std::sync::Arc;
 use surrealdb::dbs::Session;
 use surrealdb::err::Error;
+use surrealdb::gql::cache::{Generational, SchemaCache};
 use surrealdb::sql::Value;
 
 #[tokio::test]
@@ -136,3 +138,44 @@ async fn clear_transaction_cache_field() -> Result<(), Error> {
 	//
 	Ok(())
 }
+
+#[tokio::test]
+async fn graphql_schema_rebuilt_after_definitions_change_on_another_node() -> Result<(), Error> {
+	let sql = "
+		DEFINE CONFIG GRAPHQL AUTO;
+		DEFINE TABLE foo SCHEMAFULL;
+		DEFINE FIELD bar ON foo TYPE string;
+	";
+	let dbs = Arc::new(new_ds().await?);
+	let ses = Session::owner().with_ns("test").with_db("test");
+	for res in dbs.execute(sql, &ses, None).await? {
+		res.result?;
+	}
+	// A second node on the same storage
+	let node = dbs.new_node();
+	//
+	let cache = SchemaCache::<Generational>::new(dbs.clone());
+	let sdl = cache.get_schema(&ses).await.unwrap().sdl();
+	assert!(sdl.contains("bar: String!"), "{sdl}");
+	assert!(!sdl.contains("baz"), "{sdl}");
+	// Data changes leave the schema untouched
+	node.execute("CREATE foo SET bar = 'a'", &ses, None).await?.remove(0).result?;
+	assert_eq!(cache.get_schema(&ses).await.unwrap().sdl(), sdl);
+	// The schema is rebuilt once the other node changes the definitions
+	node.execute("DEFINE FIELD baz ON foo TYPE int", &ses, None).await?.remove(0).result?;
+	let sdl = cache.get_schema(&ses).await.unwrap().sdl();
+	assert!(sdl.contains("baz: Int!"), "{sdl}");
+	// As it is once they are changed within a transaction
+	let sql = "
+		BEGIN;
+		REMOVE FIELD baz ON foo;
+		COMMIT;
+	";
+	for res in node.execute(sql, &ses, None).await? {
+		res.result?;
+	}
+	let sdl = cache.get_schema(&ses).await.unwrap().sdl();
+	assert!(!sdl.contains("baz"), "{sdl}");
+	//
+	Ok(())
+}
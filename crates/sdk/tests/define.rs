@@ -39,6 +39,9 @@ async fn define_statement_namespace() -> Result<(), Error> {
 			system: {
 				available_parallelism: 0,
 				cpu_usage: 0.0f,
+				disk_available: NULL,
+				disk_total: NULL,
+				disk_used: NULL,
 				load_average: [
 					0.0f,
 					0.0f,
@@ -216,6 +219,27 @@ async fn define_statement_index_concurrently_building_status(
 		let mut r = ds.execute("INFO FOR INDEX test ON user", &session, None).await?;
 		let tmp = r.remove(0).result?;
 		if let Value::Object(o) = &tmp {
+			// The progress is only estimated once the initial indexing is done
+			let progress = match o.get("progress") {
+				Some(Value::Number(progress)) => Some(progress.to_float()),
+				None => None,
+				_ => panic!("Invalid info: {tmp:#}"),
+			};
+			if let Some(progress) = progress {
+				assert!((0.0..=1.0).contains(&progress), "{progress}");
+			}
+			if let Some(Value::Bool(false)) = o.get("building") {
+				let initial = o.get("initial").cloned().unwrap().coerce_to_i64()? as usize;
+				let pending = o.get("pending").cloned().unwrap().coerce_to_i64()?;
+				let updated = o.get("updated").cloned().unwrap().coerce_to_i64()? as usize;
+				assert_eq!(progress, Some(1.0));
+				assert!(initial > 0, "{initial} > 0");
+				assert!(initial <= initial_size, "{initial} <= {initial_size}");
+				assert_eq!(pending, 0);
+				assert!(updated > 0, "{updated} > 0");
+				assert!(updated <= appended_count, "{updated} <= appended_count");
+				break;
+			}
 			if let Some(Value::Object(o)) = o.get("building") {
 				if let Some(Value::Strand(s)) = o.get("status") {
 					let new_initial = o.get("initial").cloned();
@@ -253,17 +277,6 @@ async fn define_statement_index_concurrently_building_status(
 							}
 							continue;
 						}
-						"ready" => {
-							let initial = new_initial.unwrap().coerce_to_i64()? as usize;
-							let pending = new_pending.unwrap().coerce_to_i64()?;
-							let updated = new_updated.unwrap().coerce_to_i64()? as usize;
-							assert!(initial > 0, "{initial} > 0");
-							assert!(initial <= initial_size, "{initial} <= {initial_size}");
-							assert_eq!(pending, 0);
-							assert!(updated > 0, "{updated} > 0");
-							assert!(updated <= appended_count, "{updated} <= appended_count");
-							break;
-						}
 						_ => {}
 					}
 				}
@@ -597,8 +610,8 @@ async fn permissions_checks_define_ns() {
 	]);
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
-	let access1 = "{ accesses: {  }, namespaces: { NS: 'DEFINE NAMESPACE NS' }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }";
-	let access2 = "{ accesses: {  }, namespaces: {  }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }";
+	let access1 = "{ accesses: {  }, namespaces: { NS: 'DEFINE NAMESPACE NS' }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, disk_available: NULL, disk_total: NULL, disk_used: NULL, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }";
+	let access2 = "{ accesses: {  }, namespaces: {  }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, disk_available: NULL, disk_total: NULL, disk_used: NULL, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }";
 	let check_results = [vec![access1], vec![access2]];
 
 	let test_cases = [
@@ -761,8 +774,8 @@ async fn permissions_checks_define_access_root() {
 	]);
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
-	let access1 = r#"{ accesses: { access: "DEFINE ACCESS access ON ROOT TYPE JWT ALGORITHM HS512 KEY '[REDACTED]' WITH ISSUER KEY '[REDACTED]' DURATION FOR TOKEN 1h, FOR SESSION NONE" }, namespaces: {  }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }"#;
-	let access2 = "{ accesses: {  }, namespaces: {  }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }";
+	let access1 = r#"{ accesses: { access: "DEFINE ACCESS access ON ROOT TYPE JWT ALGORITHM HS512 KEY '[REDACTED]' WITH ISSUER KEY '[REDACTED]' DURATION FOR TOKEN 1h, FOR SESSION NONE" }, namespaces: {  }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, disk_available: NULL, disk_total: NULL, disk_used: NULL, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }"#;
+	let access2 = "{ accesses: {  }, namespaces: {  }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, disk_available: NULL, disk_total: NULL, disk_used: NULL, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }";
 	let check_results = [vec![access1], vec![access2]];
 
 	let test_cases = [
@@ -886,8 +899,8 @@ async fn permissions_checks_define_user_root() {
 	]);
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
-	let check1 = r#"{ accesses: {  }, namespaces: {  }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: { user: "DEFINE USER user ON ROOT PASSHASH 'secret' ROLES VIEWER DURATION FOR TOKEN 15m, FOR SESSION 6h" } }"#;
-	let check2 = "{ accesses: {  }, namespaces: {  }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }";
+	let check1 = r#"{ accesses: {  }, namespaces: {  }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, disk_available: NULL, disk_total: NULL, disk_used: NULL, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: { user: "DEFINE USER user ON ROOT PASSHASH 'secret' ROLES VIEWER DURATION FOR TOKEN 15m, FOR SESSION 6h" } }"#;
+	let check2 = "{ accesses: {  }, namespaces: {  }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, disk_available: NULL, disk_total: NULL, disk_used: NULL, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }";
 	let check_results = [vec![check1], vec![check2]];
 
 	let test_cases = [
@@ -3,6 +3,7 @@ use parse::Parse;
 mod helpers;
 use crate::helpers::Test;
 use helpers::new_ds;
+use surrealdb::dbs::capabilities::{Capabilities, ExperimentalTarget};
 use surrealdb::dbs::Session;
 use surrealdb::err::Error;
 use surrealdb::sql::{self, Number, Value};
@@ -2416,6 +2417,154 @@ async fn function_type_bool() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_type_bytes_from_string() -> Result<(), Error> {
+	let sql = r#"
+		RETURN type::bytes_from_string("héllo", "utf8");
+		RETURN type::bytes_from_string("68c3a96c6C6f", "hex");
+		RETURN type::bytes_from_string("aMOpbGxv", "base64");
+		RETURN type::bytes_from_string("aMOpbGxvIQ==", "base64");
+		RETURN type::string_lossy(type::bytes_from_string("héllo", "utf8"));
+		RETURN type::bytes_from_string("6g", "hex");
+		RETURN type::bytes_from_string("aGVsbG8*", "base64");
+		RETURN type::bytes_from_string("YQ=====", "base64");
+		RETURN type::bytes_from_string("YQ=", "base64");
+		RETURN type::bytes_from_string("hello", "latin1");
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	for expected in ["héllo", "héllo", "héllo", "héllo!"] {
+		let tmp = test.next()?.result?;
+		let val = Value::Bytes(expected.as_bytes().to_vec().into());
+		assert_eq!(tmp, val);
+	}
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::from("héllo");
+	assert_eq!(tmp, val);
+	//
+	for encoding in ["hex", "base64", "base64", "base64"] {
+		match test.next()?.result {
+			Err(Error::InvalidArguments {
+				name,
+				message,
+			}) => {
+				assert_eq!(&name, "type::bytes_from_string");
+				assert_eq!(
+					message,
+					format!("The first argument must be a valid {encoding} string.")
+				);
+			}
+			_ => panic!("Should have fail!"),
+		}
+	}
+	//
+	match test.next()?.result {
+		Err(Error::InvalidArguments {
+			name,
+			message,
+		}) => {
+			assert_eq!(&name, "type::bytes_from_string");
+			assert_eq!(
+				&message,
+				"The second argument must be one of 'utf8', 'hex', or 'base64', found 'latin1'."
+			);
+		}
+		_ => panic!("Should have fail!"),
+	}
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_type_cast() -> Result<(), Error> {
+	let sql = r#"
+		RETURN type::cast(["1", 2.0, 3], "array<int>");
+		RETURN type::cast("user:tobie", "record<user>");
+		RETURN type::cast(NONE, "option<string>");
+		RETURN type::cast(1, "option<string>");
+		RETURN type::cast(1, "notakind<");
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::parse("[1, 2, 3]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::parse("user:tobie");
+	assert_eq!(tmp, val);
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::None;
+	assert_eq!(tmp, val);
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::from("1");
+	assert_eq!(tmp, val);
+	//
+	match test.next()?.result {
+		Err(Error::InvalidArguments {
+			name,
+			message,
+		}) => {
+			assert_eq!(&name, "type::cast");
+			assert_eq!(&message, "The second argument must be a valid kind, found 'notakind<'.");
+		}
+		_ => panic!("Should have fail!"),
+	}
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_type_coerce() -> Result<(), Error> {
+	let sql = r#"
+		RETURN type::coerce(1.0, "int");
+		RETURN type::coerce(1.5, "int");
+		RETURN type::cast("1", "int");
+		RETURN type::coerce("1", "int");
+		RETURN type::coerce(user:tobie, "record<user>");
+		RETURN type::coerce("user:tobie", "record<user>");
+		RETURN type::coerce(1, "notakind<");
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::from(1);
+	assert_eq!(tmp, val);
+	//
+	let tmp = test.next()?.result;
+	assert!(matches!(tmp, Err(Error::CoerceTo { .. })), "{tmp:?}");
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::from(1);
+	assert_eq!(tmp, val);
+	//
+	let tmp = test.next()?.result;
+	assert!(matches!(tmp, Err(Error::CoerceTo { .. })), "{tmp:?}");
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::parse("user:tobie");
+	assert_eq!(tmp, val);
+	//
+	let tmp = test.next()?.result;
+	assert!(matches!(tmp, Err(Error::CoerceTo { .. })), "{tmp:?}");
+	//
+	match test.next()?.result {
+		Err(Error::InvalidArguments {
+			name,
+			message,
+		}) => {
+			assert_eq!(&name, "type::coerce");
+			assert_eq!(&message, "The second argument must be a valid kind, found 'notakind<'.");
+		}
+		_ => panic!("Should have fail!"),
+	}
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_type_datetime() -> Result<(), Error> {
 	let sql = r#"
@@ -2435,6 +2584,59 @@ async fn function_type_datetime() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_type_datetime_from_unix() -> Result<(), Error> {
+	let sql = r#"
+		RETURN type::datetime_from_unix(1659312000);
+		RETURN type::datetime_from_unix(1659312000, "s");
+		RETURN type::datetime_from_unix(1659312000123, "ms");
+		RETURN type::datetime_from_unix(1659312000123456, "us");
+		RETURN type::datetime_from_unix(1659312000123456789, "ns");
+		RETURN type::datetime_from_unix(9223372036854775807, "s");
+		RETURN type::datetime_from_unix(1659312000, "m");
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	for expected in [
+		"d'2022-08-01T00:00:00Z'",
+		"d'2022-08-01T00:00:00Z'",
+		"d'2022-08-01T00:00:00.123Z'",
+		"d'2022-08-01T00:00:00.123456Z'",
+		"d'2022-08-01T00:00:00.123456789Z'",
+	] {
+		let tmp = test.next()?.result?;
+		let val = Value::parse(expected);
+		assert_eq!(tmp, val);
+	}
+	//
+	match test.next()?.result {
+		Err(Error::InvalidArguments {
+			name,
+			message,
+		}) => {
+			assert_eq!(&name, "type::datetime_from_unix");
+			assert_eq!(&message, "The first argument must be an in-bounds timestamp relative to January 1, 1970 0:00:00 UTC.");
+		}
+		_ => panic!("Should have fail!"),
+	}
+	//
+	match test.next()?.result {
+		Err(Error::InvalidArguments {
+			name,
+			message,
+		}) => {
+			assert_eq!(&name, "type::datetime_from_unix");
+			assert_eq!(
+				&message,
+				"The second argument must be one of 's', 'ms', 'us', or 'ns', found 'm'."
+			);
+		}
+		_ => panic!("Should have fail!"),
+	}
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_type_decimal() -> Result<(), Error> {
 	let sql = r#"
@@ -2475,6 +2677,94 @@ async fn function_type_duration() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_type_duration_from() -> Result<(), Error> {
+	let sql = r#"
+		RETURN type::duration_from(1500, "ns");
+		RETURN type::duration_from(250, "ms");
+		RETURN type::duration_from(90, "s");
+		RETURN type::duration_from(3, "h");
+		RETURN type::duration_from(2, "w");
+		RETURN type::duration_from(1, "y");
+		RETURN type::duration_from(-1, "s");
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	for expected in ["1µs500ns", "250ms", "1m30s", "3h", "2w"] {
+		let tmp = test.next()?.result?;
+		let val = Value::parse(expected);
+		assert_eq!(tmp, val);
+	}
+	//
+	match test.next()?.result {
+		Err(Error::InvalidArguments {
+			name,
+			message,
+		}) => {
+			assert_eq!(&name, "type::duration_from");
+			assert_eq!(
+				&message,
+				"The second argument must be one of 'ns', 'us', 'ms', 's', 'm', 'h', 'd', or 'w', found 'y'."
+			);
+		}
+		_ => panic!("Should have fail!"),
+	}
+	//
+	match test.next()?.result {
+		Err(Error::InvalidArguments {
+			name,
+			message,
+		}) => {
+			assert_eq!(&name, "type::duration_from");
+			assert_eq!(&message, "The first argument must be a non-negative integer.");
+		}
+		_ => panic!("Should have fail!"),
+	}
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_type_file_from_url() -> Result<(), Error> {
+	let sql = r#"
+		RETURN type::file_from_url("s3://my-bucket/path/to/file.txt");
+		RETURN type::file_from_url("my-bucket:/file.txt");
+		RETURN type::file_from_url("s3://my-bucket");
+		RETURN type::file_from_url("s3:///file.txt");
+		RETURN type::file_from_url("file.txt");
+	"#;
+	let dbs = new_ds().await?.with_capabilities(
+		Capabilities::default().with_experimental(ExperimentalTarget::Files.into()),
+	);
+	let mut test = Test::new_ds(dbs, sql).await?;
+	//
+	let tmp = test.next()?.result?;
+	assert_eq!(tmp.to_string(), r#"f"my-bucket:/path/to/file.txt""#);
+	//
+	let tmp = test.next()?.result?;
+	assert_eq!(tmp.to_string(), r#"f"my-bucket:/file.txt""#);
+	//
+	for url in ["s3://my-bucket", "s3:///file.txt", "file.txt"] {
+		match test.next()?.result {
+			Err(Error::InvalidArguments {
+				name,
+				message,
+			}) => {
+				assert_eq!(&name, "type::file_from_url");
+				assert_eq!(
+					message,
+					format!(
+						"The argument must be a URL containing a bucket and a key, found '{url}'."
+					)
+				);
+			}
+			_ => panic!("Should have fail!"),
+		}
+	}
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_type_float() -> Result<(), Error> {
 	let sql = r#"
@@ -2494,6 +2784,108 @@ async fn function_type_float() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_type_geometry_line() -> Result<(), Error> {
+	let sql = r#"
+		RETURN type::geometry::line([(10, 11.2), [10.5, 11.9]]);
+		RETURN type::geometry::line([(10, 11.2)]);
+		RETURN type::geometry::line([(10, 11.2), "point"]);
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::parse("{ type: 'LineString', coordinates: [[10.0, 11.2], [10.5, 11.9]] }");
+	assert_eq!(tmp, val);
+	//
+	for message in [
+		"The argument must be an array of at least 2 points.",
+		"The argument must be an array of points.",
+	] {
+		match test.next()?.result {
+			Err(Error::InvalidArguments {
+				name,
+				message: m,
+			}) => {
+				assert_eq!(&name, "type::geometry::line");
+				assert_eq!(m, message);
+			}
+			_ => panic!("Should have fail!"),
+		}
+	}
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_type_geometry_polygon() -> Result<(), Error> {
+	let sql = r#"
+		RETURN type::geometry::polygon([(0, 0), (0, 1), (1, 1), (0, 0)]);
+		RETURN type::geometry::polygon([(0, 0), (0, 1), (0, 0)]);
+		RETURN type::geometry::polygon([(0, 0), (0, 1), (1, 1), (1, 0)]);
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::parse(
+		"{ type: 'Polygon', coordinates: [[[0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [0.0, 0.0]]] }",
+	);
+	assert_eq!(tmp, val);
+	//
+	for _ in 0..2 {
+		match test.next()?.result {
+			Err(Error::InvalidArguments {
+				name,
+				message,
+			}) => {
+				assert_eq!(&name, "type::geometry::polygon");
+				assert_eq!(&message, "The argument must be an array of at least 4 points, starting and ending with the same point.");
+			}
+			_ => panic!("Should have fail!"),
+		}
+	}
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_type_geometry_collection() -> Result<(), Error> {
+	let sql = r#"
+		RETURN type::geometry::collection([(10, 11.2), type::geometry::line([(0, 0), (1, 1)])]);
+		RETURN type::geometry::collection([]);
+		RETURN type::geometry::collection([(10, 11.2), 1]);
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::parse(
+		"{
+			type: 'GeometryCollection',
+			geometries: [
+				{ type: 'Point', coordinates: [10.0, 11.2] },
+				{ type: 'LineString', coordinates: [[0.0, 0.0], [1.0, 1.0]] }
+			]
+		}",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::parse("{ type: 'GeometryCollection', geometries: [] }");
+	assert_eq!(tmp, val);
+	//
+	match test.next()?.result {
+		Err(Error::InvalidArguments {
+			name,
+			message,
+		}) => {
+			assert_eq!(&name, "type::geometry::collection");
+			assert_eq!(&message, "The argument must be an array of geometries, found 1.");
+		}
+		_ => panic!("Should have fail!"),
+	}
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_type_int() -> Result<(), Error> {
 	let sql = r#"
@@ -2513,6 +2905,71 @@ async fn function_type_int() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_type_kind() -> Result<(), Error> {
+	let sql = r#"
+		RETURN type::kind([1, 2, 3]);
+		RETURN type::kind(user:tobie);
+		RETURN type::kind(d"2022-08-01T00:00:00Z");
+		RETURN type::kind((-0.118092, 51.509865));
+		RETURN type::kind({ type: "LineString", coordinates: [[10, 11.2], [10.5, 11.9]] });
+		RETURN type::kind(1.5dec);
+		RETURN type::kind(NONE);
+		RETURN type::kind(type::table("user"));
+		RETURN type::kind(<regex> "a|b");
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	for expected in [
+		"array",
+		"record<user>",
+		"datetime",
+		"geometry<point>",
+		"geometry<line>",
+		"decimal",
+		"none",
+		"table",
+		"regex",
+	] {
+		let tmp = test.next()?.result?;
+		let val = Value::from(expected);
+		assert_eq!(tmp, val);
+	}
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_type_array() -> Result<(), Error> {
+	let sql = r#"
+		RETURN type::array([1, "2", 3.0]);
+		RETURN type::array([1, "2", 3.0], "int");
+		RETURN type::array([1, "two", 3], "int");
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::parse("[1, '2', 3.0]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::parse("[1, 2, 3]");
+	assert_eq!(tmp, val);
+	//
+	match test.next()?.result {
+		Err(Error::InvalidArguments {
+			name,
+			message,
+		}) => {
+			assert_eq!(&name, "type::array");
+			assert!(message.starts_with("The element at index 1 "), "{message}");
+		}
+		_ => panic!("Should have fail!"),
+	}
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_type_is_array() -> Result<(), Error> {
 	let sql = r#"
@@ -2570,6 +3027,30 @@ async fn function_type_is_bytes() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_type_is_closure() -> Result<(), Error> {
+	let sql = r#"
+		LET $fn = |$a: int| $a + 1;
+		RETURN type::is::closure($fn);
+		RETURN type::is::closure("123");
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::None;
+	assert_eq!(tmp, val);
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::from(true);
+	assert_eq!(tmp, val);
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::from(false);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_type_is_collection() -> Result<(), Error> {
 	let sql = r#"
@@ -2678,6 +3159,9 @@ async fn function_type_is_geometry() -> Result<(), Error> {
 	let sql = r#"
 		RETURN type::is::geometry((-0.118092, 51.509865));
 		RETURN type::is::geometry("123");
+		RETURN type::is::geometry((-0.118092, 51.509865), "point");
+		RETURN type::is::geometry((-0.118092, 51.509865), "polygon");
+		RETURN type::is::geometry((-0.118092, 51.509865), "square");
 	"#;
 	let mut test = Test::new(sql).await?;
 	//
@@ -2689,6 +3173,24 @@ async fn function_type_is_geometry() -> Result<(), Error> {
 	let val = Value::from(false);
 	assert_eq!(tmp, val);
 	//
+	let tmp = test.next()?.result?;
+	let val = Value::from(true);
+	assert_eq!(tmp, val);
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::from(false);
+	assert_eq!(tmp, val);
+	//
+	match test.next()?.result {
+		Err(Error::InvalidArguments {
+			name,
+			..
+		}) => {
+			assert_eq!(&name, "type::is::geometry");
+		}
+		_ => panic!("Should have fail!"),
+	}
+	//
 	Ok(())
 }
 
@@ -2984,6 +3486,10 @@ async fn function_type_is_record() -> Result<(), Error> {
 		RETURN type::is::record("123");
 		RETURN type::is::record(person:john, 'person');
 		RETURN type::is::record(person:john, 'user');
+		RETURN type::is::record(person:john, ['user', 'person']);
+		RETURN type::is::record(person:john, ['user', 'admin']);
+		RETURN type::is::record(person:john, []);
+		RETURN type::is::record(person:john, [1]);
 	"#;
 	let mut test = Test::new(sql).await?;
 	//
@@ -3003,6 +3509,29 @@ async fn function_type_is_record() -> Result<(), Error> {
 	let val = Value::from(false);
 	assert_eq!(tmp, val);
 	//
+	let tmp = test.next()?.result?;
+	let val = Value::from(true);
+	assert_eq!(tmp, val);
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::from(false);
+	assert_eq!(tmp, val);
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::from(true);
+	assert_eq!(tmp, val);
+	//
+	match test.next()?.result {
+		Err(Error::InvalidArguments {
+			name,
+			message,
+		}) => {
+			assert_eq!(&name, "type::is::record");
+			assert_eq!(&message, "The second argument must be an array of table names.");
+		}
+		_ => panic!("Should have fail!"),
+	}
+	//
 	Ok(())
 }
 
@@ -3044,11 +3573,46 @@ async fn function_type_is_uuid() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_type_record_parts() -> Result<(), Error> {
+	let sql = r#"
+		RETURN type::record::id(user:123);
+		RETURN type::record::table(user:123);
+		RETURN type::record::id("user:123");
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::from(123);
+	assert_eq!(tmp, val);
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::from("user");
+	assert_eq!(tmp, val);
+	//
+	match test.next()?.result {
+		Err(Error::ConvertTo {
+			into,
+			..
+		}) => {
+			assert_eq!(&into, "record");
+		}
+		_ => panic!("Should have fail!"),
+	}
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_type_number() -> Result<(), Error> {
 	let sql = r#"
 		RETURN type::number("194719.1947104740");
 		RETURN type::number("1457105732053058.3957394823281756381849375");
+		RETURN type::number("12345678901234567.123456789", { preserve_decimal: true });
+		RETURN type::number("42", { preserve_decimal: true });
+		RETURN type::number("1e400", { preserve_decimal: true });
+		RETURN type::number(1.5f, { preserve_decimal: true });
+		RETURN type::number("1.5", { preserve_decimal: "yes" });
 	"#;
 	let mut test = Test::new(sql).await?;
 	//
@@ -3059,6 +3623,34 @@ async fn function_type_number() -> Result<(), Error> {
 	let tmp = test.next()?.result?;
 	let val = Value::parse("1457105732053058.3957394823281756381849375");
 	assert_eq!(tmp, val);
+	// The decimal keeps all of its digits, which a float can not hold
+	let tmp = test.next()?.result?;
+	let val = Value::parse("12345678901234567.123456789dec");
+	assert_eq!(tmp, val);
+	assert_eq!(tmp.to_string(), "12345678901234567.123456789dec");
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::from(42);
+	assert_eq!(tmp, val);
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::from(f64::INFINITY);
+	assert_eq!(tmp, val);
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::from(1.5);
+	assert_eq!(tmp, val);
+	//
+	match test.next()?.result {
+		Err(Error::InvalidArguments {
+			name,
+			message,
+		}) => {
+			assert_eq!(&name, "type::number");
+			assert_eq!(&message, "The 'preserve_decimal' option must be a boolean, found 'yes'.");
+		}
+		_ => panic!("Should have fail!"),
+	}
 	//
 	Ok(())
 }
@@ -3098,6 +3690,30 @@ async fn function_type_point() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_type_set() -> Result<(), Error> {
+	let sql = r#"
+		RETURN type::set([3, 1, 3, 2, 1]);
+		RETURN type::set(["a", "b", "a"]);
+		RETURN type::set([]);
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::parse("[3, 1, 2]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::parse("['a', 'b']");
+	assert_eq!(tmp, val);
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_type_string() -> Result<(), Error> {
 	let sql = r#"
@@ -3220,6 +3836,94 @@ async fn function_type_thing() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_type_thing_range() -> Result<(), Error> {
+	let sql = r#"
+		RETURN type::thing_range("user", user:1, user:100);
+		RETURN type::thing_range("user", 1, 100, "[]");
+		RETURN type::thing_range("user", 1, NONE);
+		RETURN type::thing_range("user", NONE, 100, "(]");
+		RETURN type::thing_range("user", 1, 100, "(]");
+		CREATE user:1, user:2, user:3, user:4;
+		SELECT VALUE id FROM type::thing_range("user", 2, 3, "[]");
+		RETURN type::thing_range("user", admin:1, NONE);
+		RETURN type::thing_range("user", 1, 100, "[[");
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	for expected in ["user:1..100", "user:1..=100", "user:1..", "user:..=100", "user:1>..=100"] {
+		let tmp = test.next()?.result?;
+		let val = Value::parse(expected);
+		assert_eq!(tmp, val);
+	}
+	//
+	test.next()?.result?;
+	let tmp = test.next()?.result?;
+	let val = Value::parse("[user:2, user:3]");
+	assert_eq!(tmp, val);
+	//
+	for expected in [
+		"Expected a record of the table 'user', found admin:1.",
+		"The fourth argument must be one of '[)', '[]', '(]', or '()', found '[['.",
+	] {
+		match test.next()?.result {
+			Err(Error::InvalidArguments {
+				name,
+				message,
+			}) => {
+				assert_eq!(&name, "type::thing_range");
+				assert_eq!(&message, expected);
+			}
+			_ => panic!("Should have fail!"),
+		}
+	}
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_type_point_from_coords() -> Result<(), Error> {
+	let sql = r#"
+		RETURN type::point(51.509865, -0.118092);
+		RETURN type::point(-90, 180);
+		RETURN type::point(90.5, 0);
+		RETURN type::point(0, -180.5);
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::parse("(-0.118092, 51.509865)");
+	assert_eq!(tmp, val);
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::parse("(180.0, -90.0)");
+	assert_eq!(tmp, val);
+	//
+	match test.next()?.result {
+		Err(Error::InvalidArguments {
+			name,
+			message,
+		}) => {
+			assert_eq!(&name, "type::point");
+			assert_eq!(&message, "The latitude must be between -90 and 90, found 90.5.");
+		}
+		_ => panic!("Should have fail!"),
+	}
+	//
+	match test.next()?.result {
+		Err(Error::InvalidArguments {
+			name,
+			message,
+		}) => {
+			assert_eq!(&name, "type::point");
+			assert_eq!(&message, "The longitude must be between -180 and 180, found -180.5.");
+		}
+		_ => panic!("Should have fail!"),
+	}
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_type_range() -> Result<(), Error> {
 	let sql = r#"
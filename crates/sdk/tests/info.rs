@@ -5,7 +5,9 @@ use regex::Regex;
 use std::collections::HashMap;
 use surrealdb::dbs::capabilities::ExperimentalTarget;
 use surrealdb::dbs::{Capabilities, Session};
+use surrealdb::err::Error;
 use surrealdb::iam::Role;
+use surrealdb::sql::Value;
 
 #[tokio::test]
 async fn info_for_root() {
@@ -22,6 +24,23 @@ async fn info_for_root() {
 	t.expect_regex(r"\{ accesses: \[\{.* \}\], namespaces: \[\{ .* \}\], nodes: \[.*\], system: \{ .* \}, users: \[\{ .* \}\] \}").unwrap();
 }
 
+#[tokio::test]
+async fn info_for_root_system_disk() {
+	let sql = "INFO FOR ROOT";
+	let mut t = Test::new(sql).await.unwrap();
+	let Value::Object(info) = t.next_value().unwrap() else {
+		panic!("INFO FOR ROOT should return an object");
+	};
+	let Some(Value::Object(system)) = info.get("system") else {
+		panic!("INFO FOR ROOT should return a system object");
+	};
+	for key in ["disk_total", "disk_available", "disk_used"] {
+		assert!(system.contains_key(key), "missing `{key}` in {system}");
+	}
+	// The in-memory storage engine has no data directory
+	assert_eq!(system.get("disk_total"), Some(&Value::Null));
+}
+
 #[tokio::test]
 async fn info_for_ns() {
 	let sql = r#"
@@ -58,6 +77,80 @@ async fn info_for_db() {
 		.unwrap();
 }
 
+#[tokio::test]
+async fn info_for_db_category() {
+	let sql = r#"
+        DEFINE TABLE TB;
+        DEFINE FUNCTION fn::greet() {RETURN "Hello";};
+        DEFINE PARAM $param VALUE "foo";
+        INFO FOR DB TABLES;
+        INFO FOR DB FUNCTIONS STRUCTURE;
+        INFO FOR DB PARAMS;
+    "#;
+	let mut t = Test::new(sql).await.unwrap();
+	t.skip_ok(3).unwrap();
+	t.expect_val("{ tables: { TB: 'DEFINE TABLE TB TYPE ANY SCHEMALESS PERMISSIONS NONE' } }")
+		.unwrap();
+	t.expect_val(
+		"{ functions: [{ args: [], block: \"{ RETURN 'Hello'; }\", name: 'greet', permissions: true }] }",
+	)
+	.unwrap();
+	t.expect_val("{ params: { param: \"DEFINE PARAM $param VALUE 'foo' PERMISSIONS FULL\" } }")
+		.unwrap();
+}
+
+#[tokio::test]
+async fn info_for_db_truncated() {
+	let mut sql: String = (0..50).map(|i| format!("DEFINE TABLE t{i:02};")).collect();
+	sql.push_str(
+		r#"
+        DEFINE PARAM $param VALUE "foo";
+        INFO FOR DB TABLES LIMIT 2 START 3;
+        INFO FOR DB TABLES START 48 STRUCTURE;
+        INFO FOR DB LIMIT 10 STRUCTURE;
+        INFO FOR DB TABLES START 100;
+    "#,
+	);
+	let mut t = Test::new(&sql).await.unwrap();
+	t.skip_ok(51).unwrap();
+	t.expect_val(
+		"{
+			tables: {
+				t03: 'DEFINE TABLE t03 TYPE ANY SCHEMALESS PERMISSIONS NONE',
+				t04: 'DEFINE TABLE t04 TYPE ANY SCHEMALESS PERMISSIONS NONE'
+			},
+			truncated: true
+		}",
+	)
+	.unwrap();
+	// The last page is not truncated
+	let val = t.next_value().unwrap();
+	let Value::Object(val) = val else {
+		panic!("expected an object: {val}");
+	};
+	assert!(!val.contains_key("truncated"));
+	let Some(Value::Array(tables)) = val.get("tables") else {
+		panic!("expected an array of tables: {val}");
+	};
+	assert_eq!(tables.len(), 2);
+	// The limit applies to every category, only the tables are truncated
+	let val = t.next_value().unwrap();
+	let Value::Object(val) = val else {
+		panic!("expected an object: {val}");
+	};
+	assert_eq!(val.get("truncated"), Some(&Value::Bool(true)));
+	let Some(Value::Array(tables)) = val.get("tables") else {
+		panic!("expected an array of tables: {val}");
+	};
+	assert_eq!(tables.len(), 10);
+	let Some(Value::Array(params)) = val.get("params") else {
+		panic!("expected an array of params: {val}");
+	};
+	assert_eq!(params.len(), 1);
+	// Starting after the last definition outputs nothing
+	t.expect_val("{ tables: {} }").unwrap();
+}
+
 #[tokio::test]
 async fn info_for_table() {
 	let sql = r#"
@@ -75,6 +168,96 @@ async fn info_for_table() {
 		.unwrap();
 }
 
+#[tokio::test]
+async fn info_for_table_stats() {
+	let sql = r#"
+        DEFINE TABLE TB;
+        CREATE TB:1, TB:2, TB:3 SET name = 'foo';
+        CREATE other:1;
+        INFO FOR TABLE TB;
+        INFO FOR TABLE TB STATS;
+        INFO FOR TABLE TB STATS STRUCTURE;
+        DELETE TB:1;
+        INFO FOR TABLE TB STATS;
+    "#;
+	let mut t = Test::new(sql).await.unwrap();
+	t.skip_ok(3).unwrap();
+	// The statistics are only output when requested
+	let val = t.next_value().unwrap();
+	let Value::Object(val) = val else {
+		panic!("expected an object: {val}");
+	};
+	assert!(!val.contains_key("stats"));
+	for _ in 0..2 {
+		let val = t.next_value().unwrap();
+		let Value::Object(val) = val else {
+			panic!("expected an object: {val}");
+		};
+		let Some(Value::Object(stats)) = val.get("stats") else {
+			panic!("expected table statistics: {val}");
+		};
+		assert_eq!(stats.get("count"), Some(&Value::from(3)));
+		let Some(Value::Number(size)) = stats.get("size") else {
+			panic!("expected a storage size: {stats}");
+		};
+		assert!(size.to_int() > 0);
+	}
+	t.skip_ok(1).unwrap();
+	let val = t.next_value().unwrap();
+	let Value::Object(val) = val else {
+		panic!("expected an object: {val}");
+	};
+	let Some(Value::Object(stats)) = val.get("stats") else {
+		panic!("expected table statistics: {val}");
+	};
+	assert_eq!(stats.get("count"), Some(&Value::from(2)));
+}
+
+#[tokio::test]
+async fn info_for_api() {
+	let sql = r#"
+        DEFINE API "/users/:id"
+            FOR any MIDDLEWARE api::timeout(1s)
+            FOR get PERMISSIONS FULL THEN { RETURN { status: 200 } };
+        INFO FOR API "/users/:id";
+        INFO FOR API "/other";
+    "#;
+	let dbs = new_ds().await.unwrap().with_capabilities(
+		Capabilities::default().with_experimental(ExperimentalTarget::DefineApi.into()),
+	);
+	let ses = Session::owner().with_ns("ns").with_db("db");
+	let mut t = Test::new_ds_session(dbs, ses, sql).await.unwrap();
+	t.skip_ok(1).unwrap();
+	let val = t.next_value().unwrap();
+	let Value::Object(val) = val else {
+		panic!("expected an object: {val}");
+	};
+	assert_eq!(val.get("path"), Some(&Value::from("/users/:id")));
+	let out = val.to_string();
+	assert!(out.contains("middleware"), "{out}");
+	assert!(out.contains("api::timeout"), "{out}");
+	assert!(out.contains("methods: ['get']"), "{out}");
+	assert!(out.contains("permissions: true"), "{out}");
+	t.expect_error_func(|e| matches!(e, Error::ApNotFound { .. })).unwrap();
+}
+
+#[tokio::test]
+async fn info_for_index() {
+	let sql = r#"
+        DEFINE TABLE user;
+        DEFINE INDEX index ON TABLE user FIELDS name, email UNIQUE;
+        INFO FOR INDEX index ON user;
+        INFO FOR INDEX index ON user STRUCTURE;
+    "#;
+	let mut t = Test::new(sql).await.unwrap();
+	t.skip_ok(2).unwrap();
+	t.expect_val("{ building: false, initial: 0, pending: 0, progress: 1.0, updated: 0 }").unwrap();
+	t.expect_val(
+		"{ building: false, cols: 'name, email', index: 'UNIQUE', initial: 0, name: 'index', pending: 0, progress: 1.0, updated: 0, what: 'user' }",
+	)
+	.unwrap();
+}
+
 #[tokio::test]
 async fn info_for_user() {
 	let sql = r#"
@@ -152,13 +335,43 @@ async fn info_for_user() {
 // Permissions
 //
 
+#[tokio::test]
+async fn info_for_user_structure_roles_and_access() {
+	let sql = r#"
+        DEFINE USER user ON DB PASSWORD 'pass' ROLES EDITOR, OWNER;
+        DEFINE USER other ON DB PASSWORD 'pass';
+        DEFINE ACCESS api ON DB TYPE BEARER FOR USER;
+        DEFINE ACCESS unused ON DB TYPE BEARER FOR USER;
+        ACCESS api ON DB GRANT FOR USER user;
+        INFO FOR USER user ON DB STRUCTURE;
+        INFO FOR USER other ON DB STRUCTURE;
+    "#;
+	let dbs = new_ds().await.unwrap().with_capabilities(
+		Capabilities::default().with_experimental(ExperimentalTarget::BearerAccess.into()),
+	);
+	let ses = Session::owner().with_ns("ns").with_db("db");
+
+	let mut res = dbs.execute(sql, &ses, None).await.unwrap();
+	assert_eq!(res.len(), 7);
+
+	let out = res.pop().unwrap().output().unwrap();
+	let out_str = out.to_string();
+	assert!(out_str.contains("access: []"), "{out_str}");
+	assert!(out_str.contains("roles: ['Viewer']"), "{out_str}");
+
+	let out = res.pop().unwrap().output().unwrap();
+	let out_str = out.to_string();
+	assert!(out_str.contains("access: ['api']"), "{out_str}");
+	assert!(out_str.contains("roles: ['EDITOR', 'OWNER']"), "{out_str}");
+}
+
 #[tokio::test]
 async fn permissions_checks_info_root() {
 	let scenario =
 		HashMap::from([("prepare", ""), ("test", "INFO FOR ROOT"), ("check", "INFO FOR ROOT")]);
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
-	let check = "{ accesses: {  }, namespaces: {  }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }";
+	let check = "{ accesses: {  }, namespaces: {  }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, disk_available: NULL, disk_total: NULL, disk_used: NULL, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }";
 	let check_results = [vec![check], vec![check]];
 
 	let test_cases = [
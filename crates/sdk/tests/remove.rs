@@ -8,9 +8,10 @@ use helpers::*;
 mod util;
 
 use std::collections::HashMap;
-use surrealdb::dbs::Session;
+use surrealdb::dbs::{Action, Session};
 use surrealdb::err::Error;
 use surrealdb::iam::Role;
+use surrealdb::kvs::{LockType, TransactionType};
 use surrealdb::sql::Value;
 
 #[tokio::test]
@@ -98,6 +99,289 @@ async fn remove_statement_namespace() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn remove_statement_namespace_detail() -> Result<(), Error> {
+	let sql = "
+		DEFINE NAMESPACE test;
+		USE NS test;
+		DEFINE USER alice ON NAMESPACE PASSWORD 'secret' ROLES VIEWER;
+		DEFINE DATABASE one;
+		DEFINE DATABASE two;
+		USE DB one;
+		DEFINE TABLE person;
+		DEFINE TABLE account;
+		USE DB two;
+		DEFINE TABLE person;
+		REMOVE NAMESPACE test DETAIL;
+		REMOVE NAMESPACE IF EXISTS test DETAIL;
+	";
+	let dbs = new_ds().await?;
+	let ses = Session::owner();
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 12);
+	//
+	for _ in 0..10 {
+		let tmp = res.remove(0).result;
+		tmp.unwrap();
+	}
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"{
+			accesses: 0,
+			databases: 2,
+			tables: 3,
+			users: 1
+		}",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	assert_eq!(tmp, Value::None);
+	Ok(())
+}
+
+#[tokio::test]
+async fn remove_statement_namespace_report() -> Result<(), Error> {
+	let sql = "
+		DEFINE NAMESPACE test;
+		REMOVE NAMESPACE IF EXISTS test REPORT;
+		REMOVE NAMESPACE IF EXISTS test REPORT;
+		REMOVE NAMESPACE IF EXISTS test;
+	";
+	let dbs = new_ds().await?;
+	let ses = Session::owner();
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 4);
+	//
+	let tmp = res.remove(0).result;
+	tmp.unwrap();
+	// The namespace existed and was removed
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("{ removed: true }");
+	assert_eq!(tmp, val);
+	// The namespace no longer exists
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("{ removed: false }");
+	assert_eq!(tmp, val);
+	// Nothing is reported by default
+	let tmp = res.remove(0).result?;
+	assert_eq!(tmp, Value::None);
+	Ok(())
+}
+
+#[tokio::test]
+async fn remove_statement_namespace_detail_report() -> Result<(), Error> {
+	let sql = "
+		DEFINE NAMESPACE test;
+		USE NS test;
+		DEFINE DATABASE one;
+		REMOVE NAMESPACE test DETAIL REPORT;
+		REMOVE NAMESPACE IF EXISTS test DETAIL REPORT;
+	";
+	let dbs = new_ds().await?;
+	let ses = Session::owner();
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 5);
+	//
+	for _ in 0..3 {
+		let tmp = res.remove(0).result;
+		tmp.unwrap();
+	}
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"{
+			accesses: 0,
+			databases: 1,
+			removed: true,
+			tables: 0,
+			users: 0
+		}",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("{ removed: false }");
+	assert_eq!(tmp, val);
+	Ok(())
+}
+
+#[tokio::test]
+async fn remove_statement_namespace_dry() -> Result<(), Error> {
+	let sql = "
+		DEFINE NAMESPACE test;
+		USE NS test;
+		DEFINE USER alice ON NAMESPACE PASSWORD 'secret' ROLES VIEWER;
+		DEFINE DATABASE one;
+		USE DB one;
+		DEFINE TABLE person;
+		REMOVE NAMESPACE test DRY;
+		INFO FOR ROOT;
+		REMOVE NAMESPACE missing DRY;
+	";
+	let dbs = new_ds().await?;
+	let ses = Session::owner();
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 9);
+	//
+	for _ in 0..6 {
+		let tmp = res.remove(0).result;
+		tmp.unwrap();
+	}
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"{
+			accesses: [],
+			databases: ['one'],
+			namespace: 'test',
+			tables: { one: ['person'] },
+			users: ['alice']
+		}",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("{ test: 'DEFINE NAMESPACE test' }");
+	assert_eq!(tmp.pick(&["namespaces".into()]), val);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_err());
+	Ok(())
+}
+
+#[tokio::test]
+async fn remove_statement_namespace_concurrently() -> Result<(), Error> {
+	let sql = "
+		DEFINE NAMESPACE test;
+		USE NS test DB test;
+		CREATE person:tobie;
+		REMOVE NAMESPACE test CONCURRENTLY;
+		INFO FOR ROOT;
+	";
+	let dbs = new_ds().await?;
+	let ses = Session::owner();
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 5);
+	//
+	for _ in 0..4 {
+		let tmp = res.remove(0).result;
+		tmp.unwrap();
+	}
+	// The namespace is no longer listed
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("{}");
+	assert_eq!(tmp.pick(&["namespaces".into()]), val);
+	// The data has not yet been reclaimed
+	let beg = b"/*test\x00".to_vec();
+	let end = b"/*test\x00\xff".to_vec();
+	let txn = dbs.transaction(TransactionType::Read, LockType::Optimistic).await?;
+	assert!(!txn.keys(beg.clone()..end.clone(), 1, None).await?.is_empty());
+	txn.cancel().await?;
+	// Reclaim the data in the background
+	dbs.namespace_removal_process().await?;
+	let txn = dbs.transaction(TransactionType::Read, LockType::Optimistic).await?;
+	assert!(txn.keys(beg..end, 1, None).await?.is_empty());
+	txn.cancel().await?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn remove_statement_namespace_concurrently_batched() -> Result<(), Error> {
+	let sql = "
+		USE NS test DB test;
+		CREATE |person:3|;
+		REMOVE NAMESPACE test CONCURRENTLY;
+	";
+	let dbs = new_ds().await?;
+	let ses = Session::owner();
+	for res in dbs.execute(sql, &ses, None).await? {
+		res.result?;
+	}
+	// The data is reclaimed one key per transaction
+	let mut batches = 0;
+	loop {
+		let txn = dbs.transaction(TransactionType::Write, LockType::Optimistic).await?;
+		let done = txn.reclaim_ns_batch("test", 1).await?;
+		// The namespace stays pending removal while data remains
+		assert_eq!(txn.get(b"/!nrtest\x00".to_vec(), None).await?.is_none(), done);
+		txn.commit().await?;
+		batches += 1;
+		if done {
+			break;
+		}
+	}
+	assert!(batches > 3);
+	let beg = b"/*test\x00".to_vec();
+	let end = b"/*test\x00\xff".to_vec();
+	let txn = dbs.transaction(TransactionType::Read, LockType::Optimistic).await?;
+	assert!(txn.keys(beg..end, 1, None).await?.is_empty());
+	txn.cancel().await?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn remove_statement_namespace_concurrently_redefine() -> Result<(), Error> {
+	let sql = "
+		USE NS test DB test;
+		CREATE person:tobie;
+		REMOVE NAMESPACE test CONCURRENTLY;
+		DEFINE NAMESPACE test;
+		USE NS test DB test;
+		CREATE person:jaime;
+	";
+	let mut t = Test::new(sql).await?;
+	t.skip_ok(3)?;
+	// The namespace can not be defined until its data is reclaimed
+	t.expect_error("The namespace 'test' is still being removed")?;
+	t.skip_ok(1)?;
+	t.expect_error("The namespace 'test' is still being removed")?;
+	// Once reclaimed, the namespace can be defined again
+	t.ds.namespace_removal_process().await?;
+	let sql = "
+		USE NS test DB test;
+		CREATE person:jaime;
+		SELECT * FROM person;
+	";
+	let res = &mut t.ds.execute(sql, &t.session, None).await?;
+	assert_eq!(res.len(), 3);
+	res.remove(0).result?;
+	res.remove(0).result?;
+	let tmp = res.remove(0).result?;
+	assert_eq!(tmp, Value::parse("[{ id: person:jaime }]"));
+	Ok(())
+}
+
+#[tokio::test]
+async fn remove_statement_namespace_live_notification() -> Result<(), Error> {
+	for (sql, expunge) in
+		[("REMOVE NAMESPACE test", false), ("REMOVE NAMESPACE AND EXPUNGE test", true)]
+	{
+		let dbs = new_ds().await?.with_notifications();
+		let ses = Session::owner().with_ns("test").with_db("test").with_rt(true);
+		let res = &mut dbs.execute("LIVE SELECT * FROM person", &ses, None).await?;
+		let Value::Uuid(live_id) = res.remove(0).result? else {
+			panic!("expected uuid");
+		};
+		let res = &mut dbs.execute(sql, &Session::owner(), None).await?;
+		res.remove(0).result?;
+		// The live query is notified that its namespace has been removed
+		let notifications = dbs.notifications().expect("expected notifications");
+		let notification = notifications.recv().await.unwrap();
+		assert_eq!(notification.id, live_id);
+		assert_eq!(notification.action, Action::Remove);
+		assert_eq!(notification.record, Value::from("test"));
+		let result = notification.result;
+		assert_eq!(result.pick(&["namespace".into()]), Value::from("test"));
+		assert_eq!(result.pick(&["expunge".into()]), Value::from(expunge));
+		assert_eq!(result.pick(&["actor".into(), "level".into()]), Value::from("/"));
+		assert!(result.pick(&["timestamp".into()]).is_datetime());
+		assert!(notifications.is_empty());
+	}
+	Ok(())
+}
+
 #[tokio::test]
 async fn remove_statement_database() -> Result<(), Error> {
 	// Database not selected
@@ -584,8 +868,8 @@ async fn permissions_checks_remove_ns() {
 	]);
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
-	let check1 = "{ accesses: {  }, namespaces: {  }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }";
-	let check2 = "{ accesses: {  }, namespaces: { NS: 'DEFINE NAMESPACE NS' }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }";
+	let check1 = "{ accesses: {  }, namespaces: {  }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, disk_available: NULL, disk_total: NULL, disk_used: NULL, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }";
+	let check2 = "{ accesses: {  }, namespaces: { NS: 'DEFINE NAMESPACE NS' }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, disk_available: NULL, disk_total: NULL, disk_used: NULL, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }";
 	let check_results = [vec![check1], vec![check2]];
 
 	let test_cases = [
@@ -751,8 +1035,8 @@ async fn permissions_checks_remove_root_access() {
 	]);
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
-	let check1 = "{ accesses: {  }, namespaces: {  }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }";
-	let check2 = r#"{ accesses: { access: "DEFINE ACCESS access ON ROOT TYPE JWT ALGORITHM HS512 KEY '[REDACTED]' WITH ISSUER KEY '[REDACTED]' DURATION FOR TOKEN 1h, FOR SESSION NONE" }, namespaces: {  }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }"#;
+	let check1 = "{ accesses: {  }, namespaces: {  }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, disk_available: NULL, disk_total: NULL, disk_used: NULL, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }";
+	let check2 = r#"{ accesses: { access: "DEFINE ACCESS access ON ROOT TYPE JWT ALGORITHM HS512 KEY '[REDACTED]' WITH ISSUER KEY '[REDACTED]' DURATION FOR TOKEN 1h, FOR SESSION NONE" }, namespaces: {  }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, disk_available: NULL, disk_total: NULL, disk_used: NULL, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }"#;
 	let check_results = [vec![check1], vec![check2]];
 
 	let test_cases = [
@@ -876,8 +1160,8 @@ async fn permissions_checks_remove_root_user() {
 	]);
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
-	let check1 = "{ accesses: {  }, namespaces: {  }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }";
-	let check2 = r#"{ accesses: {  }, namespaces: {  }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: { user: "DEFINE USER user ON ROOT PASSHASH 'secret' ROLES VIEWER DURATION FOR TOKEN 1h, FOR SESSION NONE" } }"#;
+	let check1 = "{ accesses: {  }, namespaces: {  }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, disk_available: NULL, disk_total: NULL, disk_used: NULL, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }";
+	let check2 = r#"{ accesses: {  }, namespaces: {  }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, disk_available: NULL, disk_total: NULL, disk_used: NULL, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: { user: "DEFINE USER user ON ROOT PASSHASH 'secret' ROLES VIEWER DURATION FOR TOKEN 1h, FOR SESSION NONE" } }"#;
 	let check_results = [vec![check1], vec![check2]];
 
 	let test_cases = [
@@ -1475,3 +1475,29 @@ async fn select_from_none() -> Result<(), Error> {
 	}
 	Ok(())
 }
+
+#[tokio::test]
+async fn select_start_percentage() -> Result<(), Error> {
+	let sql: &str = "
+		CREATE |item:1..10|;
+		SELECT VALUE id FROM item START 0%;
+		SELECT VALUE id FROM item START 50%;
+		SELECT VALUE id FROM item START 50% LIMIT 2;
+		SELECT VALUE id FROM item ORDER BY id DESC START AT 80%;
+		SELECT VALUE id FROM item START 100%;
+		SELECT VALUE id FROM item START 150%;
+		SELECT VALUE id FROM item START 10 % 3;
+	";
+	let mut t = Test::new(sql).await?;
+	t.skip_ok(1)?;
+	t.expect_val(
+		"[item:1, item:2, item:3, item:4, item:5, item:6, item:7, item:8, item:9, item:10]",
+	)?;
+	t.expect_val("[item:6, item:7, item:8, item:9, item:10]")?;
+	t.expect_val("[item:6, item:7]")?;
+	t.expect_val("[item:2, item:1]")?;
+	t.expect_val("[]")?;
+	t.expect_error("Found 150 but the START percentage must be between 0 and 100")?;
+	t.expect_val("[item:2, item:3, item:4, item:5, item:6, item:7, item:8, item:9, item:10]")?;
+	Ok(())
+}
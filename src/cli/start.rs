@@ -62,6 +62,13 @@ pub struct StartCommandArguments {
 	#[arg(env = "SURREAL_CHANGEFEED_GC_INTERVAL", long = "changefeed-gc-interval", value_parser = super::validator::duration)]
 	#[arg(default_value = "10s")]
 	changefeed_gc_interval: Duration,
+	#[arg(
+		help = "The interval at which to reclaim the data of removed namespaces",
+		help_heading = "Database"
+	)]
+	#[arg(env = "SURREAL_NAMESPACE_REMOVAL_INTERVAL", long = "namespace-removal-interval", value_parser = super::validator::duration)]
+	#[arg(default_value = "10s")]
+	namespace_removal_interval: Duration,
 	//
 	// Authentication
 	//
@@ -159,6 +166,7 @@ pub async fn init(
 		node_membership_check_interval,
 		node_membership_cleanup_interval,
 		changefeed_gc_interval,
+		namespace_removal_interval,
 		no_banner,
 		no_identification_headers,
 		..
@@ -186,7 +194,8 @@ pub async fn init(
 		.with_node_membership_refresh_interval(node_membership_refresh_interval)
 		.with_node_membership_check_interval(node_membership_check_interval)
 		.with_node_membership_cleanup_interval(node_membership_cleanup_interval)
-		.with_changefeed_gc_interval(changefeed_gc_interval);
+		.with_changefeed_gc_interval(changefeed_gc_interval)
+		.with_namespace_removal_interval(namespace_removal_interval);
 	// Configure the config
 	let config = Config {
 		bind: listen_addresses.first().cloned().unwrap(),
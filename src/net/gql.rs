@@ -3,7 +3,7 @@ use std::sync::Arc;
 
 use axum::routing::post_service;
 
-use surrealdb::gql::cache::Pessimistic;
+use surrealdb::gql::cache::Generational;
 use surrealdb::kvs::Datastore;
 
 use crate::gql::GraphQL;
@@ -12,6 +12,6 @@ pub(super) async fn router<S>(ds: Arc<Datastore>) -> Router<S>
 where
 	S: Clone + Send + Sync + 'static,
 {
-	let service = GraphQL::new(Pessimistic, ds);
+	let service = GraphQL::new(Generational, ds);
 	Router::new().route("/graphql", post_service(service))
 }
@@ -10,13 +10,13 @@ use surrealdb_core::rpc::RpcProtocolV2;
 use surrealdb_core::sql::Array;
 use tokio::sync::Semaphore;
 
-use surrealdb_core::gql::{Pessimistic, SchemaCache};
+use surrealdb_core::gql::SchemaCache;
 
 pub struct Http {
 	pub kvs: Arc<Datastore>,
 	pub lock: Arc<Semaphore>,
 	pub session: Arc<Session>,
-	pub gql_schema: SchemaCache<Pessimistic>,
+	pub gql_schema: SchemaCache,
 }
 
 impl Http {
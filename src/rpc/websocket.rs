@@ -21,7 +21,7 @@ use opentelemetry::Context as TelemetryContext;
 use std::sync::Arc;
 use std::time::Duration;
 use surrealdb::dbs::Session;
-use surrealdb::gql::{Pessimistic, SchemaCache};
+use surrealdb::gql::SchemaCache;
 use surrealdb::kvs::Datastore;
 use surrealdb::mem::ALLOC;
 use surrealdb::rpc::format::Format;
@@ -66,7 +66,7 @@ pub struct Websocket {
 	/// The channels used to send and receive WebSocket messages
 	pub(crate) channel: Sender<Message>,
 	/// The GraphQL schema cache stored in advance
-	pub(crate) gql_schema: SchemaCache<Pessimistic>,
+	pub(crate) gql_schema: SchemaCache,
 }
 
 impl Websocket {
@@ -1,7 +1,7 @@
 mod common;
 
 mod graphql_integration {
-	use std::{str::FromStr, time::Duration};
+	use std::{collections::HashMap, str::FromStr, time::Duration};
 
 	macro_rules! assert_equal_arrs {
 		($lhs: expr, $rhs: expr) => {
@@ -454,6 +454,9 @@ mod graphql_integration {
 					DEFINE FUNCTION fn::double($x: int) -> int {return $x * 2};
 					DEFINE FUNCTION fn::foo() -> record<foo> {return foo:1};
 					DEFINE FUNCTION fn::record() -> record {return foo:1};
+					DEFINE FUNCTION fn::add($a: int, $b: int) -> int {return $a + $b};
+					DEFINE FUNCTION fn::untyped() {return 1};
+					DEFINE FUNCTION fn::matches($r: regex) -> bool {return true};
                 "#,
 				)
 				.send()
@@ -466,7 +469,7 @@ mod graphql_integration {
 			let res = client
 				.post(gql_url)
 				.body(
-					json!({"query": r#"query{fn_foo{id, val}, fn_record {id ...on foo {val}}}"#})
+					json!({"query": r#"query{fn_foo{id, val}, fn_record {id ...on Foo {val}}}"#})
 						.to_string(),
 				)
 				.send()
@@ -505,6 +508,3369 @@ mod graphql_integration {
 			assert_eq!(expected.to_string(), body)
 		}
 
+		{
+			let res = client
+				.post(gql_url)
+				.body(json!({"query": r#"query{fn_add(a: 40, b: 2)}"#}).to_string())
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let expected = json!({
+			  "data": {
+				"fn_add": 42
+			  }
+			});
+			assert_eq!(expected.to_string(), body)
+		}
+
+		// functions without a representable signature are left out of the schema
+		{
+			let res = client
+				.post(gql_url)
+				.body(
+					json!({"query": r#"query{__type(name: "Query") {fields {name}}}"#}).to_string(),
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			assert!(body.contains("fn_add"), "body: {body}");
+			assert!(!body.contains("fn_untyped"), "body: {body}");
+			assert!(!body.contains("fn_matches"), "body: {body}");
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn filter() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema and data
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+					DEFINE TABLE foo SCHEMAFUL;
+					DEFINE FIELD val ON foo TYPE int;
+					DEFINE FIELD name ON foo TYPE option<string>;
+					CREATE foo:1 set val = 42, name = "one";
+					CREATE foo:2 set val = 43, name = "two";
+					CREATE foo:3 set val = 42;
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		// filter with eq
+		{
+			let res = client
+				.post(gql_url)
+				.body(json!({"query": r#"query{foos(filterBy: {val: {eq: 42}}){id}}"#}).to_string())
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let expected = json!({
+				"data": {
+					"foos": [
+						{
+							"id": "foo:1",
+						},
+						{
+							"id": "foo:3",
+						}
+					]
+				}
+			});
+			assert_eq!(expected.to_string(), body)
+		}
+
+		// filter with ne
+		{
+			let res = client
+				.post(gql_url)
+				.body(
+					json!({"query": r#"query{foos(filterBy: {name: {ne: "one"}}){id}}"#})
+						.to_string(),
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let expected = json!({
+				"data": {
+					"foos": [
+						{
+							"id": "foo:2",
+						},
+						{
+							"id": "foo:3",
+						}
+					]
+				}
+			});
+			assert_eq!(expected.to_string(), body)
+		}
+
+		// filter with comparison operators
+		{
+			let res = client
+				.post(gql_url)
+				.body(json!({"query": r#"query{foos(filterBy: {val: {gt: 42}}){id}}"#}).to_string())
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let expected = json!({
+				"data": {
+					"foos": [
+						{
+							"id": "foo:2",
+						}
+					]
+				}
+			});
+			assert_eq!(expected.to_string(), body)
+		}
+
+		{
+			let res = client
+				.post(gql_url)
+				.body(
+					json!({"query": r#"query{foos(filterBy: {val: {lte: 42}}){id}}"#}).to_string(),
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let expected = json!({
+				"data": {
+					"foos": [
+						{
+							"id": "foo:1",
+						},
+						{
+							"id": "foo:3",
+						}
+					]
+				}
+			});
+			assert_eq!(expected.to_string(), body)
+		}
+
+		// filter on id
+		{
+			let res = client
+				.post(gql_url)
+				.body(
+					json!({"query": r#"query{foos(filterBy: {id: {eq: "foo:2"}}){id}}"#})
+						.to_string(),
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let expected = json!({
+				"data": {
+					"foos": [
+						{
+							"id": "foo:2",
+						}
+					]
+				}
+			});
+			assert_eq!(expected.to_string(), body)
+		}
+
+		// filter with nested and/or
+		{
+			let res = client
+				.post(gql_url)
+				.body(
+					json!({"query": r#"query{foos(filterBy: {and: [{val: {eq: 42}}, {or: [{name: {ne: "one"}}, {id: {eq: "foo:2"}}]}]}){id}}"#})
+						.to_string(),
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let expected = json!({
+				"data": {
+					"foos": [
+						{
+							"id": "foo:3",
+						}
+					]
+				}
+			});
+			assert_eq!(expected.to_string(), body)
+		}
+
+		// filter with not
+		{
+			let res = client
+				.post(gql_url)
+				.body(
+					json!({"query": r#"query{foos(filterBy: {not: {val: {eq: 42}}}){id}}"#})
+						.to_string(),
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let expected = json!({
+				"data": {
+					"foos": [
+						{
+							"id": "foo:2",
+						}
+					]
+				}
+			});
+			assert_eq!(expected.to_string(), body)
+		}
+
+		// filters must have exactly one key
+		{
+			let res = client
+				.post(gql_url)
+				.body(
+					json!({"query": r#"query{foos(filterBy: {val: {eq: 42}, name: {eq: "one"}}){id}}"#})
+						.to_string(),
+				)
+				.send()
+				.await?;
+			let body = res.text().await?;
+			assert!(body.contains("exactly one key"), "body: {body}");
+		}
+
+		// unknown fields are rejected
+		{
+			let res = client
+				.post(gql_url)
+				.body(
+					json!({"query": r#"query{foos(filterBy: {other: {eq: 1}}){id}}"#}).to_string(),
+				)
+				.send()
+				.await?;
+			let body = res.text().await?;
+			assert!(body.contains("other"), "body: {body}");
+			assert!(body.contains("errors"), "body: {body}");
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn filter_schema() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+					DEFINE TABLE foo SCHEMAFUL;
+					DEFINE FIELD int ON foo TYPE int;
+					DEFINE FIELD float ON foo TYPE float;
+					DEFINE FIELD decimal ON foo TYPE decimal;
+					DEFINE FIELD number ON foo TYPE number;
+					DEFINE FIELD datetime ON foo TYPE datetime;
+					DEFINE FIELD duration ON foo TYPE duration;
+					DEFINE FIELD string ON foo TYPE string;
+					DEFINE FIELD bool ON foo TYPE bool;
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		let ordered = json!([{"name": "eq"}, {"name": "ne"}, {"name": "gt"}, {"name": "gte"}, {"name": "lt"}, {"name": "lte"}]);
+		let unordered = json!([{"name": "eq"}, {"name": "ne"}]);
+		let strings = json!([{"name": "eq"}, {"name": "ne"}, {"name": "contains"}, {"name": "startsWith"}, {"name": "endsWith"}, {"name": "matches"}]);
+		for (ty, expected) in [
+			("IntFilterInput", &ordered),
+			("FloatFilterInput", &ordered),
+			("DecimalFilterInput", &ordered),
+			("NumberFilterInput", &ordered),
+			("DateTimeFilterInput", &ordered),
+			("DurationFilterInput", &ordered),
+			("StringFilterInput", &strings),
+			("BooleanFilterInput", &unordered),
+			("IDFilterInput", &unordered),
+		] {
+			let query = format!(r#"{{__type(name: "{ty}") {{inputFields {{name}}}}}}"#);
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body).unwrap();
+			let fields = &res_obj["data"]["__type"]["inputFields"];
+			assert_equal_arrs!(fields, expected);
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn enums() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema and data
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+					DEFINE TABLE foo SCHEMAFUL;
+					DEFINE FIELD home_type ON foo TYPE "house" | "flat";
+					DEFINE FIELD alt_type ON foo TYPE option<"house" | "flat">;
+					CREATE foo:1 set home_type = "house";
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		// required enums are non-null, optional enums are nullable
+		{
+			let res = client
+				.post(gql_url)
+				.body(
+					json!({"query": r#"query{__type(name: "Foo") {fields {name type {kind name ofType {kind name}}}}}"#})
+						.to_string(),
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let fields = res_obj["data"]["__type"]["fields"].as_array().unwrap();
+			let field_type =
+				|name: &str| fields.iter().find(|f| f["name"] == name).unwrap()["type"].clone();
+
+			let home_type = field_type("homeType");
+			assert_eq!(home_type["kind"], "NON_NULL", "body: {body}");
+			assert_eq!(home_type["ofType"]["kind"], "ENUM", "body: {body}");
+
+			let alt_type = field_type("altType");
+			assert_eq!(alt_type["kind"], "ENUM", "body: {body}");
+		}
+
+		{
+			let res = client
+				.post(gql_url)
+				.body(json!({"query": r#"query{foos{homeType, altType}}"#}).to_string())
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let expected = json!({
+				"data": {
+					"foos": [
+						{
+							"homeType": "HOUSE",
+							"altType": null
+						}
+					]
+				}
+			});
+			assert_eq!(expected.to_string(), body)
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn relations() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema and data
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+					DEFINE TABLE user SCHEMAFUL;
+					DEFINE FIELD name ON user TYPE string;
+					DEFINE TABLE post SCHEMAFUL;
+					DEFINE FIELD title ON post TYPE string;
+					DEFINE TABLE video SCHEMAFUL;
+					DEFINE FIELD title ON video TYPE string;
+					DEFINE TABLE follow TYPE RELATION FROM user TO user SCHEMAFUL;
+					DEFINE TABLE like TYPE RELATION FROM user TO post | video SCHEMAFUL;
+					DEFINE FIELD rating ON like TYPE int;
+					CREATE user:1 SET name = "one";
+					CREATE user:2 SET name = "two";
+					CREATE user:3 SET name = "three";
+					CREATE post:1 SET title = "first post";
+					CREATE video:1 SET title = "first video";
+					RELATE user:1->follow:1->user:2;
+					RELATE user:1->follow:2->user:3;
+					RELATE user:1->like:1->post:1 SET rating = 5;
+					RELATE user:1->like:2->video:1 SET rating = 3;
+					RELATE user:2->like:3->post:1 SET rating = 4;
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		// relations to a single table
+		{
+			let res = client
+				.post(gql_url)
+				.body(
+					json!({"query": r#"query{users{id, follows{totalCount, nodes{name}}}}"#})
+						.to_string(),
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let expected = json!({
+				"data": {
+					"users": [
+						{
+							"id": "user:1",
+							"follows": {
+								"totalCount": 2,
+								"nodes": [
+									{
+										"name": "two"
+									},
+									{
+										"name": "three"
+									}
+								]
+							}
+						},
+						{
+							"id": "user:2",
+							"follows": {
+								"totalCount": 0,
+								"nodes": []
+							}
+						},
+						{
+							"id": "user:3",
+							"follows": {
+								"totalCount": 0,
+								"nodes": []
+							}
+						}
+					]
+				}
+			});
+			assert_eq!(expected.to_string(), body)
+		}
+
+		// relations to multiple tables, with fields on the edges
+		{
+			let res = client
+				.post(gql_url)
+				.body(
+					json!({"query": r#"query{user(id: "user:1"){likes{edges{rating, node{...on Post{id, title} ...on Video{id, title}}}}}}"#})
+						.to_string(),
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let expected = json!({
+				"data": {
+					"user": {
+						"likes": {
+							"edges": [
+								{
+									"rating": 5,
+									"node": {
+										"id": "post:1",
+										"title": "first post"
+									}
+								},
+								{
+									"rating": 3,
+									"node": {
+										"id": "video:1",
+										"title": "first video"
+									}
+								}
+							]
+						}
+					}
+				}
+			});
+			assert_eq!(expected.to_string(), body)
+		}
+
+		// relation connections are paginated
+		{
+			let res = client
+				.post(gql_url)
+				.body(
+					json!({"query": r#"query{user(id: "user:1"){follows(first: 1){nodes{name}, pageInfo{hasNextPage}}}}"#})
+						.to_string(),
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let expected = json!({
+				"data": {
+					"user": {
+						"follows": {
+							"nodes": [
+								{
+									"name": "two"
+								}
+							],
+							"pageInfo": {
+								"hasNextPage": true
+							}
+						}
+					}
+				}
+			});
+			assert_eq!(expected.to_string(), body)
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn error_codes() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+					DEFINE TABLE foo SCHEMAFUL;
+					DEFINE FIELD val ON foo TYPE int;
+					DEFINE FIELD link ON foo TYPE option<record<foo>>;
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		// input errors
+		{
+			let res = client
+				.post(gql_url)
+				.body(
+					json!({"query": r#"query{foos(filterBy: {val: {eq: 1}, link: {eq: "foo:1"}}){id}}"#})
+						.to_string(),
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			assert_eq!(res_obj["errors"][0]["extensions"]["code"], "INPUT_ERROR", "body: {body}");
+		}
+
+		// type errors include the target kind and the offending value
+		{
+			let res = client
+				.post(gql_url)
+				.body(
+					json!({"query": r#"mutation{createFoo(input: {val: 1, link: "bar:1"}){id}}"#})
+						.to_string(),
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let extensions = &res_obj["errors"][0]["extensions"];
+			assert_eq!(extensions["code"], "TYPE_ERROR", "body: {body}");
+			assert_eq!(extensions["target"], "record<foo>", "body: {body}");
+			assert_eq!(extensions["value"], "bar:1", "body: {body}");
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn field_permissions() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+		let signup_url = &format!("http://{addr}/signup");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema and data
+		{
+			let res = client
+				.post(sql_url)
+				.basic_auth(USER, Some(PASS))
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+					DEFINE ACCESS user ON DATABASE TYPE RECORD
+					SIGNUP ( CREATE user SET email = $email, pass = crypto::argon2::generate($pass) )
+					SIGNIN ( SELECT * FROM user WHERE email = $email AND crypto::argon2::compare(pass, $pass) )
+					DURATION FOR SESSION 60s, FOR TOKEN 1d;
+
+					DEFINE TABLE foo SCHEMAFUL PERMISSIONS FULL;
+					DEFINE FIELD val ON foo TYPE int;
+					DEFINE FIELD secret ON foo TYPE string PERMISSIONS NONE;
+					DEFINE FIELD owner ON foo TYPE string PERMISSIONS FOR select WHERE $auth.email = $value;
+					CREATE foo:1 SET val = 1, secret = "one", owner = "user@email.com";
+					CREATE foo:2 SET val = 2, secret = "two", owner = "other@email.com";
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		let fields_query =
+			json!({"query": r#"query{__type(name: "Foo") {fields {name type {kind}}}}"#})
+				.to_string();
+
+		// all fields are visible to root
+		{
+			let res = client
+				.post(gql_url)
+				.basic_auth(USER, Some(PASS))
+				.body(fields_query.clone())
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let fields = &res_obj["data"]["__type"]["fields"];
+			let expected = json!([
+				{"name": "id", "type": {"kind": "NON_NULL"}},
+				{"name": "val", "type": {"kind": "NON_NULL"}},
+				{"name": "secret", "type": {"kind": "NON_NULL"}},
+				{"name": "owner", "type": {"kind": "NON_NULL"}},
+			]);
+			assert_equal_arrs!(fields, &expected);
+		}
+
+		// sign up as a record user
+		let token = {
+			let req_body = json!({
+				"ns": ns,
+				"db": db,
+				"ac": "user",
+				"email": "user@email.com",
+				"pass": "pass",
+			})
+			.to_string();
+
+			let res = client.post(signup_url).body(req_body).send().await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?)?;
+			body["token"].as_str().unwrap().to_string()
+		};
+
+		// fields which can never be selected are hidden, conditional fields are nullable
+		{
+			let res = client.post(gql_url).bearer_auth(&token).body(fields_query).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let fields = &res_obj["data"]["__type"]["fields"];
+			let expected = json!([
+				{"name": "id", "type": {"kind": "NON_NULL"}},
+				{"name": "val", "type": {"kind": "NON_NULL"}},
+				{"name": "owner", "type": {"kind": "SCALAR"}},
+			]);
+			assert_equal_arrs!(fields, &expected);
+		}
+
+		// conditional fields resolve to null where the permission check fails
+		{
+			let res = client
+				.post(gql_url)
+				.bearer_auth(&token)
+				.body(json!({"query": r#"query{foos{id, val, owner}}"#}).to_string())
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let expected = json!({
+				"data": {
+					"foos": [
+						{
+							"id": "foo:1",
+							"val": 1,
+							"owner": "user@email.com"
+						},
+						{
+							"id": "foo:2",
+							"val": 2,
+							"owner": null
+						}
+					]
+				}
+			});
+			assert_eq!(expected.to_string(), body)
+		}
+
+		// hidden fields can not be queried
+		{
+			let res = client
+				.post(gql_url)
+				.bearer_auth(&token)
+				.body(json!({"query": r#"query{foos{secret}}"#}).to_string())
+				.send()
+				.await?;
+			let body = res.text().await?;
+			assert!(body.contains("Unknown field"), "body: {body}");
+			assert!(body.contains("secret"), "body: {body}");
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn json() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema and data
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+					DEFINE TABLE foo TYPE NORMAL SCHEMALESS;
+					DEFINE FIELD val ON foo TYPE int;
+					DEFINE FIELD data ON foo TYPE object;
+					DEFINE FIELD anything ON foo TYPE any;
+					DEFINE FIELD list ON foo TYPE option<array<object>>;
+					CREATE foo:1 SET val = 1, data = {a: {b: [1, "two", {c: true}]}}, anything = [1, {d: null}], list = [{e: 1}, {f: [2]}];
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		// untyped values are read as JSON
+		{
+			let res = client
+				.post(gql_url)
+				.body(
+					json!({"query": r#"query{foo(id: "foo:1"){data, anything, list}}"#})
+						.to_string(),
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let expected = json!({
+				"data": {
+					"foo": {
+						"data": {"a": {"b": [1, "two", {"c": true}]}},
+						"anything": [1, {"d": null}],
+						"list": [{"e": 1}, {"f": [2]}]
+					}
+				}
+			});
+			assert_eq!(expected.to_string(), body)
+		}
+
+		// untyped values are written as JSON
+		{
+			let res = client
+				.post(gql_url)
+				.body(
+					json!({"query": r#"mutation{createFoo(input: {val: 2, data: {x: {y: [1, 2]}}, anything: {z: "z"}}){id, data, anything}}"#})
+						.to_string(),
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let created = &res_obj["data"]["createFoo"];
+			assert_eq!(created["data"], json!({"x": {"y": [1, 2]}}), "body: {body}");
+			assert_eq!(created["anything"], json!({"z": "z"}), "body: {body}");
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn cursor_pagination() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema and data
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO CURSOR;
+					DEFINE TABLE foo SCHEMAFUL;
+					DEFINE FIELD val ON foo TYPE int;
+					CREATE foo:1 set val = 1;
+					CREATE foo:2 set val = 2;
+					CREATE foo:3 set val = 3;
+					CREATE foo:4 set val = 4;
+					CREATE foo:5 set val = 5;
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		let page = |args: String| {
+			let client = client.clone();
+			async move {
+				let args = if args.is_empty() {
+					args
+				} else {
+					format!("({args})")
+				};
+				let query = format!(
+					"query{{foos{args}{{edges{{cursor node{{id}}}} nodes{{val}} \
+					pageInfo{{hasNextPage hasPreviousPage startCursor endCursor}} totalCount}}}}"
+				);
+				let res =
+					client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+				assert_eq!(res.status(), 200);
+				let body = res.text().await?;
+				let res_obj = serde_json::Value::from_str(&body)?;
+				assert!(res_obj["errors"].is_null(), "body: {body}");
+				Ok::<_, Box<dyn std::error::Error>>(res_obj["data"]["foos"].clone())
+			}
+		};
+		let ids = |page: &serde_json::Value| {
+			page["edges"]
+				.as_array()
+				.unwrap()
+				.iter()
+				.map(|e| e["node"]["id"].as_str().unwrap().to_string())
+				.collect::<Vec<_>>()
+		};
+
+		// paginate forwards
+		let res = page("first: 2".to_string()).await?;
+		assert_eq!(ids(&res), ["foo:1", "foo:2"]);
+		assert_eq!(res["nodes"], json!([{"val": 1}, {"val": 2}]));
+		assert_eq!(res["pageInfo"]["hasNextPage"], json!(true));
+		assert_eq!(res["pageInfo"]["hasPreviousPage"], json!(false));
+		assert_eq!(res["pageInfo"]["startCursor"], res["edges"][0]["cursor"]);
+		assert_eq!(res["pageInfo"]["endCursor"], res["edges"][1]["cursor"]);
+		assert_eq!(res["totalCount"], json!(5));
+
+		let cursor = res["pageInfo"]["endCursor"].as_str().unwrap().to_string();
+		let res = page(format!(r#"first: 2, after: "{cursor}""#)).await?;
+		assert_eq!(ids(&res), ["foo:3", "foo:4"]);
+		assert_eq!(res["pageInfo"]["hasNextPage"], json!(true));
+		assert_eq!(res["pageInfo"]["hasPreviousPage"], json!(true));
+
+		let cursor = res["pageInfo"]["endCursor"].as_str().unwrap().to_string();
+		let res = page(format!(r#"first: 2, after: "{cursor}""#)).await?;
+		assert_eq!(ids(&res), ["foo:5"]);
+		assert_eq!(res["pageInfo"]["hasNextPage"], json!(false));
+		assert_eq!(res["pageInfo"]["hasPreviousPage"], json!(true));
+		assert_eq!(res["totalCount"], json!(5));
+
+		// paginate backwards
+		let res = page("last: 2".to_string()).await?;
+		assert_eq!(ids(&res), ["foo:4", "foo:5"]);
+		assert_eq!(res["pageInfo"]["hasNextPage"], json!(false));
+		assert_eq!(res["pageInfo"]["hasPreviousPage"], json!(true));
+
+		let cursor = res["pageInfo"]["startCursor"].as_str().unwrap().to_string();
+		let res = page(format!(r#"last: 2, before: "{cursor}""#)).await?;
+		assert_eq!(ids(&res), ["foo:2", "foo:3"]);
+		assert_eq!(res["pageInfo"]["hasNextPage"], json!(true));
+		assert_eq!(res["pageInfo"]["hasPreviousPage"], json!(true));
+
+		let cursor = res["pageInfo"]["startCursor"].as_str().unwrap().to_string();
+		let res = page(format!(r#"last: 2, before: "{cursor}""#)).await?;
+		assert_eq!(ids(&res), ["foo:1"]);
+		assert_eq!(res["pageInfo"]["hasNextPage"], json!(true));
+		assert_eq!(res["pageInfo"]["hasPreviousPage"], json!(false));
+
+		// without arguments all records are returned
+		let res = page("".to_string()).await?;
+		assert_eq!(ids(&res), ["foo:1", "foo:2", "foo:3", "foo:4", "foo:5"]);
+		assert_eq!(res["pageInfo"]["hasNextPage"], json!(false));
+		assert_eq!(res["pageInfo"]["hasPreviousPage"], json!(false));
+
+		// filters apply to the records of the connection and their count
+		let res = page("first: 1, filterBy: {val: {gt: 2}}".to_string()).await?;
+		assert_eq!(ids(&res), ["foo:3"]);
+		assert_eq!(res["pageInfo"]["hasNextPage"], json!(true));
+		assert_eq!(res["pageInfo"]["hasPreviousPage"], json!(false));
+		assert_eq!(res["totalCount"], json!(3));
+
+		let cursor = res["pageInfo"]["endCursor"].as_str().unwrap().to_string();
+		let res =
+			page(format!(r#"first: 2, after: "{cursor}", filterBy: {{val: {{gt: 2}}}}"#)).await?;
+		assert_eq!(ids(&res), ["foo:4", "foo:5"]);
+		assert_eq!(res["pageInfo"]["hasNextPage"], json!(false));
+		assert_eq!(res["totalCount"], json!(3));
+
+		let res = page("filterBy: {val: {gt: 5}}".to_string()).await?;
+		assert!(ids(&res).is_empty());
+		assert_eq!(res["totalCount"], json!(0));
+
+		// invalid cursors are rejected
+		{
+			let query = r#"query{foos(after: "invalid"){nodes{val}}}"#;
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			let body = res.text().await?;
+			assert!(body.contains("Invalid cursor"), "body: {body}");
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn order() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema and data
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+					DEFINE TABLE foo SCHEMAFUL;
+					DEFINE FIELD val ON foo TYPE int;
+					DEFINE FIELD other_val ON foo TYPE string;
+					CREATE foo:1 set val = 1, other_val = "b";
+					CREATE foo:2 set val = 2, other_val = "a";
+					CREATE foo:3 set val = 1, other_val = "a";
+					CREATE foo:4 set val = 2, other_val = "b";
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		for (order, expected) in [
+			("{field: VAL, direction: DESC}", json!(["foo:2", "foo:4", "foo:1", "foo:3"])),
+			("{field: OTHER_VAL, direction: ASC}", json!(["foo:2", "foo:3", "foo:1", "foo:4"])),
+			(
+				"{field: VAL, direction: ASC, then: {field: OTHER_VAL, direction: DESC}}",
+				json!(["foo:1", "foo:3", "foo:4", "foo:2"]),
+			),
+			(
+				"{field: OTHER_VAL, direction: DESC, then: {field: VAL, direction: DESC}}",
+				json!(["foo:4", "foo:1", "foo:2", "foo:3"]),
+			),
+			("{field: ID, direction: DESC}", json!(["foo:4", "foo:3", "foo:2", "foo:1"])),
+		] {
+			let query = format!("query{{foos(orderBy: {order}){{id}}}}");
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let ids = res_obj["data"]["foos"]
+				.as_array()
+				.unwrap_or_else(|| panic!("body: {body}"))
+				.iter()
+				.map(|r| r["id"].clone())
+				.collect::<Vec<_>>();
+			assert_eq!(json!(ids), expected, "order: {order}");
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn limit_start() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema and data
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+					DEFINE TABLE foo SCHEMAFUL;
+					DEFINE FIELD val ON foo TYPE int;
+					CREATE foo:1 set val = 1;
+					CREATE foo:2 set val = 2;
+					CREATE foo:3 set val = 3;
+					CREATE foo:4 set val = 4;
+					CREATE foo:5 set val = 5;
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		for (args, expected) in [
+			("limit: 2", json!(["foo:1", "foo:2"])),
+			("start: 3", json!(["foo:4", "foo:5"])),
+			("limit: 2, start: 1", json!(["foo:2", "foo:3"])),
+			("limit: 2, start: 4", json!(["foo:5"])),
+			("limit: 2, start: 5", json!([])),
+			("limit: 0", json!([])),
+			(
+				"limit: 2, start: 1, orderBy: {field: VAL, direction: DESC}",
+				json!(["foo:4", "foo:3"]),
+			),
+			("limit: 3, filterBy: {val: {gt: 1}}", json!(["foo:2", "foo:3", "foo:4"])),
+		] {
+			let query = format!("query{{foos({args}){{id}}}}");
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let ids = res_obj["data"]["foos"]
+				.as_array()
+				.unwrap_or_else(|| panic!("body: {body}"))
+				.iter()
+				.map(|r| r["id"].clone())
+				.collect::<Vec<_>>();
+			assert_eq!(json!(ids), expected, "args: {args}");
+		}
+
+		for args in ["limit: -1", "start: -1"] {
+			let query = format!("query{{foos({args}){{id}}}}");
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			assert!(body.contains("non-negative integer"), "body: {body}");
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn mutations() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+					DEFINE TABLE foo SCHEMAFUL;
+					DEFINE FIELD val ON foo TYPE int;
+					DEFINE FIELD other_val ON foo TYPE option<string>;
+					DEFINE FIELD created ON foo TYPE datetime DEFAULT time::now();
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		// create a record
+		let id = {
+			let res = client
+				.post(gql_url)
+				.body(
+					json!({"query": r#"mutation{createFoo(input: {val: 42, otherVal: "a"}){id, val, otherVal}}"#})
+						.to_string(),
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let created = &res_obj["data"]["createFoo"];
+			assert_eq!(created["val"], json!(42), "body: {body}");
+			assert_eq!(created["otherVal"], json!("a"), "body: {body}");
+			created["id"].as_str().unwrap().to_string()
+		};
+
+		// read it back
+		{
+			let res = client
+				.post(gql_url)
+				.body(
+					json!({ "query": format!(r#"query{{foo(id: "{id}"){{id, val, otherVal}}}}"#) })
+						.to_string(),
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let expected = json!({
+				"data": {
+					"foo": {
+						"id": id,
+						"val": 42,
+						"otherVal": "a"
+					}
+				}
+			});
+			assert_eq!(expected.to_string(), body);
+		}
+
+		// update it, unsetting the optional field
+		{
+			let query = format!(
+				r#"mutation{{updateFoo(id: "{id}", input: {{val: 43, otherVal: null}}){{id, val, otherVal}}}}"#
+			);
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let expected = json!({
+				"data": {
+					"updateFoo": {
+						"id": id,
+						"val": 43,
+						"otherVal": null
+					}
+				}
+			});
+			assert_eq!(expected.to_string(), body);
+		}
+
+		// updating a record which does not exist returns null
+		{
+			let query = r#"mutation{updateFoo(id: "foo:missing", input: {val: 1}){id}}"#;
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			assert_eq!(json!({"data": {"updateFoo": null}}).to_string(), body);
+		}
+
+		// values are coerced into the kind of their field
+		{
+			let query = r#"mutation{createFoo(input: {val: "not a number"}){id}}"#;
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			assert!(body.contains("errors"), "body: {body}");
+		}
+
+		// delete it
+		{
+			let query = format!(r#"mutation{{deleteFoo(id: "{id}")}}"#);
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			assert_eq!(json!({"data": {"deleteFoo": id}}).to_string(), body);
+
+			let res = client
+				.post(gql_url)
+				.body(json!({ "query": format!(r#"query{{foo(id: "{id}"){{id}}}}"#) }).to_string())
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			assert_eq!(json!({"data": {"foo": null}}).to_string(), body);
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn nested_fields() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema and data
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+					DEFINE TABLE image SCHEMAFUL;
+					DEFINE FIELD size ON image TYPE object;
+					DEFINE FIELD size.height ON image TYPE int;
+					DEFINE FIELD size.location ON image TYPE object;
+					DEFINE FIELD size.location.info ON image TYPE float;
+					DEFINE FIELD size.location.coords ON image TYPE object;
+					DEFINE FIELD size.location.coords.lat ON image TYPE float;
+					CREATE image:1 CONTENT {
+						size: {
+							height: 100,
+							location: {
+								info: 1.5,
+								coords: { lat: 51.5 }
+							}
+						}
+					};
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		{
+			let res = client
+				.post(gql_url)
+				.body(json!({"query": r#"query{image(id: "image:1"){id, size{height, location{info, coords{lat}}}}}"#}).to_string())
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let expected = json!({
+				"data": {
+					"image": {
+						"id": "image:1",
+						"size": {
+							"height": 100,
+							"location": {
+								"info": 1.5,
+								"coords": {
+									"lat": 51.5
+								}
+							}
+						}
+					}
+				}
+			});
+			assert_eq!(expected.to_string(), body);
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn scalars() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema and data
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+					DEFINE TABLE foo SCHEMAFUL;
+					DEFINE FIELD dt ON foo TYPE option<datetime>;
+					DEFINE FIELD dur ON foo TYPE option<duration>;
+					DEFINE FIELD dec ON foo TYPE option<decimal>;
+					DEFINE FIELD uid ON foo TYPE option<uuid>;
+					DEFINE FIELD bin ON foo TYPE option<bytes>;
+					CREATE foo:1 SET
+						dt = d"2024-01-02T03:04:05.123456789Z",
+						dur = 1h30m,
+						dec = 12345678901234567890.123456789dec,
+						uid = u"0190a4bc-5d4e-7a2b-9f3c-4d5e6f708192",
+						bin = <bytes>"hello";
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		let expected = json!({
+			"dt": "2024-01-02T03:04:05.123456789+00:00",
+			"dur": "1h30m",
+			"dec": "12345678901234567890.123456789",
+			"uid": "0190a4bc-5d4e-7a2b-9f3c-4d5e6f708192",
+			"bin": "aGVsbG8=",
+		});
+
+		// values stored in the database are returned as their scalar
+		{
+			let query = r#"query{foo(id: "foo:1"){dt, dur, dec, uid, bin}}"#;
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			assert_eq!(res_obj["data"]["foo"], expected, "body: {body}");
+		}
+
+		// scalars returned by the schema parse back into the same values
+		{
+			let query = format!(
+				r#"mutation{{createFoo(input: {{dt: {dt}, dur: {dur}, dec: {dec}, uid: {uid}, bin: {bin}}}){{dt, dur, dec, uid, bin}}}}"#,
+				dt = expected["dt"],
+				dur = expected["dur"],
+				dec = expected["dec"],
+				uid = expected["uid"],
+				bin = expected["bin"],
+			);
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			assert_eq!(res_obj["data"]["createFoo"], expected, "body: {body}");
+		}
+
+		// the round tripped values are equal to the original ones
+		{
+			let res = client
+				.post(sql_url)
+				.body("SELECT VALUE dt = (SELECT VALUE dt FROM ONLY foo:1) AND dur = 1h30m AND dec = 12345678901234567890.123456789dec AND uid = u\"0190a4bc-5d4e-7a2b-9f3c-4d5e6f708192\" AND bin = <bytes>\"hello\" FROM foo WHERE id != foo:1")
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			assert_eq!(res_obj[0]["result"], json!([true]), "body: {body}");
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn geometry() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema and data
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+					DEFINE TABLE foo SCHEMAFUL;
+					DEFINE FIELD point ON foo TYPE option<geometry<point>>;
+					DEFINE FIELD area ON foo TYPE option<geometry<polygon>>;
+					DEFINE FIELD shapes ON foo TYPE option<geometry<collection>>;
+					DEFINE FIELD points ON foo TYPE option<array<geometry<point>>>;
+					CREATE foo:1 SET
+						point = (-0.118092, 51.509865),
+						area = {
+							type: "Polygon",
+							coordinates: [[[0, 0], [1, 0], [1, 1], [0, 0]]]
+						},
+						shapes = {
+							type: "GeometryCollection",
+							geometries: [
+								{ type: "Point", coordinates: [1, 2] },
+								{ type: "LineString", coordinates: [[1, 2], [3, 4]] }
+							]
+						},
+						points = [(1, 2), (3, 4)];
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		let expected = json!({
+			"point": {
+				"type": "Point",
+				"coordinates": [-0.118092, 51.509865]
+			},
+			"area": {
+				"type": "Polygon",
+				"coordinates": [[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 0.0]]]
+			},
+			"shapes": {
+				"type": "GeometryCollection",
+				"geometries": [
+					{ "type": "Point", "coordinates": [1.0, 2.0] },
+					{ "type": "LineString", "coordinates": [[1.0, 2.0], [3.0, 4.0]] }
+				]
+			},
+			"points": [
+				{ "type": "Point", "coordinates": [1.0, 2.0] },
+				{ "type": "Point", "coordinates": [3.0, 4.0] }
+			]
+		});
+
+		// geometries are returned as GeoJSON
+		{
+			let query = r#"query{foo(id: "foo:1"){point, area, shapes, points}}"#;
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			assert_eq!(res_obj["data"]["foo"], expected, "body: {body}");
+		}
+
+		// GeoJSON input parses back into the same geometries
+		{
+			let query = r#"mutation($input: FooCreateInput!){createFoo(input: $input){point, area, shapes, points}}"#;
+			let res = client
+				.post(gql_url)
+				.body(json!({ "query": query, "variables": { "input": expected } }).to_string())
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			assert_eq!(res_obj["data"]["createFoo"], expected, "body: {body}");
+		}
+
+		// geometries of the wrong type are rejected
+		{
+			let query = r#"mutation($input: FooCreateInput!){createFoo(input: $input){id}}"#;
+			let input = json!({ "point": expected["area"] });
+			let res = client
+				.post(gql_url)
+				.body(json!({ "query": query, "variables": { "input": input } }).to_string())
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			assert!(body.contains("errors"), "body: {body}");
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn field_renamed_by_annotation() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema and data
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+					DEFINE TABLE user SCHEMAFULL;
+					DEFINE FIELD created_at ON user TYPE string COMMENT "@graphql(name: creation) When the user signed up";
+					CREATE user:1 SET created_at = "yesterday";
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		// the field is exposed under its annotated name, without the annotation in its description
+		{
+			let query = r#"query{__type(name: "User"){fields{name, description, type{kind}}}}"#;
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let fields = res_obj["data"]["__type"]["fields"].as_array().unwrap();
+			assert!(fields.iter().all(|f| f["name"] != "createdAt"), "body: {body}");
+			let field = fields.iter().find(|f| f["name"] == "creation").unwrap();
+			assert_eq!(field["description"], "When the user signed up", "body: {body}");
+			assert_eq!(field["type"]["kind"], "NON_NULL", "body: {body}");
+		}
+
+		// the field is resolved from the field it was defined on
+		{
+			let query = r#"query{users(filterBy: {creation: {eq: "yesterday"}}){creation}}"#;
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			assert_eq!(
+				res_obj["data"],
+				json!({"users": [{"creation": "yesterday"}]}),
+				"body: {body}"
+			);
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn name_collisions() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		for (sql, message) in [
+			// a field renamed to the name of another field
+			(
+				r#"
+				DEFINE CONFIG GRAPHQL AUTO;
+				DEFINE TABLE user SCHEMAFULL;
+				DEFINE FIELD name ON user TYPE string;
+				DEFINE FIELD full_name ON user TYPE string COMMENT "@graphql(name: name)";
+				"#,
+				"have the GraphQL name `name`",
+			),
+			// verbatim names are not singularized, so both queries of the table would be `news`
+			(
+				r#"
+				DEFINE CONFIG GRAPHQL AUTO NAMING VERBATIM;
+				DEFINE TABLE news SCHEMAFULL;
+				DEFINE FIELD title ON news TYPE string;
+				"#,
+				"have the GraphQL name `news`",
+			),
+			// different tables which only differ in their case style
+			(
+				r#"
+				DEFINE CONFIG GRAPHQL AUTO;
+				DEFINE TABLE user_profile SCHEMAFULL;
+				DEFINE TABLE userProfile SCHEMAFULL;
+				"#,
+				"have the GraphQL name `createUserProfile`",
+			),
+			// different fields which share their order field
+			(
+				r#"
+				DEFINE CONFIG GRAPHQL AUTO NAMING VERBATIM;
+				DEFINE TABLE user SCHEMAFULL;
+				DEFINE FIELD fooBar ON user TYPE string;
+				DEFINE FIELD foo_bar ON user TYPE string;
+				"#,
+				"both have the order field `FOO_BAR`",
+			),
+		] {
+			let mut headers = reqwest::header::HeaderMap::new();
+			let ns = Ulid::new().to_string();
+			let db = Ulid::new().to_string();
+			headers.insert("surreal-ns", ns.parse()?);
+			headers.insert("surreal-db", db.parse()?);
+			headers.insert(header::ACCEPT, "application/json".parse()?);
+			let client = Client::builder()
+				.connect_timeout(Duration::from_millis(10))
+				.default_headers(headers)
+				.build()?;
+
+			let res = client.post(sql_url).body(sql).send().await?;
+			assert_eq!(res.status(), 200);
+
+			let res = client
+				.post(gql_url)
+				.body(json!({"query": r#"query{__typename}"#}).to_string())
+				.send()
+				.await?;
+			assert_eq!(res.status(), 400);
+			let body = res.text().await?;
+			assert!(body.contains(message), "body: {body}");
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn descriptions_from_comments() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+					DEFINE TABLE user SCHEMAFULL COMMENT "A person using the app";
+					DEFINE FIELD name ON user TYPE string COMMENT "The full name";
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		// the types generated for the table are described by its comment
+		for ty in [
+			"User",
+			"UserFilterInput",
+			"UserOrder",
+			"UserOrderField",
+			"UserCreateInput",
+			"UserUpdateInput",
+		] {
+			let query = format!(r#"query{{__type(name: "{ty}"){{description}}}}"#);
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let description = res_obj["data"]["__type"]["description"].as_str().unwrap();
+			match ty {
+				"User" => assert_eq!(description, "A person using the app"),
+				_ => assert!(description.ends_with("\n\nA person using the app"), "{ty}: {body}"),
+			}
+		}
+
+		// the inputs generated for the fields are described by their comments
+		{
+			let query = r#"query{__type(name: "UserFilterInput"){inputFields{name, description}}}"#;
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let fields = res_obj["data"]["__type"]["inputFields"].as_array().unwrap();
+			let name = fields.iter().find(|f| f["name"] == "name").unwrap();
+			assert_eq!(name["description"], "The full name", "body: {body}");
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn incoming_relations() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema and data
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+					DEFINE TABLE user SCHEMAFULL;
+					DEFINE FIELD name ON user TYPE string;
+					DEFINE TABLE post SCHEMAFULL;
+					DEFINE FIELD title ON post TYPE string;
+					DEFINE TABLE follow TYPE RELATION FROM user TO user SCHEMAFULL;
+					DEFINE TABLE like TYPE RELATION FROM user TO post SCHEMAFULL;
+					DEFINE FIELD rating ON like TYPE int;
+					CREATE user:1 SET name = "one";
+					CREATE user:2 SET name = "two";
+					CREATE post:1 SET title = "first post";
+					RELATE user:1->follow:1->user:2;
+					RELATE user:1->like:1->post:1 SET rating = 5;
+					RELATE user:2->like:2->post:1 SET rating = 4;
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		// incoming relations are listed on the records they point to
+		{
+			let query = r#"query{post(id: "post:1"){incomingLikes{totalCount, edges{rating, node{name}}}}}"#;
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let expected = json!({
+				"post": {
+					"incomingLikes": {
+						"totalCount": 2,
+						"edges": [
+							{"rating": 5, "node": {"name": "one"}},
+							{"rating": 4, "node": {"name": "two"}}
+						]
+					}
+				}
+			});
+			assert_eq!(res_obj["data"], expected, "body: {body}");
+		}
+
+		// relations between records of the same table are listed in both directions
+		{
+			let query =
+				r#"query{user(id: "user:2"){follows{nodes{name}}, incomingFollows{nodes{name}}}}"#;
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let expected = json!({
+				"user": {
+					"follows": {"nodes": []},
+					"incomingFollows": {"nodes": [{"name": "one"}]}
+				}
+			});
+			assert_eq!(res_obj["data"], expected, "body: {body}");
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn unique_field_query() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema and data
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+					DEFINE TABLE user SCHEMAFULL;
+					DEFINE FIELD email ON user TYPE string;
+					DEFINE FIELD name ON user TYPE string;
+					DEFINE INDEX email ON user FIELDS email UNIQUE;
+					DEFINE INDEX name ON user FIELDS name;
+					CREATE user:1 SET email = "one@example.com", name = "one";
+					CREATE user:2 SET email = "two@example.com", name = "two";
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		// only unique indexes get a query
+		{
+			let query = r#"query{__type(name: "Query"){fields{name, type{name}, args{name, type{kind, ofType{name}}}}}}"#;
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let fields = res_obj["data"]["__type"]["fields"].as_array().unwrap();
+			assert!(fields.iter().all(|f| f["name"] != "userByName"), "body: {body}");
+			let field = fields.iter().find(|f| f["name"] == "userByEmail").unwrap();
+			let expected = json!({
+				"name": "userByEmail",
+				"type": {"name": "User"},
+				"args": [{"name": "email", "type": {"kind": "NON_NULL", "ofType": {"name": "String"}}}]
+			});
+			assert_eq!(field, &expected, "body: {body}");
+		}
+
+		// records are fetched by their unique field
+		{
+			let query = r#"query{userByEmail(email: "two@example.com"){id, name}}"#;
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let expected = json!({"userByEmail": {"id": "user:2", "name": "two"}});
+			assert_eq!(res_obj["data"], expected, "body: {body}");
+		}
+
+		// missing records resolve to null
+		{
+			let query = r#"query{userByEmail(email: "three@example.com"){id}}"#;
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			assert_eq!(json!({"data": {"userByEmail": null}}).to_string(), body);
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn union_record_links() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema and data
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+					DEFINE TABLE user SCHEMAFULL;
+					DEFINE FIELD favourites ON user TYPE array<record<post | video>>;
+					DEFINE FIELD pinned ON user TYPE record<post | video>;
+					DEFINE FIELD featured ON user TYPE option<record<post>>;
+					DEFINE TABLE post SCHEMAFULL;
+					DEFINE FIELD title ON post TYPE string;
+					DEFINE TABLE video SCHEMAFULL;
+					DEFINE FIELD length ON video TYPE int;
+					DEFINE TABLE like TYPE RELATION FROM user TO post | video SCHEMAFULL;
+					CREATE post:1 SET title = "first post";
+					CREATE video:1 SET length = 10;
+					CREATE user:1 SET favourites = [video:1, post:1], pinned = video:1, featured = post:1;
+					RELATE user:1->like:1->post:1;
+					RELATE user:1->like:2->video:1;
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		for (query, expected) in [
+			// relations to several tables resolve to the type of each target
+			(
+				r#"query{user(id: "user:1"){likes{nodes{__typename, ...on Post{title}}}}}"#,
+				json!({"user": {"likes": {"nodes": [
+					{"__typename": "Post", "title": "first post"},
+					{"__typename": "Video"}
+				]}}}),
+			),
+			(
+				r#"query{user(id: "user:1"){likes{edges{node{__typename, ...on Video{length}}}}}}"#,
+				json!({"user": {"likes": {"edges": [
+					{"node": {"__typename": "Post"}},
+					{"node": {"__typename": "Video", "length": 10}}
+				]}}}),
+			),
+			// as do arrays of links to several tables
+			(
+				r#"query{user(id: "user:1"){favourites{__typename, ...on Video{length}}}}"#,
+				json!({"user": {"favourites": [
+					{"__typename": "Video", "length": 10},
+					{"__typename": "Post"}
+				]}}),
+			),
+			// and single links, which resolve to the linked record
+			(
+				r#"query{user(id: "user:1"){pinned{__typename, ...on Video{id, length}}, featured{id, title}}}"#,
+				json!({"user": {
+					"pinned": {"__typename": "Video", "id": "video:1", "length": 10},
+					"featured": {"id": "post:1", "title": "first post"}
+				}}),
+			),
+		] {
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			assert_eq!(res_obj["data"], expected, "body: {body}");
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn relation_endpoints() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema and data
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+					DEFINE TABLE user SCHEMAFULL;
+					DEFINE FIELD name ON user TYPE string;
+					DEFINE TABLE post SCHEMAFULL;
+					DEFINE FIELD title ON post TYPE string;
+					DEFINE TABLE video SCHEMAFULL;
+					DEFINE FIELD length ON video TYPE int;
+					DEFINE TABLE like TYPE RELATION FROM user TO post | video SCHEMAFULL;
+					CREATE user:1 SET name = "one";
+					CREATE post:1 SET title = "first post";
+					RELATE user:1->like:1->post:1;
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		// both ends of a relation are resolved from either side
+		{
+			let query = r#"query{
+				user(id: "user:1"){likes{edges{in{id, name}, out{__typename, ...on Post{title}}}}}
+				post(id: "post:1"){incomingLikes{edges{in{name}, out{__typename}}}}
+			}"#;
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let expected = json!({
+				"user": {"likes": {"edges": [{
+					"in": {"id": "user:1", "name": "one"},
+					"out": {"__typename": "Post", "title": "first post"}
+				}]}},
+				"post": {"incomingLikes": {"edges": [{
+					"in": {"name": "one"},
+					"out": {"__typename": "Post"}
+				}]}}
+			});
+			assert_eq!(res_obj["data"], expected, "body: {body}");
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn verbatim_naming() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema and data
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO NAMING VERBATIM;
+					DEFINE TABLE user_profile SCHEMAFULL;
+					DEFINE FIELD display_name ON user_profile TYPE string;
+					DEFINE FIELD home_address ON user_profile TYPE object;
+					DEFINE FIELD home_address.street_name ON user_profile TYPE string;
+					DEFINE TABLE follows TYPE RELATION FROM user_profile TO user_profile SCHEMAFULL;
+					CREATE user_profile:1 SET display_name = "one", home_address = { street_name: "main" };
+					CREATE user_profile:2 SET display_name = "two", home_address = { street_name: "side" };
+					RELATE user_profile:1->follows:1->user_profile:2;
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		// the generated types keep the names of the tables and fields
+		for (ty, field) in [
+			("user_profile", "display_name"),
+			("user_profile_home_address_object", "street_name"),
+			("user_profile_connection", "edges"),
+			("Query", "user_profile"),
+			("Query", "user_profiles"),
+			("Mutation", "create_user_profile"),
+		] {
+			let query = format!(r#"query{{__type(name: "{ty}"){{fields{{name}}}}}}"#);
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let fields = res_obj["data"]["__type"]["fields"].as_array().unwrap();
+			assert!(fields.iter().any(|f| f["name"] == field), "{ty}.{field}: {body}");
+		}
+		for ty in ["user_profile_filter_input", "user_profile_order", "user_profile_create_input"] {
+			let query = format!(r#"query{{__type(name: "{ty}"){{name}}}}"#);
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			assert_eq!(res_obj["data"]["__type"]["name"], ty, "body: {body}");
+		}
+
+		// the records are resolved through the verbatim names
+		{
+			let query = r#"query{
+				user_profile(id: "user_profile:1"){
+					display_name
+					home_address{street_name}
+					follows{nodes{display_name}}
+				}
+				user_profiles(
+					orderBy: {field: DISPLAY_NAME, direction: DESC},
+					filterBy: {display_name: {ne: "none"}}
+				){id}
+			}"#;
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let expected = json!({
+				"user_profile": {
+					"display_name": "one",
+					"home_address": {"street_name": "main"},
+					"follows": {"nodes": [{"display_name": "two"}]}
+				},
+				"user_profiles": [{"id": "user_profile:2"}, {"id": "user_profile:1"}]
+			});
+			assert_eq!(res_obj["data"], expected, "body: {body}");
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn deterministic_schema() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let sql = r#"
+			DEFINE CONFIG GRAPHQL AUTO CURSOR;
+			DEFINE TABLE user SCHEMAFULL;
+			DEFINE FIELD name ON user TYPE string;
+			DEFINE FIELD address ON user TYPE object;
+			DEFINE FIELD address.city ON user TYPE string;
+			DEFINE FIELD status ON user TYPE "active" | "inactive";
+			DEFINE TABLE post SCHEMAFULL;
+			DEFINE FIELD title ON post TYPE string;
+			DEFINE FIELD author ON post TYPE record<user>;
+			DEFINE TABLE video SCHEMAFULL;
+			DEFINE FIELD length ON video TYPE int;
+			DEFINE TABLE like TYPE RELATION FROM user TO post | video SCHEMAFULL;
+			DEFINE FIELD at ON like TYPE datetime;
+		"#;
+		let query = json!({"query": r#"query{__schema{types{name, fields{name}, inputFields{name}, enumValues{name}, possibleTypes{name}}}}"#}).to_string();
+
+		// the same definitions in different databases generate the same schema
+		let mut schemas = Vec::new();
+		for _ in 0..2 {
+			let mut headers = reqwest::header::HeaderMap::new();
+			let ns = Ulid::new().to_string();
+			let db = Ulid::new().to_string();
+			headers.insert("surreal-ns", ns.parse()?);
+			headers.insert("surreal-db", db.parse()?);
+			headers.insert(header::ACCEPT, "application/json".parse()?);
+			let client = Client::builder()
+				.connect_timeout(Duration::from_millis(10))
+				.default_headers(headers)
+				.build()?;
+
+			let res = client.post(sql_url).body(sql).send().await?;
+			assert_eq!(res.status(), 200);
+
+			let res = client.post(gql_url).body(query.clone()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			assert!(!body.contains("errors"), "body: {body}");
+			schemas.push(body);
+		}
+		assert_eq!(schemas[0], schemas[1]);
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn array_contains_filters() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema and data
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+					DEFINE TABLE post SCHEMAFULL;
+					DEFINE FIELD tags ON post TYPE array<string>;
+					DEFINE FIELD scores ON post TYPE option<array<int>>;
+					CREATE post:1 SET tags = ["a", "b"], scores = [1, 2];
+					CREATE post:2 SET tags = ["b", "c"];
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		// array fields are filtered by the elements they contain
+		{
+			let query = r#"query{__type(name: "PostFilterInput"){inputFields{name, type{name}}}}"#;
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let fields = res_obj["data"]["__type"]["inputFields"].as_array().unwrap();
+			let tags = fields.iter().find(|f| f["name"] == "tags").unwrap();
+			assert_eq!(tags["type"]["name"], "StringArrayFilterInput", "body: {body}");
+		}
+
+		for (filter, expected) in [
+			(r#"{tags: {contains: "a"}}"#, json!([{"id": "post:1"}])),
+			(r#"{tags: {containsAny: ["a", "c"]}}"#, json!([{"id": "post:1"}, {"id": "post:2"}])),
+			(r#"{tags: {containsAll: ["b", "c"]}}"#, json!([{"id": "post:2"}])),
+			(r#"{scores: {contains: 2}}"#, json!([{"id": "post:1"}])),
+			(r#"{not: {tags: {contains: "a"}}}"#, json!([{"id": "post:2"}])),
+		] {
+			let query = format!("query{{posts(filterBy: {filter}){{id}}}}");
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			assert_eq!(res_obj["data"]["posts"], expected, "{filter}: {body}");
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn string_filters() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema and data
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+					DEFINE TABLE user SCHEMAFULL;
+					DEFINE FIELD name ON user TYPE string;
+					DEFINE FIELD nick ON user TYPE option<string>;
+					CREATE user:1 SET name = "Tobie", nick = "tobie.h";
+					CREATE user:2 SET name = "Jaime";
+					CREATE user:3 SET name = "Tobias";
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		for (filter, expected) in [
+			(r#"{name: {contains: "obi"}}"#, json!([{"id": "user:1"}, {"id": "user:3"}])),
+			(r#"{name: {startsWith: "Tob"}}"#, json!([{"id": "user:1"}, {"id": "user:3"}])),
+			(r#"{name: {endsWith: "ie"}}"#, json!([{"id": "user:1"}])),
+			(r#"{name: {matches: "^[JT].*e$"}}"#, json!([{"id": "user:1"}, {"id": "user:2"}])),
+			(r#"{nick: {startsWith: "tobie."}}"#, json!([{"id": "user:1"}])),
+			(r#"{nick: {endsWith: "."}}"#, json!([])),
+		] {
+			let query = format!("query{{users(filterBy: {filter}){{id}}}}");
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			assert_eq!(res_obj["data"]["users"], expected, "{filter}: {body}");
+		}
+
+		// invalid patterns are rejected
+		{
+			let query = r#"query{users(filterBy: {name: {matches: "("}}){id}}"#;
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let message = res_obj["errors"][0]["message"].as_str().unwrap();
+			assert!(message.contains("Invalid regex `(`"), "body: {body}");
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn is_null_filter() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema and data
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+					DEFINE TABLE user SCHEMAFULL;
+					DEFINE FIELD name ON user TYPE string;
+					DEFINE FIELD email ON user TYPE option<string>;
+					CREATE user:1 SET name = "Tobie", email = "tobie@surrealdb.com";
+					CREATE user:2 SET name = "Jaime";
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		// only the filters of optional fields can match unset values
+		for (ty, is_nullable) in [("OptionalStringFilterInput", true), ("StringFilterInput", false)]
+		{
+			let query = format!(r#"query{{__type(name: "{ty}"){{inputFields{{name}}}}}}"#);
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let fields = res_obj["data"]["__type"]["inputFields"].as_array().unwrap();
+			assert_eq!(fields.iter().any(|f| f["name"] == "isNull"), is_nullable, "{ty}: {body}");
+		}
+
+		for (filter, expected) in [
+			(r#"{email: {isNull: true}}"#, json!([{"id": "user:2"}])),
+			(r#"{email: {isNull: false}}"#, json!([{"id": "user:1"}])),
+			(r#"{email: {startsWith: "tobie"}}"#, json!([{"id": "user:1"}])),
+		] {
+			let query = format!("query{{users(filterBy: {filter}){{id}}}}");
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			assert_eq!(res_obj["data"]["users"], expected, "{filter}: {body}");
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn geometry_filters() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema and data
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+					DEFINE TABLE place SCHEMAFULL;
+					DEFINE FIELD location ON place TYPE option<geometry<point>>;
+					DEFINE FIELD area ON place TYPE geometry<polygon>;
+					CREATE place:london SET location = (-0.118, 51.509), area = {
+						type: "Polygon",
+						coordinates: [[[-0.5, 51.3], [0.3, 51.3], [0.3, 51.7], [-0.5, 51.7], [-0.5, 51.3]]]
+					};
+					CREATE place:paris SET location = (2.352, 48.857), area = {
+						type: "Polygon",
+						coordinates: [[[2.2, 48.8], [2.5, 48.8], [2.5, 48.9], [2.2, 48.9], [2.2, 48.8]]]
+					};
+					CREATE place:nowhere SET area = {
+						type: "Polygon",
+						coordinates: [[[0, 0], [1, 0], [1, 1], [0, 1], [0, 0]]]
+					};
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		let around_london =
+			r#"{type: "Polygon", coordinates: [[[-1, 51], [1, 51], [1, 52], [-1, 52], [-1, 51]]]}"#;
+		for (filter, expected) in [
+			(format!("{{location: {{within: {around_london}}}}}"), json!([{"id": "place:london"}])),
+			(format!("{{area: {{intersects: {around_london}}}}}"), json!([{"id": "place:london"}])),
+			(
+				r#"{area: {within: {type: "Polygon", coordinates: [[[-1, -1], [3, -1], [3, 49], [-1, 49], [-1, -1]]]}}}"#.to_string(),
+				json!([{"id": "place:nowhere"}, {"id": "place:paris"}]),
+			),
+			(
+				r#"{location: {near: {point: {type: "Point", coordinates: [2.3, 48.8]}, distance: 10000}}}"#.to_string(),
+				json!([{"id": "place:paris"}]),
+			),
+			// unset values are never near
+			(
+				r#"{area: {near: {point: {type: "Point", coordinates: [0.5, 0.5]}, distance: 10000}}}"#.to_string(),
+				json!([]),
+			),
+		] {
+			let query = format!("query{{places(filterBy: {filter}){{id}}}}");
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			assert_eq!(res_obj["data"]["places"], expected, "{filter}: {body}");
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn wrapped_lists() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema and data
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO LISTS WRAPPED;
+					DEFINE TABLE user SCHEMAFULL;
+					DEFINE FIELD age ON user TYPE int;
+					CREATE user:1 SET age = 20;
+					CREATE user:2 SET age = 30;
+					CREATE user:3 SET age = 40;
+					CREATE user:4 SET age = 50;
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		// lists are wrapped with the total count and the bounds of the page
+		{
+			let query = r#"query{
+				users(filterBy: {age: {gt: 25}}, orderBy: {field: AGE, direction: ASC}, start: 1, limit: 1){
+					items{id}
+					total
+					start
+					limit
+				}
+			}"#;
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let expected =
+				json!({"users": {"items": [{"id": "user:3"}], "total": 3, "start": 1, "limit": 1}});
+			assert_eq!(res_obj["data"], expected, "body: {body}");
+		}
+
+		// the bounds default to the whole table
+		{
+			let query = r#"query{users{total, start, limit}}"#;
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let expected = json!({"users": {"total": 4, "start": 0, "limit": null}});
+			assert_eq!(res_obj["data"], expected, "body: {body}");
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn computed_fields() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema and data
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+					DEFINE TABLE user SCHEMAFULL;
+					DEFINE FIELD first ON user TYPE string;
+					DEFINE FIELD last ON user TYPE string;
+					DEFINE FIELD name ON user VALUE string::concat(first, " ", last);
+					DEFINE FIELD initials ON user VALUE <future> { string::concat(first.slice(0, 1), last.slice(0, 1)) };
+					CREATE user:1 SET first = "Tobie", last = "Morgan Hitchcock";
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		// untyped fields are exposed as JSON
+		{
+			let query = r#"query{__type(name: "User"){fields{name, type{kind, ofType{name}}}}}"#;
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let fields = res_obj["data"]["__type"]["fields"].as_array().unwrap();
+			for name in ["name", "initials"] {
+				let field = fields.iter().find(|f| f["name"] == name).unwrap();
+				assert_eq!(field["type"]["ofType"]["name"], "JSON", "body: {body}");
+			}
+		}
+
+		let query = json!({"query": r#"query{user(id: "user:1"){name, initials}}"#}).to_string();
+		{
+			let res = client.post(gql_url).body(query.clone()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let expected = json!({"user": {"name": "Tobie Morgan Hitchcock", "initials": "TM"}});
+			assert_eq!(res_obj["data"], expected, "body: {body}");
+		}
+
+		// computed values follow changes to the fields they are derived from
+		{
+			let res =
+				client.post(sql_url).body(r#"UPDATE user:1 SET first = "Jaime""#).send().await?;
+			assert_eq!(res.status(), 200);
+
+			let res = client.post(gql_url).body(query).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let expected = json!({"user": {"name": "Jaime Morgan Hitchcock", "initials": "JM"}});
+			assert_eq!(res_obj["data"], expected, "body: {body}");
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn query_limits() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema and data
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO DEPTH 3 COMPLEXITY 6;
+					DEFINE TABLE user SCHEMAFULL;
+					DEFINE FIELD name ON user TYPE string;
+					DEFINE FIELD profile ON user TYPE object;
+					DEFINE FIELD profile.bio ON user TYPE string;
+					DEFINE FIELD profile.address ON user TYPE object;
+					DEFINE FIELD profile.address.city ON user TYPE string;
+					CREATE user:1 SET name = "Tobie", profile = { bio: "CEO", address: { city: "London" } };
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		// queries within the limits are run
+		{
+			let query = r#"query{user(id: "user:1"){profile{bio}}}"#;
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let expected = json!({"data": {"user": {"profile": {"bio": "CEO"}}}});
+			assert_eq!(expected.to_string(), body);
+		}
+
+		// queries which are too deep are rejected
+		{
+			let query = r#"query{user(id: "user:1"){profile{address{city}}}}"#;
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			assert_eq!(res_obj["data"], serde_json::Value::Null, "body: {body}");
+			let errors = res_obj["errors"].as_array().unwrap();
+			assert_eq!(errors.len(), 1, "body: {body}");
+			assert_eq!(errors[0]["message"], "Query depth of 4 exceeds the limit of 3");
+			assert_eq!(errors[0]["extensions"]["code"], "LIMIT_EXCEEDED");
+			assert_eq!(errors[0]["extensions"]["limit"], "depth");
+		}
+
+		// queries which are too complex are rejected
+		{
+			let query = r#"query{users{id, name, profile{bio}}, a: users{id, name}}"#;
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let errors = res_obj["errors"].as_array().unwrap();
+			assert_eq!(errors.len(), 1, "body: {body}");
+			assert_eq!(errors[0]["message"], "Query complexity of 8 exceeds the limit of 6");
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn conversion_error_paths() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema and data
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+					DEFINE TABLE item SCHEMAFULL;
+					DEFINE FIELD size ON item TYPE option<object>;
+					DEFINE FIELD size.width ON item TYPE float;
+					DEFINE FIELD spots ON item TYPE option<array<geometry<point>>>;
+					CREATE item:1 SET size.width = NaN;
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		// values read from the database fail with the path of their field
+		{
+			let query = r#"query{item(id: "item:1"){size{width}}}"#;
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let errors = res_obj["errors"].as_array().unwrap();
+			assert_eq!(errors.len(), 1, "body: {body}");
+			let message = errors[0]["message"].as_str().unwrap();
+			assert!(message.ends_with(" at `item.size.width`"), "body: {body}");
+		}
+
+		// input values fail with the path of the nested value which does not match its kind
+		{
+			let query = r#"mutation{createItem(input: {spots: [
+				{type: "Point", coordinates: [1, 2]},
+				{type: "LineString", coordinates: [[0, 0], [1, 1]]}
+			]}){id}}"#;
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let errors = res_obj["errors"].as_array().unwrap();
+			assert_eq!(errors.len(), 1, "body: {body}");
+			let message = errors[0]["message"].as_str().unwrap();
+			assert!(message.starts_with("Error converting value: "), "body: {body}");
+			assert!(
+				message.ends_with("to type: geometry<point> at `item.spots[1]`"),
+				"body: {body}"
+			);
+			assert_eq!(errors[0]["extensions"]["code"], "TYPE_ERROR");
+			assert_eq!(errors[0]["extensions"]["valuePath"], "item.spots[1]");
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn mutations_committed() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+					DEFINE TABLE item SCHEMAFULL;
+					DEFINE FIELD n ON item TYPE int ASSERT $value > 0;
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		let select = json!({"query": r#"query{items{n}}"#}).to_string();
+
+		// created records are visible to later requests
+		let id = {
+			let query = r#"mutation{createItem(input: {n: 1}){id}}"#;
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let id = res_obj["data"]["createItem"]["id"].as_str().unwrap().to_string();
+
+			let res = client.post(gql_url).body(select.clone()).send().await?;
+			let body = res.text().await?;
+			assert_eq!(json!({"data": {"items": [{"n": 1}]}}).to_string(), body);
+			id
+		};
+
+		// later mutations of a request see the changes of earlier ones
+		{
+			let query = format!(
+				r#"mutation{{
+					a: updateItem(id: "{id}", input: {{n: 2}}){{n}}
+					b: updateItem(id: "{id}", input: {{n: 3}}){{n}}
+				}}"#
+			);
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			assert_eq!(res_obj["data"], json!({"a": {"n": 2}, "b": {"n": 3}}), "body: {body}");
+
+			let res = client.post(gql_url).body(select.clone()).send().await?;
+			let body = res.text().await?;
+			assert_eq!(json!({"data": {"items": [{"n": 3}]}}).to_string(), body);
+		}
+
+		// a failing mutation is not committed
+		{
+			let query = format!(r#"mutation{{updateItem(id: "{id}", input: {{n: 0}}){{n}}}}"#);
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			assert_eq!(res_obj["errors"].as_array().unwrap().len(), 1, "body: {body}");
+
+			let res = client.post(gql_url).body(select.clone()).send().await?;
+			let body = res.text().await?;
+			assert_eq!(json!({"data": {"items": [{"n": 3}]}}).to_string(), body);
+		}
+
+		// deleted records are gone for later requests
+		{
+			let query = format!(r#"mutation{{deleteItem(id: "{id}")}}"#);
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+
+			let res = client.post(gql_url).body(select).send().await?;
+			let body = res.text().await?;
+			assert_eq!(json!({"data": {"items": []}}).to_string(), body);
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn mutations_denied_to_anonymous_users() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema
+		{
+			let res = client
+				.post(sql_url)
+				.basic_auth(USER, Some(PASS))
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+					DEFINE TABLE item SCHEMAFULL PERMISSIONS FULL;
+					DEFINE FIELD n ON item TYPE int;
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		// anonymous users can not create records
+		{
+			let query = r#"mutation{createItem(input: {n: 1}){id}}"#;
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			let body = res.text().await?;
+			assert!(body.contains("Not enough permissions"), "body: {body}");
+		}
+
+		// nothing was written
+		{
+			let res = client
+				.post(gql_url)
+				.basic_auth(USER, Some(PASS))
+				.body(json!({"query": r#"query{items{n}}"#}).to_string())
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			assert_eq!(json!({"data": {"items": []}}).to_string(), body);
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn query_timeout() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server(common::StartServerArguments {
+			auth: false,
+			args: "--query-timeout 500ms".to_string(),
+			vars: Some(HashMap::from([(
+				"SURREAL_CAPS_ALLOW_EXPERIMENTAL".to_string(),
+				"graphql".to_string(),
+			)])),
+			..Default::default()
+		})
+		.await
+		.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema, where creating slow items takes longer than the timeout
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+					DEFINE TABLE item SCHEMAFULL;
+					DEFINE FIELD slow ON item TYPE bool;
+					DEFINE FIELD n ON item TYPE int VALUE { IF slow { sleep(2s) }; RETURN $value; };
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		// statements finishing within the timeout succeed
+		{
+			let query = r#"mutation{createItem(input: {slow: false, n: 1}){n}}"#;
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			assert_eq!(json!({"data": {"createItem": {"n": 1}}}).to_string(), body);
+		}
+
+		// statements running past the timeout are stopped
+		{
+			let query = r#"mutation{createItem(input: {slow: true, n: 2}){n}}"#;
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			assert!(body.contains("exceeded the timeout"), "body: {body}");
+
+			let res = client
+				.post(gql_url)
+				.body(json!({"query": r#"query{items{n}}"#}).to_string())
+				.send()
+				.await?;
+			let body = res.text().await?;
+			assert_eq!(json!({"data": {"items": [{"n": 1}]}}).to_string(), body);
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn record_fetched_once() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema and data, where every fetch of a record computes a new nonce
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+					DEFINE TABLE item SCHEMAFULL;
+					DEFINE FIELD n ON item TYPE int;
+					DEFINE FIELD nonce ON item VALUE <future> { rand::uuid() };
+					CREATE item:1 SET n = 1;
+					CREATE item:2 SET n = 2;
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		// the fields of a record agree, as it is fetched once for all of their resolvers
+		{
+			let query = r#"query{item(id: "item:1"){n, a: nonce, b: nonce}}"#;
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let item = &res_obj["data"]["item"];
+			assert_eq!(item["a"], item["b"], "body: {body}");
+		}
+
+		// as do those of the records of a list
+		{
+			let query = r#"query{items{n, a: nonce, b: nonce}}"#;
+			let res =
+				client.post(gql_url).body(json!({ "query": query }).to_string()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let items = res_obj["data"]["items"].as_array().unwrap();
+			assert_eq!(items.len(), 2, "body: {body}");
+			for item in items {
+				assert_eq!(item["a"], item["b"], "body: {body}");
+			}
+			assert_ne!(items[0]["a"], items[1]["a"], "body: {body}");
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn schema_rebuilt_after_definitions_change() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+					DEFINE TABLE foo SCHEMAFULL;
+					DEFINE FIELD bar ON foo TYPE string;
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		let fields_query =
+			json!({"query": r#"query{__type(name: "Foo"){fields{name}}}"#}).to_string();
+
+		{
+			let res = client.post(gql_url).body(fields_query.clone()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let expected = json!([{"name": "id"}, {"name": "bar"}]);
+			assert_equal_arrs!(&res_obj["data"]["__type"]["fields"], &expected);
+		}
+
+		// data changes leave the schema untouched
+		{
+			let res = client.post(sql_url).body(r#"CREATE foo SET bar = "a""#).send().await?;
+			assert_eq!(res.status(), 200);
+
+			let res = client.post(gql_url).body(fields_query.clone()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let expected = json!([{"name": "id"}, {"name": "bar"}]);
+			assert_equal_arrs!(&res_obj["data"]["__type"]["fields"], &expected);
+		}
+
+		// the schema is rebuilt once the definitions change
+		{
+			let res = client.post(sql_url).body("DEFINE FIELD baz ON foo TYPE int").send().await?;
+			assert_eq!(res.status(), 200);
+
+			let res = client.post(gql_url).body(fields_query).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let expected = json!([{"name": "id"}, {"name": "bar"}, {"name": "baz"}]);
+			assert_equal_arrs!(&res_obj["data"]["__type"]["fields"], &expected);
+		}
+
+		Ok(())
+	}
+
+	#[test(tokio::test)]
+	async fn schema_not_shared_between_sessions() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+		let signup_url = &format!("http://{addr}/signup");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema
+		{
+			let res = client
+				.post(sql_url)
+				.basic_auth(USER, Some(PASS))
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+					DEFINE ACCESS user ON DATABASE TYPE RECORD
+					SIGNUP ( CREATE user SET email = $email, pass = crypto::argon2::generate($pass) )
+					SIGNIN ( SELECT * FROM user WHERE email = $email AND crypto::argon2::compare(pass, $pass) )
+					DURATION FOR SESSION 60s, FOR TOKEN 1d;
+
+					DEFINE TABLE foo SCHEMAFULL PERMISSIONS FULL;
+					DEFINE FIELD bar ON foo TYPE string;
+					DEFINE FIELD secret ON foo TYPE string PERMISSIONS NONE;
+				"#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		// sign up as a record user
+		let token = {
+			let req_body = json!({
+				"ns": ns,
+				"db": db,
+				"ac": "user",
+				"email": "user@email.com",
+				"pass": "pass",
+			})
+			.to_string();
+
+			let res = client.post(signup_url).body(req_body).send().await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+			let body: serde_json::Value = serde_json::from_str(&res.text().await?)?;
+			body["token"].as_str().unwrap().to_string()
+		};
+
+		let fields_query =
+			json!({"query": r#"query{__type(name: "Foo"){fields{name}}}"#}).to_string();
+		let all_fields = json!([{"name": "id"}, {"name": "bar"}, {"name": "secret"}]);
+
+		// the schema generated for root shows all fields
+		{
+			let res = client
+				.post(gql_url)
+				.basic_auth(USER, Some(PASS))
+				.body(fields_query.clone())
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			assert_equal_arrs!(&res_obj["data"]["__type"]["fields"], &all_fields);
+		}
+
+		// a restricted session does not reuse the schema generated for root
+		{
+			let res =
+				client.post(gql_url).bearer_auth(&token).body(fields_query.clone()).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			let expected = json!([{"name": "id"}, {"name": "bar"}]);
+			assert_equal_arrs!(&res_obj["data"]["__type"]["fields"], &expected);
+		}
+
+		// nor does root reuse the schema generated for the restricted session
+		{
+			let res =
+				client.post(gql_url).basic_auth(USER, Some(PASS)).body(fields_query).send().await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let res_obj = serde_json::Value::from_str(&body)?;
+			assert_equal_arrs!(&res_obj["data"]["__type"]["fields"], &all_fields);
+		}
+
 		Ok(())
 	}
 }